@@ -0,0 +1,280 @@
+//! API authentication -- node token and per-device bearer tokens with scopes.
+//!
+//! The node token (`~/.cordelia/node-token`) has full access. Device tokens
+//! are registered via `devices/register` as a SHA-256 hash and carry scopes:
+//!
+//! - `read`  -- read-only routes (items, groups, status, events)
+//! - `write` -- `read` plus L1/L2 writes and deletes
+//! - `admin` -- everything, including group and device management
+//! - `group:<id>` -- restrict to the listed groups (repeatable)
+//!
+//! Tokens are checked on every request against storage, so revocation takes
+//! effect immediately; open SSE streams are closed via `AppState::device_revocations`.
+//!
+//! Scopes bound what a token can reach; within a group, the caller's entity
+//! must also hold a suitable role (`require_role`).
+
+use axum::http::{HeaderMap, StatusCode};
+use sha2::{Digest, Sha256};
+
+use cordelia_replication::roles::{self, GroupAction};
+
+use crate::AppState;
+
+/// Access level required by a route (ordered: admin implies write implies read).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Access {
+    Read,
+    Write,
+    Admin,
+}
+
+/// Authenticated caller.
+#[derive(Debug, Clone)]
+pub struct Principal {
+    /// None for the node token.
+    pub device_id: Option<String>,
+    /// Entity the device is registered to (None for the node token).
+    entity_id: Option<String>,
+    access: Access,
+    /// None = all groups.
+    groups: Option<Vec<String>>,
+}
+
+pub type AuthError = (StatusCode, &'static str);
+
+impl Principal {
+    /// The node token: full access.
+    pub fn node() -> Self {
+        Self {
+            device_id: None,
+            entity_id: None,
+            access: Access::Admin,
+            groups: None,
+        }
+    }
+
+    pub fn device(device_id: &str, entity_id: &str, scopes: &str) -> Result<Self, String> {
+        let (access, groups) = parse_scopes(scopes)?;
+        Ok(Self {
+            device_id: Some(device_id.to_string()),
+            entity_id: Some(entity_id.to_string()),
+            access,
+            groups,
+        })
+    }
+
+    /// Whether this principal is restricted to specific groups.
+    pub fn is_group_limited(&self) -> bool {
+        self.groups.is_some()
+    }
+
+    /// Whether a resource in `group_id` is accessible. Ungrouped resources
+    /// are only accessible to unrestricted principals.
+    pub fn allows_group(&self, group_id: Option<&str>) -> bool {
+        match (&self.groups, group_id) {
+            (None, _) => true,
+            (Some(groups), Some(gid)) => groups.iter().any(|g| g == gid),
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Groups this principal is limited to (None = all).
+    pub fn groups(&self) -> Option<&[String]> {
+        self.groups.as_deref()
+    }
+
+    /// Entity acting through this principal (the node's own for the node token).
+    pub fn entity<'a>(&'a self, state: &'a AppState) -> &'a str {
+        self.entity_id.as_deref().unwrap_or(&state.entity_id)
+    }
+}
+
+/// Parse a space- or comma-separated scope list.
+pub fn parse_scopes(scopes: &str) -> Result<(Access, Option<Vec<String>>), String> {
+    let mut access = None;
+    let mut groups: Option<Vec<String>> = None;
+    for scope in scopes
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+    {
+        let level = match scope {
+            "read" => Access::Read,
+            "write" => Access::Write,
+            "admin" => Access::Admin,
+            _ => match scope.strip_prefix("group:") {
+                Some(gid) if !gid.is_empty() => {
+                    groups.get_or_insert_with(Vec::new).push(gid.to_string());
+                    continue;
+                }
+                _ => return Err(format!("unknown scope '{scope}'")),
+            },
+        };
+        access = access.max(Some(level));
+    }
+    match access {
+        Some(a) => Ok((a, groups)),
+        None => Err("scopes must include read, write or admin".into()),
+    }
+}
+
+/// Hex SHA-256 of a bearer token (as stored in `devices.auth_token_hash`).
+pub fn token_hash(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Constant-time byte comparison (length is not secret).
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Authenticate the request and check the route's access level.
+pub fn authorize(
+    state: &AppState,
+    headers: &HeaderMap,
+    required: Access,
+) -> Result<Principal, AuthError> {
+    let token = headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .unwrap_or("");
+    if token.is_empty() {
+        return Err((StatusCode::UNAUTHORIZED, "invalid bearer token"));
+    }
+
+    // Compare digests so timing reveals nothing about the node token
+    let presented = Sha256::digest(token.as_bytes());
+    let expected = Sha256::digest(state.bearer_token.as_bytes());
+    let principal = if constant_time_eq(&presented, &expected) {
+        Principal::node()
+    } else {
+        let device = match state
+            .storage
+            .get_device_by_token_hash(&hex::encode(presented))
+        {
+            Ok(Some(d)) => d,
+            Ok(None) => return Err((StatusCode::UNAUTHORIZED, "invalid bearer token")),
+            Err(e) => {
+                tracing::warn!(error = %e, "device: token lookup failed");
+                return Err((StatusCode::INTERNAL_SERVER_ERROR, "auth lookup failed"));
+            }
+        };
+        if let Err(e) = state.storage.touch_device(&device.device_id) {
+            tracing::debug!(device_id = device.device_id, error = %e, "device: touch failed");
+        }
+        match Principal::device(&device.device_id, &device.entity_id, &device.scopes) {
+            Ok(p) => p,
+            Err(e) => {
+                tracing::warn!(
+                    device_id = device.device_id,
+                    error = e,
+                    "device: invalid scopes"
+                );
+                return Err((StatusCode::FORBIDDEN, "device token has invalid scopes"));
+            }
+        }
+    };
+
+    if principal.access < required {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "token scope does not permit this route",
+        ));
+    }
+    Ok(principal)
+}
+
+/// As `authorize`, for routes with no group context (L1, devices, peers,
+/// diagnostics, metrics): group-limited tokens are refused.
+pub fn authorize_global(
+    state: &AppState,
+    headers: &HeaderMap,
+    required: Access,
+) -> Result<Principal, AuthError> {
+    let principal = authorize(state, headers, required)?;
+    if principal.is_group_limited() {
+        return Err((StatusCode::FORBIDDEN, "token is limited to specific groups"));
+    }
+    Ok(principal)
+}
+
+/// Check the principal's entity holds a role permitting `action` in
+/// `group_id`. Denials are logged to `access_log` and returned as 403.
+pub fn require_role(
+    state: &AppState,
+    principal: &Principal,
+    group_id: &str,
+    action: GroupAction,
+    resource_type: &str,
+    resource_id: Option<&str>,
+) -> Result<(), (StatusCode, String)> {
+    let entity_id = principal.entity(state);
+    let Err(reason) = roles::check_in_storage(
+        state.storage.as_ref(),
+        group_id,
+        entity_id,
+        &state.entity_id,
+        action,
+    ) else {
+        return Ok(());
+    };
+    tracing::warn!(
+        entity_id,
+        group_id,
+        action = action.as_str(),
+        reason,
+        "api: group role check failed"
+    );
+    let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
+        entity_id: entity_id.to_string(),
+        action: "access_denied".into(),
+        resource_type: resource_type.into(),
+        resource_id: resource_id.map(str::to_string),
+        group_id: Some(group_id.to_string()),
+        detail: Some(reason.clone()),
+    });
+    Err((StatusCode::FORBIDDEN, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scopes() {
+        assert_eq!(parse_scopes("read").unwrap(), (Access::Read, None));
+        assert_eq!(parse_scopes("read write").unwrap().0, Access::Write);
+        assert_eq!(
+            parse_scopes("write,group:a group:b").unwrap(),
+            (Access::Write, Some(vec!["a".into(), "b".into()]))
+        );
+        assert!(parse_scopes("group:a").is_err()); // no access level
+        assert!(parse_scopes("read root").is_err());
+        assert!(parse_scopes("read group:").is_err());
+        assert!(parse_scopes("").is_err());
+    }
+
+    #[test]
+    fn test_principal_groups() {
+        let node = Principal::node();
+        assert!(node.allows_group(None) && node.allows_group(Some("x")));
+
+        let dev = Principal::device("d1", "russell", "read group:a").unwrap();
+        assert!(dev.is_group_limited());
+        assert!(dev.allows_group(Some("a")));
+        assert!(!dev.allows_group(Some("b")));
+        assert!(!dev.allows_group(None));
+    }
+
+    #[test]
+    fn test_token_hash_and_compare() {
+        assert_eq!(
+            token_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert!(constant_time_eq(b"same", b"same"));
+        assert!(!constant_time_eq(b"same", b"samf"));
+        assert!(!constant_time_eq(b"short", b"longer"));
+    }
+}
@@ -0,0 +1,487 @@
+//! Change feed -- live event stream for agents (`GET /api/v1/events`, SSE).
+//!
+//! Every change source (API handlers, replication receive, descriptor merge)
+//! publishes into one `ChangeFeed`. Events get a per-boot sequence number and
+//! are kept in a bounded ring buffer so a reconnecting client can resume from
+//! its last event ID. Each group's `notification_policy` decides what is
+//! published: `push` (full event), `notify` (content-free activity marker) or
+//! `silent` (nothing).
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use cordelia_protocol::messages::FetchedItem;
+use cordelia_replication::{GroupCulture, NotificationPolicy};
+use cordelia_storage::Storage;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// Events retained for cursor resume.
+pub const DEFAULT_REPLAY_CAPACITY: usize = 1024;
+
+/// Kind of change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Local L2 write via the API.
+    ItemWritten,
+    /// Item stored from a peer (push or anti-entropy).
+    ItemReplicated,
+    /// Concurrent edit received from a peer, kept as a conflict sibling.
+    ItemConflict,
+    /// Item deleted (local delete or replicated tombstone).
+    ItemDeleted,
+    /// Group created locally or descriptor merged from a peer.
+    GroupUpdated,
+    /// Group tombstoned (local delete or replicated tombstone).
+    GroupDeleted,
+    /// Member added, removed, or posture changed.
+    MembershipChanged,
+    /// Something changed in a `notify` group (no detail).
+    GroupActivity,
+}
+
+impl ChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeKind::ItemWritten => "item_written",
+            ChangeKind::ItemReplicated => "item_replicated",
+            ChangeKind::ItemConflict => "item_conflict",
+            ChangeKind::ItemDeleted => "item_deleted",
+            ChangeKind::GroupUpdated => "group_updated",
+            ChangeKind::GroupDeleted => "group_deleted",
+            ChangeKind::MembershipChanged => "membership_changed",
+            ChangeKind::GroupActivity => "group_activity",
+        }
+    }
+}
+
+/// A change to publish (sequence and timestamp are assigned by the feed).
+#[derive(Debug, Clone)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub group_id: Option<String>,
+    pub item_id: Option<String>,
+    pub item_type: Option<String>,
+    /// Item author, or the member whose membership changed.
+    pub entity_id: Option<String>,
+}
+
+impl Change {
+    pub fn item(
+        kind: ChangeKind,
+        group_id: Option<&str>,
+        item_id: &str,
+        item_type: &str,
+        author_id: Option<&str>,
+    ) -> Self {
+        Self {
+            kind,
+            group_id: group_id.map(String::from),
+            item_id: Some(item_id.to_string()),
+            item_type: Some(item_type.to_string()),
+            entity_id: author_id.map(String::from),
+        }
+    }
+
+    /// Item stored from a peer. Replicated tombstones are deletions.
+    pub fn replicated(item: &FetchedItem) -> Self {
+        let kind = if item.item_type == "__tombstone__" {
+            ChangeKind::ItemDeleted
+        } else {
+            ChangeKind::ItemReplicated
+        };
+        Self::item(
+            kind,
+            Some(&item.group_id),
+            &item.item_id,
+            &item.item_type,
+            Some(&item.author_id),
+        )
+    }
+
+    /// Concurrent edit from a peer kept alongside the current version.
+    pub fn conflict(item: &FetchedItem) -> Self {
+        Self::item(
+            ChangeKind::ItemConflict,
+            Some(&item.group_id),
+            &item.item_id,
+            &item.item_type,
+            Some(&item.author_id),
+        )
+    }
+
+    pub fn group(kind: ChangeKind, group_id: &str) -> Self {
+        Self {
+            kind,
+            group_id: Some(group_id.to_string()),
+            item_id: None,
+            item_type: None,
+            entity_id: None,
+        }
+    }
+
+    pub fn member(group_id: &str, entity_id: &str) -> Self {
+        Self {
+            kind: ChangeKind::MembershipChanged,
+            group_id: Some(group_id.to_string()),
+            item_id: None,
+            item_type: None,
+            entity_id: Some(entity_id.to_string()),
+        }
+    }
+}
+
+/// A published change event, as sent to subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeEvent {
+    /// Per-boot sequence number (see `ChangeFeed::cursor`).
+    pub seq: u64,
+    pub kind: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+    /// RFC 3339 publish time.
+    pub ts: String,
+}
+
+/// Subscription handle: buffered events to replay, then the live receiver.
+pub struct Subscription {
+    pub replay: Vec<ChangeEvent>,
+    pub live: broadcast::Receiver<ChangeEvent>,
+    /// True if the cursor could not be honoured (node restarted or events
+    /// fell out of the replay buffer). The client must resync by polling.
+    pub gap: bool,
+}
+
+struct FeedInner {
+    next_seq: u64,
+    buffer: VecDeque<ChangeEvent>,
+}
+
+/// Shared change feed (one per node).
+pub struct ChangeFeed {
+    /// Distinguishes cursors across restarts (sequence numbers reset on boot).
+    epoch: u64,
+    capacity: usize,
+    inner: Mutex<FeedInner>,
+    tx: broadcast::Sender<ChangeEvent>,
+}
+
+impl ChangeFeed {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity.max(1));
+        let epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        Self {
+            epoch,
+            capacity,
+            inner: Mutex::new(FeedInner {
+                next_seq: 1,
+                buffer: VecDeque::with_capacity(capacity),
+            }),
+            tx,
+        }
+    }
+
+    /// Resume cursor for an event: `<epoch>-<seq>`. Used as the SSE event ID.
+    pub fn cursor(&self, seq: u64) -> String {
+        format!("{}-{}", self.epoch, seq)
+    }
+
+    /// Publish a change, applying the group's notification_policy.
+    /// Returns the assigned sequence number, or None if suppressed.
+    pub fn publish(&self, storage: &dyn Storage, change: Change) -> Option<u64> {
+        let culture = change
+            .group_id
+            .as_deref()
+            .and_then(|gid| storage.read_group(gid).ok().flatten())
+            .map(|g| g.culture);
+        self.publish_with_culture(culture.as_deref(), change)
+    }
+
+    /// Publish using an explicit culture (for group deletions, where the
+    /// stored culture has already been replaced by the tombstone).
+    pub fn publish_with_culture(&self, culture: Option<&str>, change: Change) -> Option<u64> {
+        let policy = culture
+            .map(|c| GroupCulture::load(c).notification())
+            .unwrap_or(NotificationPolicy::Push);
+
+        let change = match policy {
+            NotificationPolicy::Push => change,
+            NotificationPolicy::Notify => Change {
+                kind: ChangeKind::GroupActivity,
+                group_id: change.group_id,
+                item_id: None,
+                item_type: None,
+                entity_id: None,
+            },
+            NotificationPolicy::Silent => return None,
+        };
+        self.push(change)
+    }
+
+    fn push(&self, change: Change) -> Option<u64> {
+        let mut inner = self.inner.lock().ok()?;
+        let seq = inner.next_seq;
+        inner.next_seq += 1;
+        let event = ChangeEvent {
+            seq,
+            kind: change.kind,
+            group_id: change.group_id,
+            item_id: change.item_id,
+            item_type: change.item_type,
+            entity_id: change.entity_id,
+            ts: chrono::Utc::now().to_rfc3339(),
+        };
+        if inner.buffer.len() == self.capacity {
+            inner.buffer.pop_front();
+        }
+        inner.buffer.push_back(event.clone());
+        // Send under the lock so subscribe() sees a consistent replay/live split
+        let _ = self.tx.send(event);
+        Some(seq)
+    }
+
+    /// Subscribe from an optional resume cursor (`<epoch>-<seq>`, the last
+    /// event ID the client saw). Without a cursor only live events are sent.
+    pub fn subscribe(&self, cursor: Option<&str>) -> Subscription {
+        let inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let live = self.tx.subscribe();
+
+        let Some(cursor) = cursor else {
+            return Subscription {
+                replay: Vec::new(),
+                live,
+                gap: false,
+            };
+        };
+
+        let last_seen = cursor
+            .split_once('-')
+            .and_then(|(epoch, seq)| Some((epoch.parse::<u64>().ok()?, seq.parse::<u64>().ok()?)))
+            .filter(|(epoch, seq)| *epoch == self.epoch && *seq < inner.next_seq)
+            .map(|(_, seq)| seq);
+
+        match last_seen {
+            Some(seq) => {
+                let oldest = inner.buffer.front().map_or(inner.next_seq, |e| e.seq);
+                Subscription {
+                    replay: inner
+                        .buffer
+                        .iter()
+                        .filter(|e| e.seq > seq)
+                        .cloned()
+                        .collect(),
+                    live,
+                    gap: seq + 1 < oldest,
+                }
+            }
+            // Unknown epoch (node restarted) or malformed cursor
+            None => Subscription {
+                replay: Vec::new(),
+                live,
+                gap: true,
+            },
+        }
+    }
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        Self::new(DEFAULT_REPLAY_CAPACITY)
+    }
+}
+
+/// Subscriber-side filter (comma-separated `group_id` / `item_type` lists).
+#[derive(Debug, Default)]
+pub struct EventFilter {
+    groups: Option<Vec<String>>,
+    item_types: Option<Vec<String>>,
+}
+
+impl EventFilter {
+    pub fn new(groups: Option<&str>, item_types: Option<&str>) -> Self {
+        let split = |s: &str| -> Vec<String> {
+            s.split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(String::from)
+                .collect()
+        };
+        Self {
+            groups: groups.map(split),
+            item_types: item_types.map(split),
+        }
+    }
+
+    /// Limit to `allowed` groups (a group-scoped token): intersects any
+    /// requested group filter.
+    pub fn restrict_groups(&mut self, allowed: &[String]) {
+        let groups = match self.groups.take() {
+            Some(requested) => requested
+                .into_iter()
+                .filter(|g| allowed.contains(g))
+                .collect(),
+            None => allowed.to_vec(),
+        };
+        self.groups = Some(groups);
+    }
+
+    pub fn matches(&self, event: &ChangeEvent) -> bool {
+        if let Some(groups) = &self.groups {
+            match &event.group_id {
+                Some(g) if groups.contains(g) => {}
+                _ => return false,
+            }
+        }
+        if let Some(types) = &self.item_types {
+            // Events without an item type (group/membership) are not item events
+            match &event.item_type {
+                Some(t) if types.contains(t) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cordelia_storage::SqliteStorage;
+
+    fn db() -> (tempfile::TempDir, SqliteStorage) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        db.write_group(
+            "push-g",
+            "push",
+            r#"{"broadcast_eagerness":"chatty"}"#,
+            "{}",
+        )
+        .unwrap();
+        db.write_group(
+            "notify-g",
+            "notify",
+            r#"{"broadcast_eagerness":"chatty","notification_policy":"notify"}"#,
+            "{}",
+        )
+        .unwrap();
+        db.write_group(
+            "silent-g",
+            "silent",
+            r#"{"broadcast_eagerness":"chatty","notification_policy":"silent"}"#,
+            "{}",
+        )
+        .unwrap();
+        (dir, db)
+    }
+
+    fn written(group: &str, item: &str) -> Change {
+        Change::item(
+            ChangeKind::ItemWritten,
+            Some(group),
+            item,
+            "entity",
+            Some("russell"),
+        )
+    }
+
+    #[test]
+    fn test_notification_policy_applied() {
+        let (_dir, db) = db();
+        let feed = ChangeFeed::new(16);
+        let mut sub = feed.subscribe(None);
+
+        assert!(feed.publish(&db, written("push-g", "a")).is_some());
+        assert!(feed.publish(&db, written("notify-g", "b")).is_some());
+        assert!(feed.publish(&db, written("silent-g", "c")).is_none());
+
+        let e = sub.live.try_recv().unwrap();
+        assert_eq!(e.kind, ChangeKind::ItemWritten);
+        assert_eq!(e.item_id.as_deref(), Some("a"));
+        assert_eq!(e.entity_id.as_deref(), Some("russell"));
+
+        // notify: activity marker only, no item detail
+        let e = sub.live.try_recv().unwrap();
+        assert_eq!(e.kind, ChangeKind::GroupActivity);
+        assert_eq!(e.group_id.as_deref(), Some("notify-g"));
+        assert!(e.item_id.is_none() && e.entity_id.is_none());
+
+        assert!(sub.live.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_cursor_resume_and_gap() {
+        let (_dir, db) = db();
+        let feed = ChangeFeed::new(3);
+        for i in 0..5 {
+            feed.publish(&db, written("push-g", &format!("item-{i}")));
+        }
+
+        // seq 1-2 evicted; resuming from 3 replays 4 and 5
+        let sub = feed.subscribe(Some(&feed.cursor(3)));
+        assert!(!sub.gap);
+        let seqs: Vec<u64> = sub.replay.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![4, 5]);
+
+        // Up to date: nothing to replay
+        let sub = feed.subscribe(Some(&feed.cursor(5)));
+        assert!(!sub.gap && sub.replay.is_empty());
+
+        // Cursor older than the buffer: gap, replay what we have
+        let sub = feed.subscribe(Some(&feed.cursor(1)));
+        assert!(sub.gap);
+        assert_eq!(sub.replay.len(), 3);
+
+        // Other epoch (node restarted) or garbage: gap
+        assert!(feed.subscribe(Some("1-3")).gap);
+        assert!(feed.subscribe(Some("nonsense")).gap);
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let event = |group: &str, item_type: Option<&str>| ChangeEvent {
+            seq: 1,
+            kind: ChangeKind::ItemWritten,
+            group_id: Some(group.into()),
+            item_id: None,
+            item_type: item_type.map(String::from),
+            entity_id: None,
+            ts: String::new(),
+        };
+
+        let all = EventFilter::new(None, None);
+        assert!(all.matches(&event("g1", None)));
+
+        let groups = EventFilter::new(Some("g1, g2"), None);
+        assert!(groups.matches(&event("g2", Some("entity"))));
+        assert!(!groups.matches(&event("g3", Some("entity"))));
+
+        let mut scoped = EventFilter::new(Some("g1,g3"), None);
+        scoped.restrict_groups(&["g1".to_string(), "g2".to_string()]);
+        assert!(scoped.matches(&event("g1", None)));
+        assert!(!scoped.matches(&event("g2", None))); // not requested
+        assert!(!scoped.matches(&event("g3", None))); // not allowed
+        let mut scoped = EventFilter::new(None, None);
+        scoped.restrict_groups(&["g2".to_string()]);
+        assert!(scoped.matches(&event("g2", None)));
+        assert!(!scoped.matches(&event("g1", None)));
+
+        let types = EventFilter::new(None, Some("entity,learning"));
+        assert!(types.matches(&event("g1", Some("learning"))));
+        assert!(!types.matches(&event("g1", Some("session"))));
+        assert!(!types.matches(&event("g1", None)));
+    }
+}
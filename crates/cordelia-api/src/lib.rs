@@ -5,17 +5,37 @@
 //! Routes from spec section 6.
 
 use axum::{
-    extract::{Json, State},
+    extract::{Json, Query, State},
     http::{HeaderMap, StatusCode},
-    response::IntoResponse,
-    routing::post,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::{get, post},
     Router,
 };
-use cordelia_storage::{L2ItemWrite, Storage};
+use chrono::{SecondsFormat, Utc};
+use cordelia_protocol::messages::{
+    GroupDescriptor, ItemSigningFields, MembershipEntry, OwnershipTransfer,
+    GROUP_TOMBSTONE_CULTURE, MEMBERSHIP_OP_ADD, MEMBERSHIP_OP_REMOVE, MEMBERSHIP_OP_ROLE,
+};
+use cordelia_replication::hlc::{Hlc, HlcClock};
+use cordelia_replication::{
+    departure, membership, GroupAction, GroupCulture, MembershipState, Posture, Role,
+    SecurityPolicy,
+};
+use cordelia_storage::{GroupAuthority, GroupRow, L2ItemWrite, Storage};
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+pub mod auth;
+pub mod events;
+pub mod metrics;
+
+use auth::{authorize, authorize_global, require_role, Access};
+use events::{Change, ChangeFeed, ChangeKind, EventFilter};
+
 /// Notification sent when a local L2 write occurs (for replication dispatch).
 #[derive(Debug, Clone)]
 pub struct WriteNotification {
@@ -26,6 +46,16 @@ pub struct WriteNotification {
     pub key_version: u32,
     pub parent_id: Option<String>,
     pub is_copy: bool,
+    /// RFC 3339 expiry (explicit or from the group's `ttl_default`).
+    pub expires_at: Option<String>,
+    /// Hex-encoded key of this node, when it signed the item.
+    pub author_pubkey: Option<String>,
+    /// Author signature over the item (see `ItemSigningFields`).
+    pub author_signature: Option<String>,
+    /// HLC version the write was stored with.
+    pub hlc: Option<String>,
+    /// Checksums of the versions the write replaced, newest first.
+    pub ancestry: Option<Vec<String>>,
 }
 
 /// Replication diagnostics counters -- shared between replication task and API.
@@ -50,6 +80,8 @@ pub struct ReplicationStats {
     pub write_buffer_depth: AtomicU64,
     /// Pending push retries in queue (set, not incremented).
     pub pending_push_count: AtomicU64,
+    /// Items removed by expiry GC (explicit expiry or group `ttl_default`).
+    pub items_expired: AtomicU64,
 }
 
 impl ReplicationStats {
@@ -65,6 +97,7 @@ impl ReplicationStats {
             sync_errors: AtomicU64::new(0),
             write_buffer_depth: AtomicU64::new(0),
             pending_push_count: AtomicU64::new(0),
+            items_expired: AtomicU64::new(0),
         }
     }
 }
@@ -89,11 +122,27 @@ pub struct PeerDetail {
     pub addrs: Vec<String>,
     pub state: String,
     pub rtt_ms: Option<f64>,
+    /// Items we pushed to the peer. Informational: not part of the score.
     pub items_delivered: u64,
+    pub score: PeerScore,
     pub groups: Vec<String>,
     pub group_intersection: Vec<String>,
     pub is_relay: bool,
     pub protocol_version: u16,
+    /// Estimated peer clock offset (peer minus us) in milliseconds.
+    pub clock_offset_ms: Option<i64>,
+}
+
+/// Governor score of a peer, with the receiver-verified contributions it
+/// is computed from (this connection only).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PeerScore {
+    pub score: f64,
+    pub items_stored: u64,
+    pub first_deliveries: u64,
+    pub items_rejected: u64,
+    pub requests_served: u64,
+    pub requests_failed: u64,
 }
 
 /// Callback to get peer list from the node's peer pool.
@@ -103,6 +152,125 @@ pub type PeerListFn = Box<
         + Sync,
 >;
 
+/// Hot-peer coverage of one of our groups, from the governor.
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupCoverage {
+    pub group_id: String,
+    /// Hot peers carrying the group (members, or relays advertising it).
+    pub hot: usize,
+    /// Warm peers carrying it (promotion candidates).
+    pub warm: usize,
+    /// Configured `hot_per_group`.
+    pub target: usize,
+    /// Whether `hot >= target`.
+    pub covered: bool,
+}
+
+/// Callback to get per-group coverage from the governor.
+pub type GroupCoverageFn = Box<
+    dyn Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = Vec<GroupCoverage>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// Longest operator ban accepted by `POST /api/v1/peers/ban` (one year).
+pub const MAX_BAN_DURATION_SECS: u64 = 365 * 24 * 3600;
+
+/// Operator action on a peer, from the `peers/*` admin routes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeerAdminAction {
+    /// Dial a multiaddr.
+    Connect { addr: String },
+    /// Drop the connection to a peer.
+    Disconnect { peer_id: String },
+    /// Ban (and disconnect) a peer. No duration = escalating default.
+    Ban {
+        peer_id: String,
+        reason: String,
+        duration_secs: Option<u64>,
+    },
+    /// Lift a ban early.
+    Unban { peer_id: String },
+    /// Exempt a peer from churn and demotion (or undo).
+    Pin { peer_id: String, pinned: bool },
+}
+
+impl PeerAdminAction {
+    /// Action name recorded in `access_log`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Connect { .. } => "peer_connect",
+            Self::Disconnect { .. } => "peer_disconnect",
+            Self::Ban { .. } => "peer_ban",
+            Self::Unban { .. } => "peer_unban",
+            Self::Pin { pinned: true, .. } => "peer_pin",
+            Self::Pin { pinned: false, .. } => "peer_unpin",
+        }
+    }
+
+    /// Peer ID or address acted on.
+    pub fn target(&self) -> &str {
+        match self {
+            Self::Connect { addr } => addr,
+            Self::Disconnect { peer_id }
+            | Self::Ban { peer_id, .. }
+            | Self::Unban { peer_id }
+            | Self::Pin { peer_id, .. } => peer_id,
+        }
+    }
+
+    /// Check request fields the governor does not (run after auth).
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Ban { reason, .. } if reason.trim().is_empty() => {
+                Err("reason is required".into())
+            }
+            Self::Ban {
+                duration_secs: Some(0),
+                ..
+            } => Err("duration_secs must be positive".into()),
+            Self::Ban {
+                duration_secs: Some(secs),
+                ..
+            } if *secs > MAX_BAN_DURATION_SECS => Err(format!(
+                "duration_secs must be at most {MAX_BAN_DURATION_SECS}"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Why a peer admin action was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeerAdminError {
+    /// Malformed peer ID or multiaddr.
+    Invalid(String),
+    /// Peer unknown to the governor (or not in the required state).
+    NotFound(String),
+    /// The node could not carry out the action (e.g. swarm shut down).
+    Failed(String),
+}
+
+/// Callback carrying out a peer admin action (governor and swarm).
+pub type PeerAdminFn = Box<
+    dyn Fn(
+            PeerAdminAction,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<(), PeerAdminError>> + Send>,
+        > + Send
+        + Sync,
+>;
+
+/// Callback signing a payload with the node's Ed25519 key.
+pub type SignFn = Box<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
+/// Node key used to sign membership log entries.
+pub struct NodeSigner {
+    /// Hex-encoded Ed25519 public key.
+    pub pubkey_hex: String,
+    pub sign: SignFn,
+}
+
 /// Shared state for all API handlers.
 pub struct AppState {
     pub storage: Box<dyn Storage>,
@@ -114,9 +282,33 @@ pub struct AppState {
     pub shared_groups: Option<std::sync::Arc<tokio::sync::RwLock<Vec<String>>>>,
     pub peer_count_fn: Option<PeerCountFn>,
     pub peer_list_fn: Option<PeerListFn>,
+    /// Per-group hot-peer coverage for `/api/v1/peers`.
+    pub group_coverage_fn: Option<GroupCoverageFn>,
+    /// Operator peer management (`peers/connect`, `ban`, ...).
+    pub peer_admin_fn: Option<PeerAdminFn>,
     pub replication_stats: Option<Arc<ReplicationStats>>,
     /// Signal to trigger immediate anti-entropy sync for a newly added group.
     pub bootstrap_sync: Option<tokio::sync::mpsc::Sender<String>>,
+    /// Live change feed (`GET /api/v1/events`), shared with replication and swarm.
+    pub change_feed: Option<Arc<ChangeFeed>>,
+    /// Governor peer counts by state (hot/warm/cold/banned) for `/metrics`.
+    pub governor_counts_fn: Option<metrics::GovernorCountsFn>,
+    /// Per-protocol request latency, recorded by the swarm task.
+    pub protocol_metrics: Option<Arc<metrics::ProtocolMetrics>>,
+    /// Revoked device IDs, so open sessions (SSE) close immediately.
+    pub device_revocations: tokio::sync::broadcast::Sender<String>,
+    /// Signs membership log entries (None = membership changes stay local).
+    pub signer: Option<NodeSigner>,
+    /// Node HLC, stamping local item and group versions.
+    pub hlc: Arc<HlcClock>,
+}
+
+impl AppState {
+    fn publish(&self, change: Change) {
+        if let Some(feed) = &self.change_feed {
+            feed.publish(self.storage.as_ref(), change);
+        }
+    }
 }
 
 /// Build the axum router.
@@ -130,38 +322,48 @@ pub fn router(state: Arc<AppState>) -> Router {
         .route("/api/v1/l2/write", post(l2_write))
         .route("/api/v1/l2/delete", post(l2_delete))
         .route("/api/v1/l2/search", post(l2_search))
+        .route("/api/v1/l2/conflicts", post(l2_conflicts))
         .route("/api/v1/groups/create", post(groups_create))
         .route("/api/v1/groups/list", post(groups_list))
         .route("/api/v1/groups/read", post(groups_read))
         .route("/api/v1/groups/items", post(groups_items))
         .route("/api/v1/groups/delete", post(groups_delete))
+        .route("/api/v1/groups/leave", post(groups_leave))
         .route("/api/v1/groups/add_member", post(groups_add_member))
         .route("/api/v1/groups/remove_member", post(groups_remove_member))
         .route("/api/v1/groups/update_posture", post(groups_update_posture))
+        .route("/api/v1/groups/departures", post(groups_departures))
+        .route("/api/v1/groups/transfer_owner", post(groups_transfer_owner))
+        .route("/api/v1/groups/set_admins", post(groups_set_admins))
         .route("/api/v1/devices/register", post(devices_register))
         .route("/api/v1/devices/list", post(devices_list))
         .route("/api/v1/devices/revoke", post(devices_revoke))
         .route("/api/v1/status", post(status))
         .route("/api/v1/peers", post(peers))
+        .route("/api/v1/peers/connect", post(peers_connect))
+        .route("/api/v1/peers/disconnect", post(peers_disconnect))
+        .route("/api/v1/peers/ban", post(peers_ban))
+        .route("/api/v1/peers/unban", post(peers_unban))
+        .route("/api/v1/peers/pin", post(peers_pin))
         .route("/api/v1/diagnostics", post(diagnostics))
+        // Streaming (SSE) is GET so standard EventSource clients can resume
+        .route("/api/v1/events", get(events_stream))
+        .route("/metrics", get(metrics_authed))
         .with_state(state)
 }
 
-// ============================================================================
-// Auth middleware (inline check)
-// ============================================================================
-
-fn check_auth(state: &AppState, headers: &HeaderMap) -> Result<(), (StatusCode, &'static str)> {
-    let auth = headers
-        .get("authorization")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    let expected = format!("Bearer {}", state.bearer_token);
-    if auth != expected {
-        return Err((StatusCode::UNAUTHORIZED, "invalid bearer token"));
+/// Standalone `/metrics` router for a separate listen address (scrapers
+/// usually cannot send a bearer token, so auth is optional here).
+pub fn metrics_router(state: Arc<AppState>, require_token: bool) -> Router {
+    if require_token {
+        Router::new()
+            .route("/metrics", get(metrics_authed))
+            .with_state(state)
+    } else {
+        Router::new()
+            .route("/metrics", get(metrics_open))
+            .with_state(state)
     }
-    Ok(())
 }
 
 // ============================================================================
@@ -210,6 +412,8 @@ pub struct L2MetaResponse {
     pub checksum: Option<String>,
     pub parent_id: Option<String>,
     pub is_copy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -231,6 +435,12 @@ pub struct L2WriteMeta {
     pub key_version: Option<i32>,
     pub parent_id: Option<String>,
     pub is_copy: Option<bool>,
+    /// RFC 3339 expiry. Defaults to the group's `ttl_default`, if any.
+    pub expires_at: Option<String>,
+    /// Checksums of conflict siblings this write merges (see `l2/conflicts`).
+    /// They are cleared here and, via the item's ancestry, on every peer.
+    #[serde(default)]
+    pub resolves: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -238,6 +448,14 @@ pub struct L2DeleteRequest {
     pub item_id: String,
 }
 
+#[derive(Deserialize, Default)]
+pub struct L2ConflictsRequest {
+    /// Only this item's conflicts.
+    pub item_id: Option<String>,
+    /// Only conflicts in this group.
+    pub group_id: Option<String>,
+}
+
 #[derive(Deserialize)]
 pub struct L2SearchRequest {
     pub query: String,
@@ -279,18 +497,42 @@ pub struct GroupDeleteRequest {
     pub group_id: String,
 }
 
+#[derive(Deserialize)]
+pub struct GroupLeaveRequest {
+    pub group_id: String,
+}
+
 #[derive(Deserialize)]
 pub struct GroupAddMemberRequest {
     pub group_id: String,
     pub entity_id: String,
     #[serde(default = "default_member_role")]
     pub role: String,
+    /// Hex-encoded node key of the member, recorded in the membership log
+    /// (lets an admin's node sign later entries).
+    #[serde(default)]
+    pub pubkey: Option<String>,
 }
 
 fn default_member_role() -> String {
     "member".into()
 }
 
+#[derive(Deserialize)]
+pub struct GroupTransferOwnerRequest {
+    pub group_id: String,
+    pub new_owner_id: String,
+    /// Hex-encoded Ed25519 node key of the new owner.
+    pub new_owner_pubkey: String,
+}
+
+#[derive(Deserialize)]
+pub struct GroupSetAdminsRequest {
+    pub group_id: String,
+    /// Hex-encoded Ed25519 keys allowed to sign culture updates.
+    pub admins: Vec<String>,
+}
+
 #[derive(Deserialize)]
 pub struct GroupRemoveMemberRequest {
     pub group_id: String,
@@ -317,6 +559,13 @@ pub struct DeviceRegisterRequest {
     #[serde(default = "default_device_type")]
     pub device_type: String,
     pub auth_token_hash: String,
+    /// API scopes for this device's token (default: read-only).
+    #[serde(default = "default_device_scopes")]
+    pub scopes: String,
+}
+
+fn default_device_scopes() -> String {
+    "read".into()
 }
 
 fn default_device_type() -> String {
@@ -334,8 +583,38 @@ pub struct DeviceRevokeRequest {
     pub device_id: String,
 }
 
+#[derive(Deserialize)]
+pub struct PeerConnectRequest {
+    pub addr: String,
+}
+
+#[derive(Deserialize)]
+pub struct PeerIdRequest {
+    pub peer_id: String,
+}
+
+#[derive(Deserialize)]
+pub struct PeerBanRequest {
+    pub peer_id: String,
+    pub reason: String,
+    /// Ban length. Default: the base ban duration, escalating on repeats.
+    pub duration_secs: Option<u64>,
+}
+
+#[derive(Deserialize)]
+pub struct PeerPinRequest {
+    pub peer_id: String,
+    /// false unpins.
+    #[serde(default = "default_pinned")]
+    pub pinned: bool,
+}
+
+fn default_pinned() -> bool {
+    true
+}
+
 fn default_culture() -> String {
-    r#"{"broadcast_eagerness":"chatty"}"#.into()
+    GroupCulture::default().to_canonical_json()
 }
 
 fn default_security_policy() -> String {
@@ -350,6 +629,9 @@ pub struct StatusResponse {
     pub peers_warm: usize,
     pub peers_hot: usize,
     pub groups: Vec<String>,
+    /// Hex-encoded Ed25519 node key (for `transfer_owner`, `set_admins`, `add_member`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_pubkey: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -358,6 +640,8 @@ pub struct PeersResponse {
     pub hot: usize,
     pub total: usize,
     pub peers: Vec<PeerDetail>,
+    /// Hot-peer coverage of each of our groups.
+    pub coverage: Vec<GroupCoverage>,
 }
 
 // ============================================================================
@@ -369,7 +653,7 @@ async fn l1_read(
     headers: HeaderMap,
     Json(req): Json<L1ReadRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Read) {
         return e.into_response();
     }
 
@@ -395,7 +679,7 @@ async fn l1_write(
     headers: HeaderMap,
     Json(req): Json<L1WriteRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Write) {
         return e.into_response();
     }
 
@@ -411,7 +695,7 @@ async fn l1_delete(
     headers: HeaderMap,
     Json(req): Json<L1DeleteRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Write) {
         return e.into_response();
     }
 
@@ -427,7 +711,7 @@ async fn l1_list(
     headers: HeaderMap,
     Json(_req): Json<L1ListRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Read) {
         return e.into_response();
     }
 
@@ -442,11 +726,21 @@ async fn l2_read(
     headers: HeaderMap,
     Json(req): Json<L2ReadRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
-    }
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
 
     match state.storage.read_l2_item(&req.item_id) {
+        // Expired but not yet garbage-collected: treat as gone
+        Ok(Some(row))
+            if cordelia_replication::is_expired(row.expires_at.as_deref(), Utc::now()) =>
+        {
+            (StatusCode::NOT_FOUND, "not found").into_response()
+        }
+        Ok(Some(row)) if !principal.allows_group(row.group_id.as_deref()) => {
+            (StatusCode::FORBIDDEN, "token does not permit this group").into_response()
+        }
         Ok(Some(row)) => {
             let data_val = serde_json::from_slice::<serde_json::Value>(&row.data)
                 .unwrap_or(serde_json::Value::Null);
@@ -463,6 +757,7 @@ async fn l2_read(
                     checksum: row.checksum,
                     parent_id: row.parent_id,
                     is_copy: row.is_copy,
+                    expires_at: row.expires_at,
                 },
             })
             .into_response()
@@ -477,9 +772,10 @@ async fn l2_write(
     headers: HeaderMap,
     Json(req): Json<L2WriteRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
-    }
+    let principal = match authorize(&state, &headers, Access::Write) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
 
     let data = match serde_json::to_vec(&req.data) {
         Ok(d) => d,
@@ -518,6 +814,164 @@ async fn l2_write(
 
     let meta = req.meta.unwrap_or_default();
 
+    let existing_group = state
+        .storage
+        .read_l2_item_meta(&req.item_id)
+        .ok()
+        .flatten()
+        .map(|m| m.group_id);
+    // Group-limited tokens may neither move an item out of, nor write into, other groups
+    if let Some(existing) = &existing_group {
+        if !principal.allows_group(existing.as_deref()) {
+            return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+        }
+    }
+    if (meta.group_id.is_some() || existing_group.is_none())
+        && !principal.allows_group(meta.group_id.as_deref())
+    {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+
+    // Viewers (and non-members, once membership is known) cannot write
+    let target_group = meta
+        .group_id
+        .clone()
+        .or_else(|| existing_group.clone().flatten());
+    let mut role_groups: Vec<&str> = existing_group
+        .iter()
+        .flatten()
+        .chain(target_group.iter())
+        .map(String::as_str)
+        .collect();
+    role_groups.dedup();
+    for gid in role_groups {
+        if let Err(e) = require_role(
+            &state,
+            &principal,
+            gid,
+            GroupAction::Write,
+            "l2_item",
+            Some(&req.item_id),
+        ) {
+            return e.into_response();
+        }
+    }
+
+    if let Some(gid) = target_group.as_deref() {
+        if let Err(e) = require_not_departed(&state, gid) {
+            return e.into_response();
+        }
+
+        // The group's security policy may narrow item types and size
+        let policy = SecurityPolicy::of(state.storage.as_ref(), gid);
+        if !policy.allows_type(&req.item_type) {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "group '{gid}' security policy does not allow item type '{}'",
+                    req.item_type
+                ),
+            )
+                .into_response();
+        }
+        if data.len() > policy.max_item_bytes() {
+            return (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "Conditions Not Met: item is {} bytes but group '{gid}' limit is {} bytes",
+                    data.len(),
+                    policy.max_item_bytes()
+                ),
+            )
+                .into_response();
+        }
+    }
+
+    // Resolve expiry: explicit value wins, else the group's ttl_default
+    let now = Utc::now();
+    let expires_at = match meta.expires_at.as_deref() {
+        Some(ts) => match cordelia_replication::parse_timestamp(ts) {
+            Some(exp) if exp > now => Some(exp.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            Some(_) => {
+                return (StatusCode::BAD_REQUEST, "expires_at is in the past").into_response();
+            }
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!("invalid expires_at '{ts}' -- expected RFC 3339"),
+                )
+                    .into_response();
+            }
+        },
+        None => meta
+            .group_id
+            .as_deref()
+            .and_then(|gid| state.storage.read_group(gid).ok().flatten())
+            .and_then(|g| GroupCulture::load(&g.culture).default_expiry(now)),
+    };
+
+    // Ancestry: the version being replaced, any siblings merged into this
+    // write, then what the replaced version itself descended from
+    let previous = state.storage.read_l2_item(&req.item_id).ok().flatten();
+    if !meta.resolves.is_empty() {
+        let siblings = state
+            .storage
+            .list_l2_conflicts(Some(&req.item_id))
+            .unwrap_or_default();
+        if let Some(cs) = meta
+            .resolves
+            .iter()
+            .find(|cs| !siblings.iter().any(|c| &c.checksum == *cs))
+        {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("'{cs}' is not a conflict sibling of item '{}'", req.item_id),
+            )
+                .into_response();
+        }
+    }
+    let ancestry = match &previous {
+        Some(prev) => {
+            let mut ancestry: Vec<String> = Vec::new();
+            for cs in prev
+                .checksum
+                .iter()
+                .chain(&meta.resolves)
+                .chain(prev.ancestry.iter().flatten())
+            {
+                if !ancestry.contains(cs) {
+                    ancestry.push(cs.clone());
+                }
+            }
+            ancestry.truncate(cordelia_protocol::MAX_ITEM_ANCESTRY);
+            ancestry
+        }
+        None => Vec::new(),
+    };
+
+    let version = state.hlc.now();
+    let updated_at = cordelia_replication::format_timestamp(version.wall());
+    let hlc = version.to_string();
+    // Sign group items with the node key so peers can verify the author
+    let (author_pubkey, author_signature) = match meta.group_id.as_deref() {
+        Some(gid) => {
+            let checksum = cordelia_replication::item_checksum(&data, expires_at.as_deref());
+            sign_item(
+                &state,
+                &ItemSigningFields {
+                    item_id: &req.item_id,
+                    group_id: gid,
+                    item_type: &req.item_type,
+                    checksum: &checksum,
+                    updated_at: &updated_at,
+                    expires_at: expires_at.as_deref(),
+                    hlc: Some(&hlc),
+                    ancestry: Some(&ancestry),
+                },
+            )
+        }
+        None => (None, None),
+    };
     let write = L2ItemWrite {
         id: req.item_id,
         item_type: req.item_type,
@@ -529,11 +983,19 @@ async fn l2_write(
         key_version: meta.key_version.unwrap_or(1),
         parent_id: meta.parent_id,
         is_copy: meta.is_copy.unwrap_or(false),
-        updated_at: None, // local write: use datetime('now')
+        updated_at: Some(updated_at),
+        expires_at,
+        author_pubkey,
+        author_signature,
+        hlc: Some(hlc),
+        ancestry: Some(ancestry),
     };
 
     match state.storage.write_l2_item(&write) {
         Ok(()) => {
+            if !meta.resolves.is_empty() {
+                let _ = state.storage.delete_l2_conflicts(&write.id, &meta.resolves);
+            }
             tracing::info!(
                 item_id = write.id,
                 item_type = write.item_type,
@@ -542,6 +1004,13 @@ async fn l2_write(
                 is_copy = write.is_copy,
                 "mem: l2 item written"
             );
+            state.publish(Change::item(
+                ChangeKind::ItemWritten,
+                write.group_id.as_deref(),
+                &write.id,
+                &write.item_type,
+                write.author_id.as_deref(),
+            ));
             // Notify replication task of the write
             if let Some(tx) = &state.write_notify {
                 let _ = tx.send(WriteNotification {
@@ -552,6 +1021,11 @@ async fn l2_write(
                     key_version: write.key_version as u32,
                     parent_id: write.parent_id,
                     is_copy: write.is_copy,
+                    expires_at: write.expires_at,
+                    author_pubkey: write.author_pubkey,
+                    author_signature: write.author_signature,
+                    hlc: write.hlc,
+                    ancestry: write.ancestry,
                 });
             }
             Json(serde_json::json!({ "ok": true })).into_response()
@@ -563,24 +1037,92 @@ async fn l2_write(
     }
 }
 
+/// This node's key and its author signature over a group item, if the
+/// node has a key.
+fn sign_item(state: &AppState, fields: &ItemSigningFields) -> (Option<String>, Option<String>) {
+    let Some(signer) = &state.signer else {
+        return (None, None);
+    };
+    (
+        Some(signer.pubkey_hex.clone()),
+        Some(hex::encode((signer.sign)(&fields.payload()))),
+    )
+}
+
+/// Items of a departed (tombstoned) group are retained read-only: 409 on
+/// any write or delete.
+fn require_not_departed(state: &AppState, gid: &str) -> Result<(), (StatusCode, String)> {
+    match state.storage.read_group(gid) {
+        Ok(Some(g)) if g.culture == cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE => Err((
+            StatusCode::CONFLICT,
+            format!("group '{gid}' has been left -- retained items are read-only"),
+        )),
+        _ => Ok(()),
+    }
+}
+
 async fn l2_delete(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Json(req): Json<L2DeleteRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
-    }
+    let principal = match authorize(&state, &headers, Access::Write) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
 
     // Read metadata before delete to get group_id for tombstone replication
     let meta = state.storage.read_l2_item_meta(&req.item_id).ok().flatten();
+    if let Some(m) = &meta {
+        if !principal.allows_group(m.group_id.as_deref()) {
+            return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+        }
+        if let Some(gid) = &m.group_id {
+            if let Err(e) = require_role(
+                &state,
+                &principal,
+                gid,
+                GroupAction::Write,
+                "l2_item",
+                Some(&req.item_id),
+            ) {
+                return e.into_response();
+            }
+            if let Err(e) = require_not_departed(&state, gid) {
+                return e.into_response();
+            }
+        }
+    }
 
     match state.storage.delete_l2_item(&req.item_id) {
         Ok(true) => {
             tracing::info!(item_id = req.item_id, "mem: l2 item deleted");
+            state.publish(Change::item(
+                ChangeKind::ItemDeleted,
+                meta.as_ref().and_then(|m| m.group_id.as_deref()),
+                &req.item_id,
+                "__tombstone__",
+                Some(&state.entity_id),
+            ));
             // Tombstone replication: notify peers to delete this item
             if let Some(ref meta) = meta {
                 if let (Some(tx), Some(group_id)) = (&state.write_notify, &meta.group_id) {
+                    let checksum = cordelia_replication::item_checksum(&[], None);
+                    let version = state.hlc.now();
+                    let hlc = version.to_string();
+                    let (author_pubkey, author_signature) = sign_item(
+                        &state,
+                        &ItemSigningFields {
+                            item_id: &req.item_id,
+                            group_id,
+                            item_type: "__tombstone__",
+                            checksum: &checksum,
+                            updated_at: &cordelia_replication::format_timestamp(version.wall()),
+                            expires_at: None,
+                            hlc: Some(&hlc),
+                            ancestry: None,
+                        },
+                    );
                     let _ = tx.send(WriteNotification {
                         item_id: req.item_id.clone(),
                         item_type: "__tombstone__".into(),
@@ -589,6 +1131,11 @@ async fn l2_delete(
                         key_version: meta.key_version as u32,
                         parent_id: None,
                         is_copy: false,
+                        expires_at: None,
+                        author_pubkey,
+                        author_signature,
+                        hlc: Some(hlc),
+                        ancestry: None,
                     });
                     tracing::info!(
                         item_id = req.item_id,
@@ -604,17 +1151,87 @@ async fn l2_delete(
     }
 }
 
+/// Items with concurrent edits kept as conflict siblings, each with its
+/// current version. A write listing sibling checksums in `meta.resolves`
+/// settles them.
+async fn l2_conflicts(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<L2ConflictsRequest>,
+) -> impl IntoResponse {
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+
+    let rows = match state.storage.list_l2_conflicts(req.item_id.as_deref()) {
+        Ok(rows) => rows,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    let json_data = |data: &[u8]| {
+        serde_json::from_slice::<serde_json::Value>(data).unwrap_or(serde_json::Value::Null)
+    };
+
+    // Rows are ordered by item, so siblings of one item are adjacent
+    let mut conflicts = Vec::new();
+    for siblings in rows.chunk_by(|a, b| a.item_id == b.item_id) {
+        let item_id = &siblings[0].item_id;
+        let Ok(Some(current)) = state.storage.read_l2_item(item_id) else {
+            continue;
+        };
+        if !principal.allows_group(current.group_id.as_deref())
+            || req
+                .group_id
+                .as_ref()
+                .is_some_and(|gid| current.group_id.as_ref() != Some(gid))
+        {
+            continue;
+        }
+        conflicts.push(serde_json::json!({
+            "item_id": item_id,
+            "group_id": current.group_id,
+            "current": {
+                "checksum": current.checksum,
+                "author_id": current.author_id,
+                "updated_at": current.updated_at,
+                "hlc": current.hlc,
+                "data": json_data(&current.data),
+            },
+            "siblings": siblings
+                .iter()
+                .map(|c| serde_json::json!({
+                    "checksum": c.checksum,
+                    "author_id": c.author_id,
+                    "updated_at": c.updated_at,
+                    "hlc": c.hlc,
+                    "data": json_data(&c.data),
+                    "detected_at": c.detected_at,
+                }))
+                .collect::<Vec<_>>(),
+        }));
+    }
+    Json(serde_json::json!({ "conflicts": conflicts })).into_response()
+}
+
 async fn l2_search(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Json(req): Json<L2SearchRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+
+    if let Some(gid) = req.group_id.as_deref() {
+        if !principal.allows_group(Some(gid)) {
+            return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+        }
     }
+    let post_filter = req.group_id.is_some() || principal.is_group_limited();
 
     // Fetch more results if group_id filter is active (post-filter needs headroom)
-    let fetch_limit = if req.group_id.is_some() {
+    let fetch_limit = if post_filter {
         req.limit * 4
     } else {
         req.limit
@@ -622,8 +1239,8 @@ async fn l2_search(
 
     match state.storage.fts_search(&req.query, fetch_limit) {
         Ok(ids) => {
-            let filtered = if let Some(ref group_id) = req.group_id {
-                // Post-filter: only return items belonging to the requested group
+            let filtered = if post_filter {
+                // Post-filter: only items in the requested group that the token may see
                 ids.into_iter()
                     .filter(|id| {
                         state
@@ -631,7 +1248,11 @@ async fn l2_search(
                             .read_l2_item_meta(id)
                             .ok()
                             .flatten()
-                            .map(|m| m.group_id.as_deref() == Some(group_id))
+                            .map(|m| {
+                                let gid = m.group_id.as_deref();
+                                req.group_id.as_deref().is_none_or(|g| gid == Some(g))
+                                    && principal.allows_group(gid)
+                            })
                             .unwrap_or(false)
                     })
                     .take(req.limit as usize)
@@ -650,21 +1271,48 @@ async fn groups_create(
     headers: HeaderMap,
     Json(req): Json<GroupCreateRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+    // Re-creating an existing group rewrites its culture: owner/admin only
+    if let Err(e) = require_role(
+        &state,
+        &principal,
+        &req.group_id,
+        GroupAction::Administer,
+        "group",
+        Some(&req.group_id),
+    ) {
         return e.into_response();
     }
+    let culture = match GroupCulture::parse(&req.culture) {
+        Ok(c) => c.to_canonical_json(),
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+    if let Err(e) = SecurityPolicy::parse(&req.security_policy) {
+        return (StatusCode::BAD_REQUEST, e).into_response();
+    }
 
     match state
         .storage
-        .write_group(&req.group_id, &req.name, &req.culture, &req.security_policy)
+        .write_group(&req.group_id, &req.name, &culture, &req.security_policy)
     {
         Ok(()) => {
+            let version = state.hlc.now().to_string();
+            if let Err(e) = state.storage.set_group_hlc(&req.group_id, Some(&version)) {
+                tracing::warn!(group_id = req.group_id, error = %e, "mem: failed to stamp group version");
+            }
             tracing::info!(
                 group_id = req.group_id,
                 name = req.name,
-                culture = req.culture,
+                culture,
                 "mem: group created"
             );
+            state.publish(Change::group(ChangeKind::GroupUpdated, &req.group_id));
             // Push new group into shared dynamic groups
             if let Some(shared) = &state.shared_groups {
                 let mut groups = shared.write().await;
@@ -695,15 +1343,17 @@ async fn groups_create(
 }
 
 async fn groups_list(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
-    }
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
 
     match state.storage.list_groups() {
         Ok(groups) => {
             let live: Vec<_> = groups
                 .into_iter()
                 .filter(|g| g.culture != cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE)
+                .filter(|g| principal.allows_group(Some(&g.id)))
                 .collect();
             Json(serde_json::json!({ "groups": live })).into_response()
         }
@@ -716,8 +1366,12 @@ async fn groups_read(
     headers: HeaderMap,
     Json(req): Json<GroupReadRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
     }
 
     match state.storage.read_group(&req.group_id) {
@@ -731,9 +1385,18 @@ async fn groups_read(
                 .storage
                 .list_members(&req.group_id)
                 .unwrap_or_default();
+            // What this node emits for the group under its own posture
+            let posture = Posture::of(state.storage.as_ref(), &req.group_id, &state.entity_id);
             Json(serde_json::json!({
                 "group": group,
                 "members": members,
+                "local_posture": {
+                    "posture": posture.as_str(),
+                    "eager_push": posture.pushes(),
+                    "serves_sync": posture.serves(),
+                    "pulls_sync": posture.pulls(),
+                    "advertised": posture.advertises(),
+                },
             }))
             .into_response()
         }
@@ -747,8 +1410,12 @@ async fn groups_items(
     headers: HeaderMap,
     Json(req): Json<GroupItemsRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
     }
 
     match state
@@ -765,63 +1432,200 @@ async fn groups_delete(
     headers: HeaderMap,
     Json(req): Json<GroupDeleteRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
     }
 
-    // Check group exists
-    match state.storage.read_group(&req.group_id) {
-        Ok(Some(_)) => {}
+    // Check group exists (and capture culture before the tombstone overwrites it)
+    let group = match state.storage.read_group(&req.group_id) {
+        Ok(Some(g)) => g,
         Ok(None) => return (StatusCode::NOT_FOUND, "group not found").into_response(),
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    if let Err(e) = require_role(
+        &state,
+        &principal,
+        &req.group_id,
+        GroupAction::Delete,
+        "group",
+        Some(&req.group_id),
+    ) {
+        return e.into_response();
+    }
+    // Peers only accept an owner-signed tombstone for a group that has ever
+    // been signed, so only the owner key holder may delete one. A group
+    // never signed gets an unsigned tombstone unless we own it locally.
+    let ever_signed = group.owner_pubkey.is_some() || group.signature.is_some();
+    let signer = match owner_signer(&state, &group) {
+        Ok(s) => Some(s),
+        Err(e) if ever_signed => return e.into_response(),
+        Err(_) => None,
+    };
+    depart_group(&state, group, signer, departure::REASON_LOCAL_LEAVE).await
+}
+
+/// Leave a group this node does not own. Unlike `groups_delete` nothing
+/// is signed: the tombstone stays on this node (see `departure::left`).
+async fn groups_leave(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<GroupLeaveRequest>,
+) -> impl IntoResponse {
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+
+    let group = match state.storage.read_group(&req.group_id) {
+        Ok(Some(g)) if g.culture != GROUP_TOMBSTONE_CULTURE => g,
+        Ok(_) => return (StatusCode::NOT_FOUND, "group not found").into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    if holds_owner_key(&state, &group) {
+        return (
+            StatusCode::CONFLICT,
+            "the owner cannot leave -- transfer ownership or delete the group",
+        )
+            .into_response();
     }
+    depart_group(&state, group, None, departure::REASON_MEMBER_LEAVE).await
+}
+
+/// Tombstone `group` on this node (signed and propagated if `signer` is
+/// given), stop replicating it and apply its departure_policy.
+async fn depart_group(
+    state: &AppState,
+    group: GroupRow,
+    signer: Option<&NodeSigner>,
+    reason: &str,
+) -> Response {
+    let group_id = group.id.clone();
+    let prior_culture = group.culture.clone();
 
     // Write tombstone descriptor (culture = __deleted__) instead of deleting.
     // This propagates via GroupExchange to peers using LWW semantics.
-    let tombstone_culture = cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE;
+    let tombstone_culture = GROUP_TOMBSTONE_CULTURE;
     if let Err(e) = state
         .storage
-        .write_group(&req.group_id, &req.group_id, tombstone_culture, "{}")
+        .write_group(&group_id, &group_id, tombstone_culture, "{}")
     {
         return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response();
     }
+    let version = state.hlc.now();
+    if let Err(e) = state
+        .storage
+        .set_group_hlc(&group_id, Some(&version.to_string()))
+    {
+        return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response();
+    }
+    if let Some(signer) = signer {
+        let mut desc = descriptor_now(
+            &GroupRow {
+                culture: tombstone_culture.to_string(),
+                ..group.clone()
+            },
+            &version,
+        );
+        desc.owner_id = Some(
+            group
+                .owner_id
+                .clone()
+                .unwrap_or_else(|| state.entity_id.clone()),
+        );
+        desc.owner_pubkey = Some(signer.pubkey_hex.clone());
+        if let Err(e) = sign_and_store_descriptor(state, signer, desc) {
+            return e.into_response();
+        }
+    }
 
     // Soft-remove members (CoW: posture = 'removed', no hard delete)
-    if let Ok(members) = state.storage.list_members(&req.group_id) {
+    if let Ok(members) = state.storage.list_members(&group_id) {
         for m in members {
             let _ = state
                 .storage
-                .update_member_posture(&req.group_id, &m.entity_id, "removed");
+                .update_member_posture(&group_id, &m.entity_id, "removed");
         }
     }
 
-    tracing::info!(
-        group_id = req.group_id,
-        "mem: group tombstoned for deletion"
-    );
+    tracing::info!(group_id, reason, "mem: group tombstoned for deletion");
+    if let Some(feed) = &state.change_feed {
+        feed.publish_with_culture(
+            Some(&prior_culture),
+            Change::group(ChangeKind::GroupDeleted, &group_id),
+        );
+    }
 
     // Remove from shared dynamic groups (stops item replication)
     if let Some(shared) = &state.shared_groups {
         let mut groups = shared.write().await;
-        groups.retain(|g| g != &req.group_id);
+        groups.retain(|g| g != &group_id);
         tracing::info!(
-            group_id = req.group_id,
+            group_id,
             remaining_groups = groups.len(),
             "mem: group removed from shared_groups"
         );
     }
 
+    // Enforce departure_policy on local items (re-deleting a tombstone is a no-op)
+    let departure = if prior_culture != tombstone_culture {
+        let policy = departure::departure_policy_for(&prior_culture);
+        match departure::apply_departure_policy(state.storage.as_ref(), &group_id, policy, reason) {
+            Ok(d) => Some(d),
+            Err(e) => {
+                tracing::warn!(group_id, error = %e, "mem: departure policy failed");
+                return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response();
+            }
+        }
+    } else {
+        None
+    };
+
     // Log access
     let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
         entity_id: state.entity_id.clone(),
-        action: "delete_group".into(),
+        action: if reason == departure::REASON_MEMBER_LEAVE {
+            "leave_group".into()
+        } else {
+            "delete_group".into()
+        },
         resource_type: "group".into(),
-        resource_id: Some(req.group_id.clone()),
-        group_id: Some(req.group_id),
-        detail: Some("tombstone".into()),
+        resource_id: Some(group_id.clone()),
+        group_id: Some(group_id),
+        detail: Some(match &departure {
+            Some(d) => format!("tombstone departure_policy={}", d.policy),
+            None => "tombstone".into(),
+        }),
     });
 
-    Json(serde_json::json!({ "ok": true })).into_response()
+    Json(serde_json::json!({ "ok": true, "departure": departure })).into_response()
+}
+
+async fn groups_departures(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+
+    match state.storage.list_departures() {
+        Ok(departures) => {
+            let visible: Vec<_> = departures
+                .into_iter()
+                .filter(|d| principal.allows_group(Some(&d.group_id)))
+                .collect();
+            Json(serde_json::json!({ "departures": visible })).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
 }
 
 async fn groups_add_member(
@@ -829,22 +1633,51 @@ async fn groups_add_member(
     headers: HeaderMap,
     Json(req): Json<GroupAddMemberRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+    if let Err(e) = require_role(
+        &state,
+        &principal,
+        &req.group_id,
+        GroupAction::Administer,
+        "group",
+        Some(&req.group_id),
+    ) {
         return e.into_response();
     }
 
     // Validate role
-    match req.role.as_str() {
-        "owner" | "admin" | "member" | "viewer" => {}
-        _ => {
-            return (
-                StatusCode::BAD_REQUEST,
-                "invalid role: must be owner, admin, member, or viewer",
-            )
-                .into_response()
-        }
-    }
+    let Some(role) = Role::parse(&req.role) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            "invalid role: must be owner, admin, member, or viewer",
+        )
+            .into_response();
+    };
 
+    let op = match state.storage.get_membership(&req.group_id, &req.entity_id) {
+        Ok(Some(_)) => MEMBERSHIP_OP_ROLE,
+        Ok(None) => MEMBERSHIP_OP_ADD,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    // Check authority before touching the local row
+    let entry = match authorize_member_change(
+        &state,
+        &principal,
+        &req.group_id,
+        op,
+        &req.entity_id,
+        Some(role),
+        req.pubkey.as_deref(),
+    ) {
+        Ok(entry) => entry,
+        Err(e) => return e.into_response(),
+    };
     match state
         .storage
         .add_member(&req.group_id, &req.entity_id, &req.role)
@@ -856,6 +1689,8 @@ async fn groups_add_member(
                 role = req.role,
                 "mem: member added to group"
             );
+            let logged = append_membership(&state, &req.group_id, entry);
+            state.publish(Change::member(&req.group_id, &req.entity_id));
             let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
                 entity_id: state.entity_id.clone(),
                 action: "add_member".into(),
@@ -865,7 +1700,7 @@ async fn groups_add_member(
                 detail: Some(format!("entity={} role={}", req.entity_id, req.role)),
             });
 
-            Json(serde_json::json!({ "ok": true })).into_response()
+            Json(serde_json::json!({ "ok": true, "logged": logged })).into_response()
         }
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -876,10 +1711,36 @@ async fn groups_remove_member(
     headers: HeaderMap,
     Json(req): Json<GroupRemoveMemberRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+    if let Err(e) = require_role(
+        &state,
+        &principal,
+        &req.group_id,
+        GroupAction::Administer,
+        "group",
+        Some(&req.group_id),
+    ) {
         return e.into_response();
     }
 
+    let entry = match authorize_member_change(
+        &state,
+        &principal,
+        &req.group_id,
+        MEMBERSHIP_OP_REMOVE,
+        &req.entity_id,
+        None,
+        None,
+    ) {
+        Ok(entry) => entry,
+        Err(e) => return e.into_response(),
+    };
     match state.storage.remove_member(&req.group_id, &req.entity_id) {
         Ok(true) => {
             tracing::info!(
@@ -887,6 +1748,8 @@ async fn groups_remove_member(
                 entity_id = req.entity_id,
                 "mem: member removed from group"
             );
+            let logged = append_membership(&state, &req.group_id, entry);
+            state.publish(Change::member(&req.group_id, &req.entity_id));
             let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
                 entity_id: state.entity_id.clone(),
                 action: "remove_member".into(),
@@ -896,20 +1759,415 @@ async fn groups_remove_member(
                 detail: Some(format!("entity={}", req.entity_id)),
             });
 
-            Json(serde_json::json!({ "ok": true })).into_response()
+            Json(serde_json::json!({ "ok": true, "logged": logged })).into_response()
         }
         Ok(false) => (StatusCode::NOT_FOUND, "member not found").into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
+/// Check a membership change before it is applied locally: the acting
+/// entity's role must permit it (`roles::check_member_change`), and if this
+/// node has authority in the group's membership log (its owner, or an admin
+/// whose key the log recorded) the signed entry must pass the log's rules.
+/// Returns the entry to append once applied; None if the change stays local
+/// to this node. Denials are logged to `access_log` and returned as 403.
+#[allow(clippy::too_many_arguments)]
+fn authorize_member_change(
+    state: &AppState,
+    principal: &auth::Principal,
+    group_id: &str,
+    op: &str,
+    entity_id: &str,
+    role: Option<Role>,
+    member_pubkey: Option<&str>,
+) -> Result<Option<cordelia_storage::MembershipLogRow>, (StatusCode, String)> {
+    let actor = principal.entity(state);
+    let checked = state
+        .storage
+        .list_members(group_id)
+        .map_err(|e| format!("membership lookup failed: {e}"))
+        .and_then(|members| {
+            cordelia_replication::roles::check_member_change(&members, actor, entity_id, role)
+        })
+        .and_then(|()| sign_membership(state, group_id, op, entity_id, role, member_pubkey));
+    checked.map_err(|reason| {
+        tracing::warn!(
+            group_id,
+            entity_id,
+            reason,
+            "mem: membership change refused"
+        );
+        let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
+            entity_id: actor.to_string(),
+            action: "access_denied".into(),
+            resource_type: "group".into(),
+            resource_id: Some(group_id.to_string()),
+            group_id: Some(group_id.to_string()),
+            detail: Some(reason.clone()),
+        });
+        (StatusCode::FORBIDDEN, reason)
+    })
+}
+
+/// Sign a membership log entry if this node has authority in the group,
+/// and check it against the log. Ok(None) when there is no log authority.
+fn sign_membership(
+    state: &AppState,
+    group_id: &str,
+    op: &str,
+    entity_id: &str,
+    role: Option<Role>,
+    member_pubkey: Option<&str>,
+) -> Result<Option<cordelia_storage::MembershipLogRow>, String> {
+    let Some(signer) = &state.signer else {
+        return Ok(None);
+    };
+    let log = state
+        .storage
+        .list_membership_log(group_id)
+        .map_err(|e| format!("membership log lookup failed: {e}"))?;
+    let members = match membership::state_of(state.storage.as_ref(), group_id) {
+        Ok(Some(members)) => members,
+        // Descriptor not signed yet: we will sign it if we own the group
+        Ok(None) => match state.storage.get_membership(group_id, &state.entity_id) {
+            Ok(Some(m)) if m.role == Role::Owner.as_str() => {
+                MembershipState::replay(&state.entity_id, &signer.pubkey_hex, &log)
+            }
+            _ => return Ok(None),
+        },
+        Err(e) => return Err(format!("membership log lookup failed: {e}")),
+    };
+
+    let mut entry = MembershipEntry {
+        group_id: group_id.to_string(),
+        op: op.to_string(),
+        entity_id: entity_id.to_string(),
+        role: role.map(|r| r.as_str().to_string()),
+        member_pubkey: member_pubkey.map(str::to_string),
+        created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        signer_id: state.entity_id.clone(),
+        signer_pubkey: signer.pubkey_hex.clone(),
+        signature: String::new(),
+        prev: membership::heads(&log),
+    };
+    entry.signature = hex::encode((signer.sign)(&entry.signing_payload()));
+    let row = membership::to_row(&entry);
+    members.authorize(&row)?;
+    Ok(Some(row))
+}
+
+/// Append a membership log entry for a change just applied locally.
+/// Returns whether it was logged; an unlogged change stays local to this
+/// node.
+fn append_membership(
+    state: &AppState,
+    group_id: &str,
+    entry: Option<cordelia_storage::MembershipLogRow>,
+) -> bool {
+    let Some(row) = entry else {
+        return false;
+    };
+    match state.storage.append_membership_entry(&row) {
+        Ok(_) => true,
+        Err(e) => {
+            tracing::warn!(group_id, error = %e, "mem: failed to append membership log entry");
+            false
+        }
+    }
+}
+
+/// Whether `key` looks like a hex-encoded Ed25519 public key.
+fn is_pubkey_hex(key: &str) -> bool {
+    key.len() == 64 && key.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Load a live group whose owner key this node holds, for re-signing its
+/// descriptor as owner.
+fn owned_group<'a>(
+    state: &'a AppState,
+    group_id: &str,
+) -> Result<(GroupRow, &'a NodeSigner), (StatusCode, String)> {
+    let group = match state.storage.read_group(group_id) {
+        Ok(Some(g)) if g.culture != GROUP_TOMBSTONE_CULTURE => g,
+        Ok(_) => return Err((StatusCode::NOT_FOUND, "group not found".into())),
+        Err(e) => return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    };
+    let signer = owner_signer(state, &group)?;
+    Ok((group, signer))
+}
+
+/// This node's signer, if it holds the group's owner key (or the group has
+/// no owner key yet and we own it locally).
+fn owner_signer<'a>(
+    state: &'a AppState,
+    group: &GroupRow,
+) -> Result<&'a NodeSigner, (StatusCode, String)> {
+    let signer = state.signer.as_ref().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "node key not available".to_string(),
+    ))?;
+    if !holds_owner_key(state, group) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "this node does not hold the group owner key".into(),
+        ));
+    }
+    Ok(signer)
+}
+
+/// Whether this node owns `group`: holds the owner key on its descriptor,
+/// or, for a group never signed, has the local `owner` row.
+fn holds_owner_key(state: &AppState, group: &GroupRow) -> bool {
+    match group.owner_pubkey.as_deref() {
+        Some(key) => state.signer.as_ref().is_some_and(|s| s.pubkey_hex == key),
+        None => matches!(
+            state.storage.get_membership(&group.id, &state.entity_id),
+            Ok(Some(m)) if m.role == Role::Owner.as_str()
+        ),
+    }
+}
+
+/// Sign `desc` with the node key and persist it as the group's descriptor.
+fn sign_and_store_descriptor(
+    state: &AppState,
+    signer: &NodeSigner,
+    mut desc: GroupDescriptor,
+) -> Result<GroupDescriptor, (StatusCode, String)> {
+    desc.signature = Some(hex::encode((signer.sign)(&desc.signing_payload())));
+    let authority = GroupAuthority {
+        updated_at: desc.updated_at.clone(),
+        owner_id: desc.owner_id.clone().unwrap_or_default(),
+        owner_pubkey: desc.owner_pubkey.clone().unwrap_or_default(),
+        signature: desc.signature.clone().unwrap_or_default(),
+        signer_pubkey: desc.signer_pubkey.clone(),
+        admins: desc.admins.clone().unwrap_or_default(),
+        owner_transfers: desc
+            .transfers
+            .as_ref()
+            .and_then(|t| serde_json::to_string(t).ok()),
+        hlc: desc.hlc.clone(),
+    };
+    state
+        .storage
+        .write_group_authority(&desc.id, &authority)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(desc)
+}
+
+/// Descriptor for a stored group, re-versioned at `version` (a fresh reading
+/// of the node HLC, so peers take it as newer).
+fn descriptor_now(group: &GroupRow, version: &Hlc) -> GroupDescriptor {
+    let transfers = membership::parse_transfers(group.owner_transfers.as_deref());
+    GroupDescriptor {
+        id: group.id.clone(),
+        culture: group.culture.clone(),
+        // Same format as SQLite datetime('now'), as for unsigned groups
+        updated_at: version.wall().format("%Y-%m-%d %H:%M:%S").to_string(),
+        checksum: GroupDescriptor::compute_checksum(&group.id, &group.culture),
+        owner_id: group.owner_id.clone(),
+        owner_pubkey: group.owner_pubkey.clone(),
+        signature: None,
+        admins: (!group.admins.is_empty()).then(|| group.admins.clone()),
+        signer_pubkey: None,
+        transfers: (!transfers.is_empty()).then_some(transfers),
+        security_policy: SecurityPolicy::for_descriptor(&group.security_policy),
+        hlc: Some(version.to_string()),
+    }
+}
+
+async fn groups_transfer_owner(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<GroupTransferOwnerRequest>,
+) -> impl IntoResponse {
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+    if let Err(e) = require_role(
+        &state,
+        &principal,
+        &req.group_id,
+        GroupAction::Transfer,
+        "group",
+        Some(&req.group_id),
+    ) {
+        return e.into_response();
+    }
+    if !is_pubkey_hex(&req.new_owner_pubkey) || req.new_owner_id.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            "new_owner_id and a hex Ed25519 new_owner_pubkey are required",
+        )
+            .into_response();
+    }
+    let (group, signer) = match owned_group(&state, &req.group_id) {
+        Ok(g) => g,
+        Err(e) => return e.into_response(),
+    };
+    let new_pubkey = req.new_owner_pubkey.to_ascii_lowercase();
+    if new_pubkey == signer.pubkey_hex {
+        return (
+            StatusCode::BAD_REQUEST,
+            "group is already owned by this key",
+        )
+            .into_response();
+    }
+
+    // The outgoing owner signs the handover, then the descriptor carrying it
+    let log = match state.storage.list_membership_log(&req.group_id) {
+        Ok(log) => log,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    let mut desc = descriptor_now(&group, &state.hlc.now());
+    let mut transfer = OwnershipTransfer {
+        from_pubkey: signer.pubkey_hex.clone(),
+        to_owner_id: req.new_owner_id.clone(),
+        to_pubkey: new_pubkey.clone(),
+        transferred_at: desc.updated_at.clone(),
+        signature: String::new(),
+        hlc: desc.hlc.clone(),
+        log_heads: membership::heads(&log),
+    };
+    transfer.signature = hex::encode((signer.sign)(&transfer.signing_payload(&req.group_id)));
+    desc.transfers.get_or_insert_with(Vec::new).push(transfer);
+    desc.owner_id = Some(req.new_owner_id.clone());
+    desc.owner_pubkey = Some(new_pubkey.clone());
+    desc.signer_pubkey = Some(signer.pubkey_hex.clone());
+    if let Err(e) = sign_and_store_descriptor(&state, signer, desc) {
+        return e.into_response();
+    }
+
+    tracing::info!(
+        group_id = req.group_id,
+        new_owner = req.new_owner_id,
+        "mem: group ownership transferred"
+    );
+    // New owner takes the owner row; we (and any other owner rows) become admin
+    match membership::apply(state.storage.as_ref(), &req.group_id) {
+        Ok(changed) => {
+            for entity_id in changed {
+                state.publish(Change::member(&req.group_id, &entity_id));
+            }
+        }
+        Err(e) => {
+            tracing::warn!(group_id = req.group_id, error = %e, "mem: membership update after transfer failed")
+        }
+    }
+    state.publish(Change::group(ChangeKind::GroupUpdated, &req.group_id));
+    let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
+        entity_id: principal.entity(&state).to_string(),
+        action: "transfer_owner".into(),
+        resource_type: "group".into(),
+        resource_id: Some(req.group_id.clone()),
+        group_id: Some(req.group_id.clone()),
+        detail: Some(format!("new_owner={}", req.new_owner_id)),
+    });
+
+    Json(serde_json::json!({
+        "ok": true,
+        "owner_id": req.new_owner_id,
+        "owner_pubkey": new_pubkey,
+    }))
+    .into_response()
+}
+
+async fn groups_set_admins(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<GroupSetAdminsRequest>,
+) -> impl IntoResponse {
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+    if let Err(e) = require_role(
+        &state,
+        &principal,
+        &req.group_id,
+        GroupAction::Transfer,
+        "group",
+        Some(&req.group_id),
+    ) {
+        return e.into_response();
+    }
+    if !req.admins.iter().all(|k| is_pubkey_hex(k)) {
+        return (
+            StatusCode::BAD_REQUEST,
+            "admins must be hex Ed25519 public keys",
+        )
+            .into_response();
+    }
+    let (group, signer) = match owned_group(&state, &req.group_id) {
+        Ok(g) => g,
+        Err(e) => return e.into_response(),
+    };
+
+    let mut admins: Vec<String> = req.admins.iter().map(|k| k.to_ascii_lowercase()).collect();
+    admins.sort();
+    admins.dedup();
+    let mut desc = descriptor_now(&group, &state.hlc.now());
+    desc.owner_id = Some(
+        group
+            .owner_id
+            .clone()
+            .unwrap_or_else(|| state.entity_id.clone()),
+    );
+    desc.owner_pubkey = Some(signer.pubkey_hex.clone());
+    desc.admins = (!admins.is_empty()).then(|| admins.clone());
+    if let Err(e) = sign_and_store_descriptor(&state, signer, desc) {
+        return e.into_response();
+    }
+
+    tracing::info!(
+        group_id = req.group_id,
+        admins = admins.len(),
+        "mem: group descriptor admins updated"
+    );
+    state.publish(Change::group(ChangeKind::GroupUpdated, &req.group_id));
+    let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
+        entity_id: principal.entity(&state).to_string(),
+        action: "set_admins".into(),
+        resource_type: "group".into(),
+        resource_id: Some(req.group_id.clone()),
+        group_id: Some(req.group_id.clone()),
+        detail: Some(format!("admins={}", admins.len())),
+    });
+
+    Json(serde_json::json!({ "ok": true, "admins": admins })).into_response()
+}
+
 async fn groups_update_posture(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Json(req): Json<GroupUpdatePostureRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
+    let principal = match authorize(&state, &headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if !principal.allows_group(Some(&req.group_id)) {
+        return (StatusCode::FORBIDDEN, "token does not permit this group").into_response();
+    }
+    // Members set their own posture; others' need Administer
+    if principal.entity(&state) != req.entity_id {
+        if let Err(e) = require_role(
+            &state,
+            &principal,
+            &req.group_id,
+            GroupAction::Administer,
+            "group",
+            Some(&req.group_id),
+        ) {
+            return e.into_response();
+        }
     }
 
     // Validate posture
@@ -935,6 +2193,7 @@ async fn groups_update_posture(
                 posture = req.posture,
                 "mem: member posture updated"
             );
+            state.publish(Change::member(&req.group_id, &req.entity_id));
             let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
                 entity_id: state.entity_id.clone(),
                 action: "update_posture".into(),
@@ -960,7 +2219,7 @@ async fn devices_register(
     headers: HeaderMap,
     Json(req): Json<DeviceRegisterRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Admin) {
         return e.into_response();
     }
 
@@ -976,12 +2235,17 @@ async fn devices_register(
         }
     }
 
+    if let Err(e) = auth::parse_scopes(&req.scopes) {
+        return (StatusCode::BAD_REQUEST, format!("invalid scopes: {e}")).into_response();
+    }
+
     let device = cordelia_storage::DeviceRow {
         device_id: req.device_id.clone(),
         entity_id: req.entity_id.clone(),
         device_name: req.device_name.clone(),
         device_type: req.device_type,
         auth_token_hash: req.auth_token_hash,
+        scopes: req.scopes,
         created_at: String::new(), // Set by DB default
         last_seen_at: None,
         revoked_at: None,
@@ -1013,7 +2277,7 @@ async fn devices_list(
     headers: HeaderMap,
     Json(req): Json<DeviceListRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Admin) {
         return e.into_response();
     }
 
@@ -1028,6 +2292,7 @@ async fn devices_list(
                         "entity_id": d.entity_id,
                         "device_name": d.device_name,
                         "device_type": d.device_type,
+                        "scopes": d.scopes,
                         "created_at": d.created_at,
                         "last_seen_at": d.last_seen_at,
                         "revoked_at": d.revoked_at,
@@ -1045,7 +2310,7 @@ async fn devices_revoke(
     headers: HeaderMap,
     Json(req): Json<DeviceRevokeRequest>,
 ) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Admin) {
         return e.into_response();
     }
 
@@ -1056,6 +2321,7 @@ async fn devices_revoke(
                 entity_id = req.entity_id,
                 "device: revoked"
             );
+            let _ = state.device_revocations.send(req.device_id.clone());
             let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
                 entity_id: req.entity_id,
                 action: "revoke_device".into(),
@@ -1072,9 +2338,10 @@ async fn devices_revoke(
 }
 
 async fn status(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
-        return e.into_response();
-    }
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
 
     let (warm, hot) = if let Some(f) = &state.peer_count_fn {
         f().await
@@ -1082,8 +2349,14 @@ async fn status(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl
         (0, 0)
     };
 
+    // Group-limited tokens only see their own groups
     let groups = if let Some(sg) = &state.shared_groups {
-        sg.read().await.clone()
+        sg.read()
+            .await
+            .iter()
+            .filter(|g| principal.allows_group(Some(g.as_str())))
+            .cloned()
+            .collect()
     } else {
         vec![]
     };
@@ -1095,12 +2368,13 @@ async fn status(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl
         peers_warm: warm,
         peers_hot: hot,
         groups,
+        node_pubkey: state.signer.as_ref().map(|s| s.pubkey_hex.clone()),
     })
     .into_response()
 }
 
 async fn peers(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Read) {
         return e.into_response();
     }
 
@@ -1116,17 +2390,298 @@ async fn peers(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl I
         vec![]
     };
 
+    let coverage = if let Some(f) = &state.group_coverage_fn {
+        f().await
+    } else {
+        vec![]
+    };
+
     Json(PeersResponse {
         warm,
         hot,
         total: warm + hot,
         peers,
+        coverage,
     })
     .into_response()
 }
 
+async fn peers_connect(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<PeerConnectRequest>,
+) -> impl IntoResponse {
+    peer_admin(
+        &state,
+        &headers,
+        PeerAdminAction::Connect { addr: req.addr },
+    )
+    .await
+}
+
+async fn peers_disconnect(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<PeerIdRequest>,
+) -> impl IntoResponse {
+    let action = PeerAdminAction::Disconnect {
+        peer_id: req.peer_id,
+    };
+    peer_admin(&state, &headers, action).await
+}
+
+async fn peers_ban(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<PeerBanRequest>,
+) -> impl IntoResponse {
+    let action = PeerAdminAction::Ban {
+        peer_id: req.peer_id,
+        reason: req.reason,
+        duration_secs: req.duration_secs,
+    };
+    peer_admin(&state, &headers, action).await
+}
+
+async fn peers_unban(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<PeerIdRequest>,
+) -> impl IntoResponse {
+    let action = PeerAdminAction::Unban {
+        peer_id: req.peer_id,
+    };
+    peer_admin(&state, &headers, action).await
+}
+
+async fn peers_pin(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<PeerPinRequest>,
+) -> impl IntoResponse {
+    let action = PeerAdminAction::Pin {
+        peer_id: req.peer_id,
+        pinned: req.pinned,
+    };
+    peer_admin(&state, &headers, action).await
+}
+
+/// Run a peer admin action (admin scope, node-wide) and record it in
+/// `access_log`.
+async fn peer_admin(
+    state: &AppState,
+    headers: &HeaderMap,
+    action: PeerAdminAction,
+) -> axum::response::Response {
+    let principal = match authorize_global(state, headers, Access::Admin) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    if let Err(e) = action.validate() {
+        return (StatusCode::BAD_REQUEST, e).into_response();
+    }
+    let Some(f) = &state.peer_admin_fn else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "peer management not available",
+        )
+            .into_response();
+    };
+
+    let entity_id = principal.entity(state).to_string();
+    let name = action.name();
+    let target = action.target().to_string();
+    let detail = match &action {
+        PeerAdminAction::Ban {
+            reason,
+            duration_secs,
+            ..
+        } => Some(match duration_secs {
+            Some(secs) => format!("{reason} ({secs}s)"),
+            None => reason.clone(),
+        }),
+        _ => None,
+    };
+
+    match f(action).await {
+        Ok(()) => {
+            tracing::info!(entity_id, action = name, peer = target, "api: peer admin");
+            let _ = state.storage.log_access(&cordelia_storage::AccessLogEntry {
+                entity_id,
+                action: name.into(),
+                resource_type: "peer".into(),
+                resource_id: Some(target),
+                group_id: None,
+                detail,
+            });
+            Json(serde_json::json!({ "ok": true })).into_response()
+        }
+        Err(PeerAdminError::Invalid(e)) => (StatusCode::BAD_REQUEST, e).into_response(),
+        Err(PeerAdminError::NotFound(e)) => (StatusCode::NOT_FOUND, e).into_response(),
+        Err(PeerAdminError::Failed(e)) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct EventsQuery {
+    /// Comma-separated group IDs to include (default: all).
+    pub group_id: Option<String>,
+    /// Comma-separated item types to include (default: all events).
+    pub item_type: Option<String>,
+    /// Resume cursor (last event ID seen). `Last-Event-ID` header also accepted.
+    pub cursor: Option<String>,
+}
+
+async fn events_stream(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<EventsQuery>,
+) -> axum::response::Response {
+    let principal = match authorize(&state, &headers, Access::Read) {
+        Ok(p) => p,
+        Err(e) => return e.into_response(),
+    };
+    let Some(feed) = state.change_feed.clone() else {
+        return (StatusCode::SERVICE_UNAVAILABLE, "change feed not available").into_response();
+    };
+
+    let cursor = query.cursor.or_else(|| {
+        headers
+            .get("last-event-id")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from)
+    });
+    let mut filter = EventFilter::new(query.group_id.as_deref(), query.item_type.as_deref());
+    if let Some(groups) = principal.groups() {
+        filter.restrict_groups(groups);
+    }
+    let sub = feed.subscribe(cursor.as_deref());
+
+    tracing::debug!(
+        cursor = cursor.as_deref().unwrap_or("-"),
+        replay = sub.replay.len(),
+        gap = sub.gap,
+        "mem: event stream subscribed"
+    );
+
+    // A gap means events were missed: tell the client to resync by polling
+    let reset = sub.gap.then(|| {
+        Event::default()
+            .event("reset")
+            .data("cursor expired -- resync via groups/items")
+    });
+    let initial: std::collections::VecDeque<Event> = reset
+        .into_iter()
+        .chain(
+            sub.replay
+                .iter()
+                .filter(|e| filter.matches(e))
+                .map(|e| sse_event(&feed, e)),
+        )
+        .collect();
+
+    // Device sessions end as soon as the device is revoked
+    let entity = principal.entity(&state).to_string();
+    let revoked = principal
+        .device_id
+        .clone()
+        .map(|id| (id, entity, state.device_revocations.subscribe()));
+
+    let stream = futures_util::stream::unfold(
+        EventStreamState {
+            initial,
+            live: sub.live,
+            feed,
+            filter,
+            revoked,
+            state: state.clone(),
+        },
+        |mut st| async move {
+            if let Some(ev) = st.initial.pop_front() {
+                return Some((Ok::<_, std::convert::Infallible>(ev), st));
+            }
+            loop {
+                let next = tokio::select! {
+                    next = st.live.recv() => next,
+                    Some(device_id) = revoked_device(&st.state, &mut st.revoked) => {
+                        tracing::info!(device_id, "mem: event stream closed (device revoked)");
+                        return None;
+                    }
+                };
+                match next {
+                    Ok(e) if st.filter.matches(&e) => {
+                        let ev = sse_event(&st.feed, &e);
+                        return Some((Ok(ev), st));
+                    }
+                    Ok(_) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                        tracing::warn!(missed = n, "mem: event stream subscriber lagged");
+                        let ev = Event::default()
+                            .event("reset")
+                            .data(format!("{n} events dropped -- resync via groups/items"));
+                        return Some((Ok(ev), st));
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        },
+    );
+
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+struct EventStreamState {
+    initial: std::collections::VecDeque<Event>,
+    live: tokio::sync::broadcast::Receiver<events::ChangeEvent>,
+    feed: Arc<ChangeFeed>,
+    filter: EventFilter,
+    /// (device_id, entity_id, revocation receiver) for device-token sessions.
+    revoked: Option<DeviceSession>,
+    state: Arc<AppState>,
+}
+
+type DeviceSession = (String, String, tokio::sync::broadcast::Receiver<String>);
+
+/// Resolves with the device ID once this session's device is revoked;
+/// pends forever for node-token sessions.
+async fn revoked_device(state: &AppState, revoked: &mut Option<DeviceSession>) -> Option<String> {
+    let Some((device_id, entity_id, rx)) = revoked else {
+        return std::future::pending().await;
+    };
+    loop {
+        match rx.recv().await {
+            Ok(id) if id == *device_id => return Some(id),
+            Ok(_) => continue,
+            // Missed notifications may include ours: ask storage instead
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                let active = state.storage.list_devices(entity_id).is_ok_and(|devices| {
+                    devices
+                        .iter()
+                        .any(|d| d.device_id == *device_id && d.revoked_at.is_none())
+                });
+                if !active {
+                    return Some(device_id.clone());
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                return std::future::pending().await
+            }
+        }
+    }
+}
+
+fn sse_event(feed: &ChangeFeed, event: &events::ChangeEvent) -> Event {
+    let data = serde_json::to_string(event).unwrap_or_default();
+    Event::default()
+        .id(feed.cursor(event.seq))
+        .event(event.kind.as_str())
+        .data(data)
+}
+
 async fn diagnostics(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl IntoResponse {
-    if let Err(e) = check_auth(&state, &headers) {
+    if let Err(e) = authorize_global(&state, &headers, Access::Read) {
         return e.into_response();
     }
 
@@ -1154,6 +2709,24 @@ async fn diagnostics(State(state): State<Arc<AppState>>, headers: HeaderMap) ->
             "sync_errors": stats.sync_errors.load(Ordering::Relaxed),
             "write_buffer_depth": stats.write_buffer_depth.load(Ordering::Relaxed),
             "pending_push_count": stats.pending_push_count.load(Ordering::Relaxed),
+            "items_expired": stats.items_expired.load(Ordering::Relaxed),
+        })
+    } else {
+        serde_json::json!("not available")
+    };
+
+    let governor = if let Some(f) = &state.governor_counts_fn {
+        let c = f().await;
+        serde_json::json!({
+            "hot": c.hot,
+            "warm": c.warm,
+            "cold": c.cold,
+            "banned": c.banned,
+            "protected": c.protected,
+            "diversity_rejections": {
+                "subnet": c.subnet_rejections,
+                "share_source": c.share_source_rejections,
+            },
         })
     } else {
         serde_json::json!("not available")
@@ -1193,12 +2766,34 @@ async fn diagnostics(State(state): State<Arc<AppState>>, headers: HeaderMap) ->
             "hot": hot,
         },
         "groups": groups,
+        "governor": governor,
         "replication": repl,
         "mempool": mempool,
     }))
     .into_response()
 }
 
+async fn metrics_authed(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> axum::response::Response {
+    if let Err(e) = authorize_global(&state, &headers, Access::Read) {
+        return e.into_response();
+    }
+    metrics_open(State(state)).await
+}
+
+async fn metrics_open(State(state): State<Arc<AppState>>) -> axum::response::Response {
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        metrics::render(&state).await,
+    )
+        .into_response()
+}
+
 // Need base64 for l1_read fallback
 use base64::Engine;
 
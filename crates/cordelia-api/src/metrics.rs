@@ -0,0 +1,416 @@
+//! Prometheus metrics (`GET /metrics`, text exposition format 0.0.4).
+//!
+//! Replication counters come from `ReplicationStats`, peer gauges from the
+//! governor, per-group sizes from `StorageStats`, and request latencies from
+//! `ProtocolMetrics` (recorded by the swarm task per mini-protocol).
+
+use std::fmt::{Display, Write as _};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::AppState;
+
+/// Latency histogram bucket upper bounds (seconds).
+pub const LATENCY_BUCKETS_SECS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Outbound request-response mini-protocols with latency tracking.
+pub const PROTOCOLS: [&str; 5] = ["peer_share", "sync", "fetch", "push", "group_exchange"];
+
+/// Fixed-bucket latency histogram (lock-free).
+pub struct Histogram {
+    /// Per-bucket counts (not cumulative); last slot is +Inf.
+    buckets: [AtomicU64; LATENCY_BUCKETS_SECS.len() + 1],
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl Histogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            count: AtomicU64::new(0),
+            sum_micros: AtomicU64::new(0),
+        }
+    }
+
+    pub fn observe(&self, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        let idx = LATENCY_BUCKETS_SECS
+            .iter()
+            .position(|b| secs <= *b)
+            .unwrap_or(LATENCY_BUCKETS_SECS.len());
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+    }
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Per-protocol request latency and failure counts (shared with swarm task).
+pub struct ProtocolMetrics {
+    latency: [Histogram; PROTOCOLS.len()],
+    failures: [AtomicU64; PROTOCOLS.len()],
+}
+
+impl ProtocolMetrics {
+    pub fn new() -> Self {
+        Self {
+            latency: std::array::from_fn(|_| Histogram::new()),
+            failures: std::array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+
+    /// Record a completed request (response received).
+    pub fn observe(&self, protocol: &str, elapsed: Duration) {
+        if let Some(i) = PROTOCOLS.iter().position(|p| *p == protocol) {
+            self.latency[i].observe(elapsed);
+        }
+    }
+
+    /// Record an outbound failure (timeout, dial failure, stream error).
+    pub fn record_failure(&self, protocol: &str) {
+        if let Some(i) = PROTOCOLS.iter().position(|p| *p == protocol) {
+            self.failures[i].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Default for ProtocolMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Governor peer counts by state, with network-diversity figures.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GovernorCounts {
+    pub hot: usize,
+    pub warm: usize,
+    pub cold: usize,
+    pub banned: usize,
+    /// Long-lived peers churn will not evict.
+    pub protected: usize,
+    /// Dials or hot promotions skipped because the subnet was full.
+    pub subnet_rejections: u64,
+    /// Shared peers refused because their peer-share source hit its cap.
+    pub share_source_rejections: u64,
+}
+
+/// Callback to get peer counts from the governor.
+pub type GovernorCountsFn = Box<
+    dyn Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = GovernorCounts> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// Text exposition builder.
+struct Exposition(String);
+
+impl Exposition {
+    fn family(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.0, "# HELP {name} {help}");
+        let _ = writeln!(self.0, "# TYPE {name} {kind}");
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.0.push_str(name);
+        if !labels.is_empty() {
+            self.0.push('{');
+            for (i, (k, v)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.0.push(',');
+                }
+                let _ = write!(self.0, "{k}=\"{}\"", escape_label(v));
+            }
+            self.0.push('}');
+        }
+        let _ = writeln!(self.0, " {value}");
+    }
+
+    fn single(&mut self, name: &str, kind: &str, help: &str, value: impl Display) {
+        self.family(name, kind, help);
+        self.sample(name, &[], value);
+    }
+}
+
+fn escape_label(v: &str) -> String {
+    v.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Render all node metrics in Prometheus text format.
+pub async fn render(state: &AppState) -> String {
+    let mut out = Exposition(String::new());
+
+    out.single(
+        "cordelia_uptime_seconds",
+        "gauge",
+        "Seconds since node start.",
+        state.start_time.elapsed().as_secs(),
+    );
+
+    // -- Replication --
+    if let Some(stats) = &state.replication_stats {
+        let counters = [
+            (
+                "cordelia_replication_items_pushed_total",
+                "Items pushed to peers.",
+                &stats.items_pushed,
+            ),
+            (
+                "cordelia_replication_items_synced_total",
+                "Items received via anti-entropy sync.",
+                &stats.items_synced,
+            ),
+            (
+                "cordelia_replication_items_rejected_total",
+                "Items rejected on receive.",
+                &stats.items_rejected,
+            ),
+            (
+                "cordelia_replication_items_duplicate_total",
+                "Items received that were already stored.",
+                &stats.items_duplicate,
+            ),
+            (
+                "cordelia_replication_items_expired_total",
+                "Items removed by expiry GC.",
+                &stats.items_expired,
+            ),
+            (
+                "cordelia_replication_push_retries_exhausted_total",
+                "Pushes that exhausted all retries.",
+                &stats.push_retries_exhausted,
+            ),
+            (
+                "cordelia_replication_sync_rounds_total",
+                "Anti-entropy sync rounds completed.",
+                &stats.sync_rounds,
+            ),
+            (
+                "cordelia_replication_sync_rounds_with_diff_total",
+                "Anti-entropy sync rounds that found missing items.",
+                &stats.sync_rounds_with_diff,
+            ),
+            (
+                "cordelia_replication_sync_errors_total",
+                "Anti-entropy sync rounds that failed.",
+                &stats.sync_errors,
+            ),
+        ];
+        for (name, help, v) in counters {
+            out.single(name, "counter", help, v.load(Ordering::Relaxed));
+        }
+        out.single(
+            "cordelia_replication_write_buffer_depth",
+            "gauge",
+            "Items waiting in the write coalescing buffer.",
+            stats.write_buffer_depth.load(Ordering::Relaxed),
+        );
+        out.single(
+            "cordelia_replication_pending_pushes",
+            "gauge",
+            "Push retries queued.",
+            stats.pending_push_count.load(Ordering::Relaxed),
+        );
+    }
+
+    // -- Peers --
+    let counts = if let Some(f) = &state.governor_counts_fn {
+        Some(f().await)
+    } else if let Some(f) = &state.peer_count_fn {
+        let (warm, hot) = f().await;
+        Some(GovernorCounts {
+            hot,
+            warm,
+            ..Default::default()
+        })
+    } else {
+        None
+    };
+    if let Some(c) = counts {
+        out.family("cordelia_peers", "gauge", "Known peers by governor state.");
+        for (s, n) in [
+            ("hot", c.hot),
+            ("warm", c.warm),
+            ("cold", c.cold),
+            ("banned", c.banned),
+        ] {
+            out.sample("cordelia_peers", &[("state", s)], n);
+        }
+    }
+    if let (Some(c), Some(_)) = (counts, &state.governor_counts_fn) {
+        out.single(
+            "cordelia_peers_protected",
+            "gauge",
+            "Long-lived peers exempt from churn.",
+            c.protected,
+        );
+        out.family(
+            "cordelia_governor_diversity_rejections_total",
+            "counter",
+            "Peers turned away by network-diversity constraints.",
+        );
+        for (constraint, n) in [
+            ("subnet", c.subnet_rejections),
+            ("share_source", c.share_source_rejections),
+        ] {
+            out.sample(
+                "cordelia_governor_diversity_rejections_total",
+                &[("constraint", constraint)],
+                n,
+            );
+        }
+    }
+    if let Some(f) = &state.peer_list_fn {
+        let peers = f().await;
+        out.family(
+            "cordelia_peer_rtt_seconds",
+            "gauge",
+            "Last ping round-trip time per connected peer.",
+        );
+        for p in peers.iter() {
+            if let Some(rtt) = p.rtt_ms {
+                out.sample(
+                    "cordelia_peer_rtt_seconds",
+                    &[("peer", &p.node_id), ("state", &p.state)],
+                    rtt / 1000.0,
+                );
+            }
+        }
+    }
+
+    // -- Protocol latency --
+    if let Some(pm) = &state.protocol_metrics {
+        let name = "cordelia_request_duration_seconds";
+        out.family(
+            name,
+            "histogram",
+            "Outbound request latency per mini-protocol.",
+        );
+        for (proto, h) in PROTOCOLS.iter().zip(pm.latency.iter()) {
+            let mut cumulative = 0u64;
+            for (i, bound) in LATENCY_BUCKETS_SECS.iter().enumerate() {
+                cumulative += h.buckets[i].load(Ordering::Relaxed);
+                out.sample(
+                    &format!("{name}_bucket"),
+                    &[("protocol", proto), ("le", &bound.to_string())],
+                    cumulative,
+                );
+            }
+            let count = h.count.load(Ordering::Relaxed);
+            out.sample(
+                &format!("{name}_bucket"),
+                &[("protocol", proto), ("le", "+Inf")],
+                count,
+            );
+            out.sample(
+                &format!("{name}_sum"),
+                &[("protocol", proto)],
+                h.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0,
+            );
+            out.sample(&format!("{name}_count"), &[("protocol", proto)], count);
+        }
+        out.family(
+            "cordelia_request_failures_total",
+            "counter",
+            "Outbound request failures per mini-protocol.",
+        );
+        for (proto, f) in PROTOCOLS.iter().zip(pm.failures.iter()) {
+            out.sample(
+                "cordelia_request_failures_total",
+                &[("protocol", proto)],
+                f.load(Ordering::Relaxed),
+            );
+        }
+    }
+
+    // -- Storage --
+    match state.storage.storage_stats() {
+        Ok(stats) => {
+            out.single(
+                "cordelia_storage_items",
+                "gauge",
+                "L2 items stored.",
+                stats.l2_item_count,
+            );
+            out.single(
+                "cordelia_storage_bytes",
+                "gauge",
+                "L2 item data bytes stored.",
+                stats.l2_data_bytes,
+            );
+            out.single(
+                "cordelia_storage_groups",
+                "gauge",
+                "Groups known locally.",
+                stats.group_count,
+            );
+            let per_group = [
+                ("cordelia_group_items", "L2 items per group."),
+                ("cordelia_group_bytes", "L2 data bytes per group."),
+                ("cordelia_group_members", "Members per group."),
+            ];
+            for (i, (name, help)) in per_group.into_iter().enumerate() {
+                out.family(name, "gauge", help);
+                for g in &stats.groups {
+                    let value = [g.item_count, g.data_bytes, g.member_count][i];
+                    out.sample(name, &[("group_id", &g.group_id)], value);
+                }
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "mem: storage stats unavailable for metrics");
+        }
+    }
+
+    out.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets() {
+        let h = Histogram::new();
+        h.observe(Duration::from_millis(3));
+        h.observe(Duration::from_millis(30));
+        h.observe(Duration::from_secs(60));
+        assert_eq!(h.buckets[0].load(Ordering::Relaxed), 1); // <= 5ms
+        assert_eq!(h.buckets[3].load(Ordering::Relaxed), 1); // <= 50ms
+        assert_eq!(
+            h.buckets[LATENCY_BUCKETS_SECS.len()].load(Ordering::Relaxed),
+            1
+        ); // +Inf
+        assert_eq!(h.count.load(Ordering::Relaxed), 3);
+        assert_eq!(h.sum_micros.load(Ordering::Relaxed), 60_033_000);
+    }
+
+    #[test]
+    fn test_exposition_format() {
+        let mut out = Exposition(String::new());
+        out.family("x_total", "counter", "Help text.");
+        out.sample("x_total", &[("group_id", "a\"b"), ("state", "hot")], 3);
+        assert_eq!(
+            out.0,
+            "# HELP x_total Help text.\n# TYPE x_total counter\nx_total{group_id=\"a\\\"b\",state=\"hot\"} 3\n"
+        );
+
+        let pm = ProtocolMetrics::new();
+        pm.observe("sync", Duration::from_millis(20));
+        pm.record_failure("fetch");
+        pm.observe("unknown", Duration::from_millis(20)); // ignored
+        assert_eq!(pm.latency[1].count.load(Ordering::Relaxed), 1);
+        assert_eq!(pm.failures[2].load(Ordering::Relaxed), 1);
+    }
+}
@@ -200,6 +200,17 @@ pub fn sha256_hex(data: &[u8]) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Verify a hex-encoded Ed25519 signature over `payload` by a hex-encoded
+/// public key. Malformed hex fails verification.
+pub fn verify_ed25519_hex(payload: &[u8], pubkey_hex: &str, sig_hex: &str) -> bool {
+    let (Ok(pubkey), Ok(sig)) = (hex::decode(pubkey_hex), hex::decode(sig_hex)) else {
+        return false;
+    };
+    ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, &pubkey)
+        .verify(payload, &sig)
+        .is_ok()
+}
+
 /// Compute node ID from Ed25519 public key (SHA-256 of pubkey bytes).
 pub fn node_id_from_pubkey(pubkey: &[u8]) -> [u8; 32] {
     let mut hasher = Sha256::new();
@@ -324,4 +335,15 @@ mod tests {
             "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
         );
     }
+
+    #[test]
+    fn test_verify_ed25519_hex() {
+        let identity = identity::NodeIdentity::generate().unwrap();
+        let pubkey = hex::encode(identity.public_key());
+        let sig = hex::encode(identity.sign(b"payload"));
+        assert!(verify_ed25519_hex(b"payload", &pubkey, &sig));
+        assert!(!verify_ed25519_hex(b"tampered", &pubkey, &sig));
+        assert!(!verify_ed25519_hex(b"payload", "zz", &sig));
+        assert!(!verify_ed25519_hex(b"payload", &pubkey, "not-hex"));
+    }
 }
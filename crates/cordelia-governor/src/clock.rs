@@ -0,0 +1,72 @@
+//! Clock abstraction -- lets tests move governor (and replication) time
+//! forward by hours or days without waiting.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Source of monotonic and wall-clock time.
+pub trait Clock: Send + Sync {
+    /// Monotonic now (timeouts, backoff, bans).
+    fn now(&self) -> Instant;
+    /// Wall-clock now (values that outlive the process, e.g. the peer book).
+    fn wall(&self) -> SystemTime;
+}
+
+/// The real clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that only moves when told to. Starts at the real time of
+/// creation; both readings advance together.
+#[derive(Debug)]
+pub struct ManualClock {
+    start: Instant,
+    start_wall: SystemTime,
+    offset: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            start_wall: SystemTime::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move time forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner()) += by;
+    }
+
+    /// Time advanced since creation.
+    pub fn elapsed(&self) -> Duration {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn wall(&self) -> SystemTime {
+        self.start_wall + self.elapsed()
+    }
+}
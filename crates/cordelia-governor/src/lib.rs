@@ -3,11 +3,17 @@
 //! Background tokio task, ticks every 10s.
 //! Manages Cold → Warm → Hot peer lifecycle with adversarial demotion.
 
+pub mod clock;
+
+pub use clock::{Clock, ManualClock, SystemClock};
+
 use cordelia_protocol::{GroupId, NodeId, ERA_0};
+use libp2p::multiaddr::Protocol;
 use libp2p::Multiaddr;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 /// Governor tick interval (sourced from current era).
 pub const TICK_INTERVAL: Duration = Duration::from_secs(ERA_0.tick_interval_secs);
@@ -21,12 +27,36 @@ const STALE_TIMEOUT: Duration = Duration::from_secs(ERA_0.stale_timeout_secs);
 /// Default ban duration (sourced from current era).
 const DEFAULT_BAN_DURATION: Duration = Duration::from_secs(ERA_0.ban_base_duration_secs);
 
+/// Longest ban the governor applies, operator-set or escalated (one year).
+pub const MAX_BAN_DURATION: Duration = Duration::from_secs(365 * 24 * 3600);
+
+/// Peer book entries not active for this long are dropped on restore
+/// (unless a ban is still in force).
+pub const PEER_BOOK_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// Connection age before an active peer can join the protected set.
+const PROTECTED_TENURE: Duration = Duration::from_secs(3600);
+
+/// Items a rejected item cancels out in a peer's contribution value.
+const REJECT_PENALTY: f64 = 5.0;
+
+/// Consecutive dial failures after which an address is pruned from a peer
+/// (as long as the peer has another).
+pub const ADDR_PRUNE_FAILURES: u32 = 3;
+
+/// Addresses kept per peer. Peers and peer-share sources list addresses
+/// freely; past this they are dropped.
+pub const MAX_PEER_ADDRS: usize = 8;
+
 /// Dial policy controls which peers the governor will attempt to connect to.
 #[derive(Debug, Clone)]
 pub enum DialPolicy {
     /// Dial any discovered peer (relay behaviour).
     All,
-    /// Only dial peers marked as relays or bootnodes (personal node behaviour).
+    /// Only dial peers marked as relays or bootnodes, plus peers sharing
+    /// one of our groups that we can reach through a relay circuit (a
+    /// hole punch then upgrades it to a direct connection). Personal node
+    /// behaviour.
     RelaysOnly,
     /// Only dial specific trusted relay PeerIds (keeper behaviour).
     TrustedOnly(Vec<NodeId>),
@@ -36,6 +66,20 @@ pub enum DialPolicy {
 pub struct GovernorTargets {
     pub hot_min: usize,
     pub hot_max: usize,
+    /// Hot peers carrying each of our groups. Coverage takes precedence
+    /// over `hot_max`: a group's last carriers are not demoted.
+    #[serde(default = "default_hot_per_group")]
+    pub hot_per_group: usize,
+    /// Max connected peers per public IPv4 /24 or IPv6 /48 (0 = no limit).
+    /// Applies to dialling and separately to hot promotion.
+    #[serde(default = "default_max_per_subnet")]
+    pub max_per_subnet: usize,
+    /// Max known peers learned from any one peer-share source (0 = no limit).
+    #[serde(default = "default_max_per_share_source")]
+    pub max_per_share_source: usize,
+    /// Long-lived, best-scoring active peers that churn never evicts.
+    #[serde(default = "default_protected_peers")]
+    pub protected_peers: usize,
     pub warm_min: usize,
     pub warm_max: usize,
     pub cold_max: usize,
@@ -48,6 +92,10 @@ impl Default for GovernorTargets {
         Self {
             hot_min: 2,
             hot_max: 20,
+            hot_per_group: 1,
+            max_per_subnet: 2,
+            max_per_share_source: 10,
+            protected_peers: 2,
             warm_min: 10,
             warm_max: 50,
             cold_max: 100,
@@ -57,6 +105,144 @@ impl Default for GovernorTargets {
     }
 }
 
+fn default_hot_per_group() -> usize {
+    1
+}
+
+fn default_max_per_subnet() -> usize {
+    2
+}
+
+fn default_max_per_share_source() -> usize {
+    10
+}
+
+fn default_protected_peers() -> usize {
+    2
+}
+
+/// Network-diversity subnet of an address: the public IPv4 /24 or IPv6 /48
+/// of a direct IP address. Loopback, private and link-local addresses have
+/// none (LAN peers are not an eclipse risk and are not capped), and relay
+/// circuit addresses are the relay's, not the peer's.
+pub fn subnet_of(addr: &Multiaddr) -> Option<String> {
+    if is_circuit_addr(addr) {
+        return None;
+    }
+    ip_of(addr).and_then(public_subnet)
+}
+
+/// Whether an address reaches its peer through a relay (`/p2p-circuit`).
+pub fn is_circuit_addr(addr: &Multiaddr) -> bool {
+    addr.iter().any(|p| matches!(p, Protocol::P2pCircuit))
+}
+
+/// Whether an address is loopback, private or link-local (only reachable
+/// on this host or LAN). Addresses without an IP (DNS) are not local.
+pub fn is_local_addr(addr: &Multiaddr) -> bool {
+    ip_of(addr).is_some_and(|ip| public_subnet(ip).is_none())
+}
+
+fn ip_of(addr: &Multiaddr) -> Option<std::net::IpAddr> {
+    addr.iter().find_map(|p| match p {
+        Protocol::Ip4(ip) => Some(std::net::IpAddr::V4(ip)),
+        Protocol::Ip6(ip) => Some(std::net::IpAddr::V6(ip)),
+        _ => None,
+    })
+}
+
+fn public_subnet(ip: std::net::IpAddr) -> Option<String> {
+    match ip {
+        std::net::IpAddr::V4(v4) => {
+            if v4.is_loopback() || v4.is_private() || v4.is_link_local() || v4.is_unspecified() {
+                return None;
+            }
+            let [a, b, c, _] = v4.octets();
+            Some(format!("{a}.{b}.{c}.0/24"))
+        }
+        std::net::IpAddr::V6(v6) => {
+            let seg = v6.segments();
+            let unique_local = seg[0] & 0xfe00 == 0xfc00;
+            let link_local = seg[0] & 0xffc0 == 0xfe80;
+            if v6.is_loopback() || v6.is_unspecified() || unique_local || link_local {
+                return None;
+            }
+            Some(format!("{:x}:{:x}:{:x}::/48", seg[0], seg[1], seg[2]))
+        }
+    }
+}
+
+/// Dial outcomes for one of a peer's addresses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AddrHistory {
+    pub successes: u32,
+    /// Failures since the last success.
+    pub failures: u32,
+    pub last_success: Option<Instant>,
+}
+
+/// `addrs` less those pruned after repeated failures (all of them if
+/// every one was pruned).
+fn without_pruned(
+    addrs: &[Multiaddr],
+    history: &HashMap<Multiaddr, AddrHistory>,
+) -> Vec<Multiaddr> {
+    let kept: Vec<Multiaddr> = addrs
+        .iter()
+        .filter(|a| {
+            history
+                .get(*a)
+                .is_none_or(|h| h.failures < ADDR_PRUNE_FAILURES)
+        })
+        .cloned()
+        .collect();
+    if kept.is_empty() {
+        addrs.to_vec()
+    } else {
+        kept
+    }
+}
+
+/// Address equality ignoring a trailing `/p2p/<peer id>` (which the swarm
+/// appends to dialled addresses).
+fn same_addr(a: &Multiaddr, b: &Multiaddr) -> bool {
+    let strip = |m: &Multiaddr| {
+        let mut m = m.clone();
+        if matches!(m.iter().last(), Some(Protocol::P2p(_))) {
+            m.pop();
+        }
+        m
+    };
+    strip(a) == strip(b)
+}
+
+/// Counts of peers the diversity constraints turned away.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiversityStats {
+    /// Dials or hot promotions skipped because the subnet was full.
+    pub subnet_rejections: u64,
+    /// Shared peers refused because their source hit its cap.
+    pub share_source_rejections: u64,
+}
+
+/// Hot-peer coverage of one of our groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupCoverage {
+    pub group_id: GroupId,
+    /// Hot peers carrying the group (members, or relays advertising it).
+    pub hot: usize,
+    /// Warm peers carrying it (promotion candidates).
+    pub warm: usize,
+    /// `hot_per_group` target.
+    pub target: usize,
+}
+
+impl GroupCoverage {
+    pub fn is_covered(&self) -> bool {
+        self.hot >= self.target
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PeerState {
     Cold,
@@ -96,17 +282,103 @@ pub struct PeerInfo {
     pub groups: Vec<GroupId>,
     pub rtt_ms: Option<f64>,
     pub last_activity: Instant,
-    pub items_delivered: u64,
+    /// What the peer has delivered to us this connection, as we saw it.
+    pub contribution: PeerContribution,
     pub connected_since: Option<Instant>,
     pub demoted_at: Option<Instant>,
     pub disconnect_count: u32,
     pub last_disconnected: Option<Instant>,
     /// Whether this peer is a relay/bootnode (eligible for dial under restricted policies).
     pub is_relay: bool,
+    /// Discovered on the local link (mDNS) and not since expired.
+    pub is_lan: bool,
+    /// Bans so far. Kept after a ban expires, so repeat offenders are
+    /// banned for longer.
+    pub ban_escalation: u32,
+    /// Peer whose peer-share response told us about this peer.
+    pub learned_from: Option<NodeId>,
+    /// First learned of from the DHT.
+    pub from_dht: bool,
+    /// Pinned by the operator: never churned, demoted or evicted.
+    pub pinned: bool,
+    /// Dial outcomes per address (including pruned ones, so they stay pruned).
+    pub addr_history: HashMap<Multiaddr, AddrHistory>,
+    /// Remote address of the current connection.
+    pub connected_addr: Option<Multiaddr>,
+    /// Addresses and groups came from the peer itself (its connection,
+    /// identify or group exchange) or the operator, so peer-share sources
+    /// cannot replace them.
+    pub confirmed: bool,
+}
+
+/// A known peer as saved in the peer book. Times are wall-clock so the
+/// record survives a restart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeerRecord {
+    pub node_id: NodeId,
+    pub addrs: Vec<Multiaddr>,
+    pub groups: Vec<GroupId>,
+    pub is_relay: bool,
+    pub rtt_ms: Option<f64>,
+    pub disconnect_count: u32,
+    pub ban_escalation: u32,
+    /// End and reason of a ban in force when saved.
+    pub ban: Option<(SystemTime, String)>,
+    pub last_seen: SystemTime,
+}
+
+/// Receiver-verified counters of what a peer has given us. Only things we
+/// checked ourselves count: a peer cannot raise its score by claiming to
+/// have delivered, or by being pushed to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerContribution {
+    /// Items from the peer (push or fetch) that we stored.
+    pub items_stored: u64,
+    /// Stored items we had never seen before: the peer delivered first.
+    pub first_deliveries: u64,
+    /// Items from the peer we rejected (penalised).
+    pub items_rejected: u64,
+    /// Sync and fetch requests the peer answered.
+    pub requests_served: u64,
+    /// Sync and fetch requests to the peer that failed or timed out.
+    pub requests_failed: u64,
+}
+
+impl PeerContribution {
+    /// Useful deliveries net of penalties. A first delivery counts twice,
+    /// a served request once, and a rejected item cancels
+    /// [`REJECT_PENALTY`] items. Never negative.
+    pub fn value(&self) -> f64 {
+        let useful = self.items_stored + self.first_deliveries + self.requests_served;
+        (useful as f64 - self.items_rejected as f64 * REJECT_PENALTY).max(0.0)
+    }
+
+    /// Share of requests the peer answered (1.0 before any request).
+    pub fn reliability(&self) -> f64 {
+        (self.requests_served + 1) as f64 / (self.requests_served + self.requests_failed + 1) as f64
+    }
+
+    /// Score: value per second connected, weighted by reliability and RTT.
+    pub fn score(&self, connected_for: Duration, rtt_ms: Option<f64>) -> f64 {
+        let elapsed = connected_for.as_secs_f64().max(1.0);
+        let rtt_factor = rtt_ms.map(|r| 1.0 / (1.0 + r / 100.0)).unwrap_or(0.5);
+        self.value() / elapsed * self.reliability() * rtt_factor
+    }
+}
+
+impl std::ops::AddAssign for PeerContribution {
+    fn add_assign(&mut self, other: Self) {
+        self.items_stored += other.items_stored;
+        self.first_deliveries += other.first_deliveries;
+        self.items_rejected += other.items_rejected;
+        self.requests_served += other.requests_served;
+        self.requests_failed += other.requests_failed;
+    }
 }
 
 impl PeerInfo {
-    pub fn new(node_id: NodeId, addrs: Vec<Multiaddr>, groups: Vec<GroupId>) -> Self {
+    pub fn new(node_id: NodeId, mut addrs: Vec<Multiaddr>, groups: Vec<GroupId>) -> Self {
+        addrs.truncate(MAX_PEER_ADDRS);
         Self {
             node_id,
             addrs,
@@ -114,27 +386,63 @@ impl PeerInfo {
             groups,
             rtt_ms: None,
             last_activity: Instant::now(),
-            items_delivered: 0,
+            contribution: PeerContribution::default(),
             connected_since: None,
             demoted_at: None,
             disconnect_count: 0,
             last_disconnected: None,
             is_relay: false,
+            is_lan: false,
+            ban_escalation: 0,
+            learned_from: None,
+            from_dht: false,
+            pinned: false,
+            addr_history: HashMap::new(),
+            connected_addr: None,
+            confirmed: false,
         }
     }
 
-    /// Performance score: items delivered per second, weighted by RTT.
-    pub fn score(&self) -> f64 {
-        let elapsed = self
-            .connected_since
-            .map(|s| s.elapsed().as_secs_f64())
-            .unwrap_or(1.0)
-            .max(1.0);
+    /// Addresses to dial the peer on, best first: the one that last worked,
+    /// then by successes, direct before relayed. Loopback and private
+    /// addresses are dropped for a peer that also has non-local ones.
+    pub fn dial_addrs(&self) -> Vec<Multiaddr> {
+        let remote = self.addrs.iter().any(|a| !is_local_addr(a));
+        let mut addrs: Vec<Multiaddr> = self
+            .addrs
+            .iter()
+            .filter(|a| !remote || !is_local_addr(a))
+            .cloned()
+            .collect();
+        addrs.sort_by_key(|a| {
+            let h = self.addr_history.get(a).copied().unwrap_or_default();
+            (
+                std::cmp::Reverse(h.last_success),
+                std::cmp::Reverse(h.successes),
+                h.failures,
+                is_circuit_addr(a),
+            )
+        });
+        addrs
+    }
 
-        let throughput = self.items_delivered as f64 / elapsed;
-        let rtt_factor = self.rtt_ms.map(|r| 1.0 / (1.0 + r / 100.0)).unwrap_or(0.5);
+    /// The subnet the peer counts against for diversity: that of its
+    /// connection's remote address while connected, else of the address we
+    /// would dial first. One subnet, however many addresses it lists.
+    pub fn subnet(&self) -> Option<String> {
+        match &self.connected_addr {
+            Some(addr) => subnet_of(addr),
+            None => self.dial_addrs().first().and_then(subnet_of),
+        }
+    }
 
-        throughput * rtt_factor
+    /// Performance score from the peer's contribution over this connection.
+    pub fn score(&self, now: Instant) -> f64 {
+        let connected_for = self
+            .connected_since
+            .map(|s| now.saturating_duration_since(s))
+            .unwrap_or(Duration::from_secs(1));
+        self.contribution.score(connected_for, self.rtt_ms)
     }
 
     /// Whether this peer has any groups in common with the given set.
@@ -150,6 +458,10 @@ pub struct Governor {
     our_groups: Vec<GroupId>,
     last_churn: Instant,
     dial_policy: DialPolicy,
+    /// Dial LAN-discovered peers whatever the dial policy.
+    dial_lan_peers: bool,
+    diversity: DiversityStats,
+    clock: Arc<dyn Clock>,
 }
 
 /// Actions the governor wants the node to take after a tick.
@@ -172,47 +484,190 @@ impl Governor {
         targets: GovernorTargets,
         our_groups: Vec<GroupId>,
         dial_policy: DialPolicy,
+    ) -> Self {
+        Self::with_clock(targets, our_groups, dial_policy, Arc::new(SystemClock))
+    }
+
+    /// Governor reading time from `clock` (a [`ManualClock`] in tests).
+    pub fn with_clock(
+        targets: GovernorTargets,
+        our_groups: Vec<GroupId>,
+        dial_policy: DialPolicy,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             peers: HashMap::new(),
             targets,
             our_groups,
-            last_churn: Instant::now(),
+            last_churn: clock.now(),
             dial_policy,
+            dial_lan_peers: false,
+            diversity: DiversityStats::default(),
+            clock,
         }
     }
 
+    /// The clock this governor reads.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
     /// Update this node's group membership (for dynamic group creation).
     pub fn set_groups(&mut self, groups: Vec<GroupId>) {
         self.our_groups = groups;
     }
 
-    /// Add or update a known peer.
+    /// Let peers discovered on the local link be dialled even when the
+    /// dial policy would refuse them (off by default).
+    pub fn set_dial_lan_peers(&mut self, dial: bool) {
+        self.dial_lan_peers = dial;
+    }
+
+    /// Add or update a known peer from first-hand information (its
+    /// connection, identify, group exchange, or operator config).
     pub fn add_peer(&mut self, node_id: NodeId, addrs: Vec<Multiaddr>, groups: Vec<GroupId>) {
-        self.peers
+        let now = self.clock.now();
+        let peer = self
+            .peers
             .entry(node_id)
             .and_modify(|p| {
-                p.addrs = addrs.clone();
+                p.addrs = without_pruned(&addrs, &p.addr_history);
+                p.addrs.truncate(MAX_PEER_ADDRS);
                 p.groups = groups.clone();
             })
-            .or_insert_with(|| PeerInfo::new(node_id, addrs, groups));
+            .or_insert_with(|| PeerInfo {
+                last_activity: now,
+                ..PeerInfo::new(node_id, addrs, groups)
+            });
+        peer.confirmed = true;
+    }
+
+    /// Add a peer discovered on the local link (mDNS), Cold if new. Its LAN
+    /// addresses are added to any we already know; groups are kept.
+    pub fn add_lan_peer(&mut self, node_id: NodeId, addrs: Vec<Multiaddr>) {
+        self.merge_discovered_peer(node_id, addrs).is_lan = true;
+    }
+
+    /// Add a peer found in the DHT, Cold if new. Its addresses are added to
+    /// any we already know; its relay status and groups are not taken from
+    /// the DHT (anyone can publish a record) but learned once connected.
+    /// The DHT counts as one peer-share source: a new peer is refused once
+    /// `max_per_share_source` known peers came from it. Returns whether the
+    /// peer was added or updated.
+    pub fn add_dht_peer(&mut self, node_id: NodeId, addrs: Vec<Multiaddr>) -> bool {
+        let known = self.peers.contains_key(&node_id);
+        if !known {
+            let cap = self.targets.max_per_share_source;
+            let from_dht = self.peers.values().filter(|p| p.from_dht).count();
+            if cap > 0 && from_dht >= cap {
+                self.diversity.share_source_rejections += 1;
+                tracing::debug!(peer = %node_id, from_dht, "gov: DHT peer refused (source cap)");
+                return false;
+            }
+        }
+        let peer = self.merge_discovered_peer(node_id, addrs);
+        if !known {
+            peer.from_dht = true;
+        }
+        true
+    }
+
+    /// The entry for a discovered peer (Cold if new), with `addrs` merged
+    /// into its known addresses.
+    fn merge_discovered_peer(&mut self, node_id: NodeId, addrs: Vec<Multiaddr>) -> &mut PeerInfo {
+        let now = self.clock.now();
+        let peer = self.peers.entry(node_id).or_insert_with(|| PeerInfo {
+            last_activity: now,
+            ..PeerInfo::new(node_id, vec![], vec![])
+        });
+        for addr in without_pruned(&addrs, &peer.addr_history) {
+            if peer.addrs.len() >= MAX_PEER_ADDRS {
+                break;
+            }
+            if !peer.addrs.iter().any(|a| same_addr(a, &addr)) {
+                peer.addrs.push(addr);
+            }
+        }
+        peer
+    }
+
+    /// A LAN peer's mDNS record expired: it is no longer known to be on
+    /// the local link.
+    pub fn expire_lan_peer(&mut self, node_id: &NodeId) {
+        if let Some(peer) = self.peers.get_mut(node_id) {
+            peer.is_lan = false;
+        }
+    }
+
+    /// Add a peer learned from `source`'s peer-share response. A source
+    /// reaches at most `max_per_share_source` peers, added or updated, so
+    /// one peer cannot flood us with its own address list: a new peer is
+    /// refused once that many came from the same source, and a known peer
+    /// is only updated by the source it came from, and only until we have
+    /// its addresses and groups first-hand. Returns whether the peer was
+    /// added or updated.
+    pub fn add_shared_peer(
+        &mut self,
+        node_id: NodeId,
+        addrs: Vec<Multiaddr>,
+        groups: Vec<GroupId>,
+        source: NodeId,
+    ) -> bool {
+        if let Some(peer) = self.peers.get_mut(&node_id) {
+            if peer.confirmed || peer.learned_from != Some(source) {
+                return false;
+            }
+            peer.addrs = without_pruned(&addrs, &peer.addr_history);
+            peer.addrs.truncate(MAX_PEER_ADDRS);
+            peer.groups = groups;
+            return true;
+        }
+        let cap = self.targets.max_per_share_source;
+        let from_source = self
+            .peers
+            .values()
+            .filter(|p| p.learned_from == Some(source))
+            .count();
+        if cap > 0 && from_source >= cap {
+            self.diversity.share_source_rejections += 1;
+            tracing::debug!(
+                peer = %node_id,
+                %source,
+                from_source,
+                "gov: shared peer refused (source cap)"
+            );
+            return false;
+        }
+        let now = self.clock.now();
+        self.peers.insert(
+            node_id,
+            PeerInfo {
+                last_activity: now,
+                learned_from: Some(source),
+                ..PeerInfo::new(node_id, addrs, groups)
+            },
+        );
+        true
     }
 
     /// Record that a peer sent us a keep-alive response.
     pub fn record_activity(&mut self, node_id: &NodeId, rtt_ms: Option<f64>) {
         if let Some(peer) = self.peers.get_mut(node_id) {
-            peer.last_activity = Instant::now();
+            peer.last_activity = self.clock.now();
             if let Some(rtt) = rtt_ms {
                 peer.rtt_ms = Some(rtt);
             }
         }
     }
 
-    /// Record that a peer delivered items.
-    pub fn record_items_delivered(&mut self, node_id: &NodeId, count: u64) {
+    /// Update a peer's contribution for the current connection (tracked by
+    /// the node's peer pool). Any new contribution counts as activity.
+    pub fn set_contribution(&mut self, node_id: &NodeId, contribution: PeerContribution) {
         if let Some(peer) = self.peers.get_mut(node_id) {
-            peer.items_delivered += count;
-            peer.last_activity = Instant::now();
+            if peer.contribution != contribution {
+                peer.contribution = contribution;
+                peer.last_activity = self.clock.now();
+            }
         }
     }
 
@@ -226,12 +681,20 @@ impl Governor {
                     "gov: cold -> warm (connected)"
                 );
                 peer.state = PeerState::Warm;
-                peer.connected_since = Some(Instant::now());
-                peer.last_activity = Instant::now();
+                peer.connected_since = Some(self.clock.now());
+                peer.last_activity = self.clock.now();
             }
         }
     }
 
+    /// Record the remote address of a peer's connection: the one address
+    /// it counts against for subnet diversity while connected.
+    pub fn record_connection_addr(&mut self, node_id: &NodeId, addr: &Multiaddr) {
+        if let Some(peer) = self.peers.get_mut(node_id) {
+            peer.connected_addr = Some(addr.clone());
+        }
+    }
+
     /// Mark peer as disconnected (back to Cold) with reconnect backoff.
     /// Called when connection drops to keep governor in sync with pool.
     /// Tracks disconnect count for exponential backoff: min(2^count * 30s, 15min).
@@ -241,8 +704,9 @@ impl Governor {
                 let from = peer.state.name();
                 peer.state = PeerState::Cold;
                 peer.connected_since = None;
+                peer.connected_addr = None;
                 peer.disconnect_count += 1;
-                peer.last_disconnected = Some(Instant::now());
+                peer.last_disconnected = Some(self.clock.now());
                 let backoff = Self::reconnect_backoff(peer.disconnect_count);
                 tracing::info!(
                     peer = %node_id,
@@ -260,7 +724,7 @@ impl Governor {
     pub fn mark_dial_failed(&mut self, node_id: &NodeId) {
         if let Some(peer) = self.peers.get_mut(node_id) {
             peer.disconnect_count += 1;
-            peer.last_disconnected = Some(Instant::now());
+            peer.last_disconnected = Some(self.clock.now());
             let backoff = Self::reconnect_backoff(peer.disconnect_count);
             tracing::debug!(
                 peer = %node_id,
@@ -323,29 +787,130 @@ impl Governor {
         }
     }
 
-    /// Ban a peer for protocol violation.
-    pub fn ban_peer(&mut self, node_id: &NodeId, reason: String) {
-        if let Some(peer) = self.peers.get_mut(node_id) {
-            let from = peer.state.name();
-            let escalation = match &peer.state {
-                PeerState::Banned { escalation, .. } => escalation + 1,
-                _ => 1,
-            };
-            let duration = DEFAULT_BAN_DURATION * escalation;
-            tracing::warn!(
+    /// Ban a peer for protocol violation, for `duration` or (None) the
+    /// base ban duration times the escalation count, capped at
+    /// [`MAX_BAN_DURATION`]. Clears any pin. Returns false if the peer is
+    /// unknown (or the ban end is past what the clock can represent).
+    pub fn ban_peer(
+        &mut self,
+        node_id: &NodeId,
+        reason: String,
+        duration: Option<Duration>,
+    ) -> bool {
+        let Some(peer) = self.peers.get_mut(node_id) else {
+            return false;
+        };
+        let from = peer.state.name();
+        let escalation = peer.ban_escalation.saturating_add(1);
+        let duration = duration
+            .unwrap_or(DEFAULT_BAN_DURATION.saturating_mul(escalation))
+            .min(MAX_BAN_DURATION);
+        let Some(until) = self.clock.now().checked_add(duration) else {
+            return false;
+        };
+        peer.ban_escalation = escalation;
+        tracing::warn!(
+            peer = %node_id,
+            from,
+            reason = reason,
+            escalation,
+            ban_duration_secs = duration.as_secs(),
+            "gov: peer banned"
+        );
+        peer.state = PeerState::Banned {
+            until,
+            reason,
+            escalation,
+        };
+        peer.connected_since = None;
+        peer.connected_addr = None;
+        peer.pinned = false;
+        true
+    }
+
+    /// Lift a ban early (operator action). The peer returns to Cold; its
+    /// ban history is kept. Returns false if the peer is not banned.
+    pub fn unban_peer(&mut self, node_id: &NodeId) -> bool {
+        let Some(peer) = self.peers.get_mut(node_id) else {
+            return false;
+        };
+        if !peer.state.is_banned() {
+            return false;
+        }
+        tracing::info!(peer = %node_id, "gov: ban lifted by operator");
+        peer.state = PeerState::Cold;
+        true
+    }
+
+    /// Pin or unpin a peer (operator action). Returns false if the peer is
+    /// unknown or banned.
+    pub fn set_pinned(&mut self, node_id: &NodeId, pinned: bool) -> bool {
+        match self.peers.get_mut(node_id) {
+            Some(peer) if !peer.state.is_banned() => {
+                tracing::info!(peer = %node_id, pinned, "gov: peer pin updated");
+                peer.pinned = pinned;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Addresses to dial a peer on, best first (see [`PeerInfo::dial_addrs`]).
+    pub fn dial_addrs(&self, node_id: &NodeId) -> Vec<Multiaddr> {
+        self.peers
+            .get(node_id)
+            .map(PeerInfo::dial_addrs)
+            .unwrap_or_default()
+    }
+
+    /// Record a successful dial of one of a peer's addresses.
+    pub fn record_addr_success(&mut self, node_id: &NodeId, addr: &Multiaddr) {
+        let now = self.clock.now();
+        let Some(peer) = self.peers.get_mut(node_id) else {
+            return;
+        };
+        let Some(addr) = peer.addrs.iter().find(|a| same_addr(a, addr)) else {
+            return;
+        };
+        let h = peer.addr_history.entry(addr.clone()).or_default();
+        h.successes += 1;
+        h.failures = 0;
+        h.last_success = Some(now);
+    }
+
+    /// Record a direct address that reached a peer we knew only through a
+    /// relay (a hole punch). It becomes the peer's preferred address.
+    pub fn record_direct_addr(&mut self, node_id: &NodeId, addr: &Multiaddr) {
+        let Some(peer) = self.peers.get_mut(node_id) else {
+            return;
+        };
+        if !peer.addrs.iter().any(|a| same_addr(a, addr)) {
+            peer.addrs.insert(0, addr.clone());
+            peer.addrs.truncate(MAX_PEER_ADDRS);
+        }
+        self.record_addr_success(node_id, addr);
+    }
+
+    /// Record a failed dial of one of a peer's addresses. After
+    /// [`ADDR_PRUNE_FAILURES`] in a row it is pruned, unless it is the
+    /// peer's last address.
+    pub fn record_addr_failure(&mut self, node_id: &NodeId, addr: &Multiaddr) {
+        let Some(peer) = self.peers.get_mut(node_id) else {
+            return;
+        };
+        let Some(addr) = peer.addrs.iter().find(|a| same_addr(a, addr)).cloned() else {
+            return;
+        };
+        let h = peer.addr_history.entry(addr.clone()).or_default();
+        h.failures += 1;
+        if h.failures >= ADDR_PRUNE_FAILURES && peer.addrs.len() > 1 {
+            tracing::info!(
                 peer = %node_id,
-                from,
-                reason = reason,
-                escalation,
-                ban_duration_secs = duration.as_secs(),
-                "gov: peer banned"
+                %addr,
+                failures = h.failures,
+                "gov: pruned failing address"
             );
-            peer.state = PeerState::Banned {
-                until: Instant::now() + duration,
-                reason,
-                escalation,
-            };
-            peer.connected_since = None;
+            peer.addrs.retain(|a| *a != addr);
         }
     }
 
@@ -358,9 +923,16 @@ impl Governor {
 
     /// Check if a peer is dialable under the current policy.
     fn is_dialable(&self, peer: &PeerInfo) -> bool {
+        if peer.is_lan && self.dial_lan_peers {
+            return true;
+        }
         match &self.dial_policy {
             DialPolicy::All => true,
-            DialPolicy::RelaysOnly => peer.is_relay,
+            DialPolicy::RelaysOnly => {
+                peer.is_relay
+                    || (peer.addrs.iter().any(is_circuit_addr)
+                        && peer.groups.iter().any(|g| self.our_groups.contains(g)))
+            }
             DialPolicy::TrustedOnly(trusted) => trusted.contains(&peer.node_id),
         }
     }
@@ -383,6 +955,119 @@ impl Governor {
             .collect()
     }
 
+    /// Hot and warm coverage of each of our groups.
+    pub fn group_coverage(&self) -> Vec<GroupCoverage> {
+        self.our_groups
+            .iter()
+            .map(|g| {
+                let carriers = self.peers.values().filter(|p| p.groups.contains(g));
+                let (mut hot, mut warm) = (0, 0);
+                for p in carriers {
+                    match p.state {
+                        PeerState::Hot => hot += 1,
+                        PeerState::Warm => warm += 1,
+                        _ => {}
+                    }
+                }
+                GroupCoverage {
+                    group_id: g.clone(),
+                    hot,
+                    warm,
+                    target: self.targets.hot_per_group,
+                }
+            })
+            .collect()
+    }
+
+    /// Hot peers each of our groups is short of its `hot_per_group` target.
+    fn coverage_deficits(&self) -> HashMap<GroupId, usize> {
+        self.group_coverage()
+            .into_iter()
+            .filter(|c| !c.is_covered())
+            .map(|c| (c.group_id, c.target - c.hot))
+            .collect()
+    }
+
+    /// Peers turned away by the diversity constraints so far.
+    pub fn diversity_stats(&self) -> DiversityStats {
+        self.diversity
+    }
+
+    /// Long-lived good peers churn never evicts: the best-scoring
+    /// `protected_peers` active peers connected for [`PROTECTED_TENURE`].
+    pub fn protected_peers(&self) -> HashSet<NodeId> {
+        let now = self.clock.now();
+        let mut tenured: Vec<(NodeId, f64)> = self
+            .peers
+            .values()
+            .filter(|p| {
+                p.state.is_active()
+                    && p.connected_since
+                        .is_some_and(|t| now.saturating_duration_since(t) >= PROTECTED_TENURE)
+            })
+            .map(|p| (p.node_id, p.score(now)))
+            .collect();
+        tenured.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        tenured
+            .into_iter()
+            .take(self.targets.protected_peers)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Whether `peer`'s subnet already has `max_per_subnet` peers in a
+    /// state matching `counted` (not counting `peer` itself).
+    fn subnet_full(&self, peer: &PeerInfo, counted: fn(&PeerState) -> bool) -> bool {
+        let cap = self.targets.max_per_subnet;
+        let Some(subnet) = peer.subnet().filter(|_| cap > 0) else {
+            return false;
+        };
+        let in_subnet = self
+            .peers
+            .values()
+            .filter(|p| {
+                p.node_id != peer.node_id
+                    && counted(&p.state)
+                    && p.subnet().as_ref() == Some(&subnet)
+            })
+            .count();
+        in_subnet >= cap
+    }
+
+    /// Take up to `needed` dial candidates, in order, keeping each subnet
+    /// within `max_per_subnet` connected peers.
+    fn diverse_dials(&mut self, candidates: Vec<NodeId>, needed: usize) -> Vec<NodeId> {
+        let cap = self.targets.max_per_subnet;
+        let mut per_subnet: HashMap<String, usize> = HashMap::new();
+        for p in self.peers.values().filter(|p| p.state.is_active()) {
+            if let Some(subnet) = p.subnet() {
+                *per_subnet.entry(subnet).or_default() += 1;
+            }
+        }
+
+        let mut chosen = Vec::new();
+        for id in candidates {
+            if chosen.len() == needed {
+                break;
+            }
+            let subnet = match self.peers.get(&id) {
+                Some(p) if cap > 0 => p.subnet(),
+                _ => None,
+            };
+            if let Some(subnet) = subnet {
+                let in_subnet = per_subnet.entry(subnet).or_default();
+                if *in_subnet >= cap {
+                    self.diversity.subnet_rejections += 1;
+                    tracing::debug!(peer = %id, "gov: dial skipped (subnet full)");
+                    continue;
+                }
+                *in_subnet += 1;
+            }
+            chosen.push(id);
+        }
+        chosen
+    }
+
     /// Get counts by state.
     pub fn counts(&self) -> (usize, usize, usize, usize) {
         let mut hot = 0;
@@ -413,7 +1098,8 @@ impl Governor {
         // 3. Promote Cold → Warm if needed
         self.promote_cold_to_warm(&mut actions);
 
-        // 4. Promote Warm → Hot if needed
+        // 4. Promote Warm → Hot: uncovered groups first, then by score
+        self.cover_groups(&mut actions);
         self.promote_warm_to_hot(&mut actions);
 
         // 5. Demote excess Hot → Warm
@@ -429,7 +1115,7 @@ impl Governor {
     }
 
     fn unban_expired(&mut self, actions: &mut GovernorActions) {
-        let now = Instant::now();
+        let now = self.clock.now();
         for peer in self.peers.values_mut() {
             if let PeerState::Banned {
                 until,
@@ -455,7 +1141,7 @@ impl Governor {
     }
 
     fn reap_dead(&mut self, actions: &mut GovernorActions) {
-        let now = Instant::now();
+        let now = self.clock.now();
         let dead_ids: Vec<NodeId> = self
             .peers
             .values()
@@ -476,7 +1162,7 @@ impl Governor {
                         );
                         peer.state = PeerState::Warm;
                         peer.connected_since = None;
-                        peer.demoted_at = Some(Instant::now());
+                        peer.demoted_at = Some(now);
                         actions.transitions.push((id, from, "warm".into()));
                     }
                     PeerState::Warm => {
@@ -514,7 +1200,14 @@ impl Governor {
             cold_available = cold,
             "gov: promoting cold peers toward warm_max"
         );
-        let now = Instant::now();
+        let now = self.clock.now();
+        // Groups with no connected carrier to promote: dial their peers first
+        let unserved: Vec<GroupId> = self
+            .group_coverage()
+            .into_iter()
+            .filter(|c| c.hot + c.warm < c.target)
+            .map(|c| c.group_id)
+            .collect();
         let mut candidates: Vec<NodeId> = self
             .peers
             .values()
@@ -526,13 +1219,18 @@ impl Governor {
                         .is_none_or(|t| now.duration_since(t) >= backoff)
                 }
             })
-            .map(|p| (p.node_id, p.has_group_overlap(&self.our_groups)))
+            .map(|p| {
+                let priority = (
+                    p.has_group_overlap(&unserved),
+                    p.has_group_overlap(&self.our_groups),
+                );
+                (p.node_id, priority)
+            })
             .collect::<Vec<_>>()
             .into_iter()
-            .sorted_by_key(|(_, overlap)| std::cmp::Reverse(*overlap))
+            .sorted_by_key(|(_, priority)| std::cmp::Reverse(*priority))
             .into_iter()
             .map(|(id, _)| id)
-            .take(needed)
             .collect();
 
         // If no sorting available, just take first N
@@ -547,10 +1245,10 @@ impl Governor {
                             .is_none_or(|t| now.duration_since(t) >= backoff)
                     }
                 })
-                .take(needed)
                 .map(|p| p.node_id)
                 .collect();
         }
+        let candidates = self.diverse_dials(candidates, needed);
 
         if !candidates.is_empty() {
             // Log skipped peers for backoff visibility
@@ -578,7 +1276,68 @@ impl Governor {
         }
     }
 
+    /// Promote warm peers carrying groups below `hot_per_group`, preferring
+    /// peers that cover the most such groups, then the best scored.
+    fn cover_groups(&mut self, actions: &mut GovernorActions) {
+        let now = self.clock.now();
+        let mut deficits = self.coverage_deficits();
+        while !deficits.is_empty() {
+            let best = self
+                .peers
+                .values()
+                .filter(|p| {
+                    p.state == PeerState::Warm
+                        && p.demoted_at
+                            .is_none_or(|d| now.saturating_duration_since(d) > DEAD_TIMEOUT)
+                        && !self.subnet_full(p, is_hot)
+                })
+                .map(|p| {
+                    let covers = p
+                        .groups
+                        .iter()
+                        .filter(|g| deficits.contains_key(*g))
+                        .count();
+                    (p.node_id, covers, p.score(now))
+                })
+                .filter(|(_, covers, _)| *covers > 0)
+                .max_by(|a, b| {
+                    a.1.cmp(&b.1)
+                        .then(a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+                });
+            let Some((id, covers, score)) = best else {
+                break;
+            };
+            let Some(peer) = self.peers.get_mut(&id) else {
+                break;
+            };
+            tracing::info!(
+                peer = %id,
+                covers,
+                score = format!("{score:.4}"),
+                "gov: warm -> hot (uncovered group)"
+            );
+            peer.state = PeerState::Hot;
+            peer.disconnect_count = 0; // stable connection, reset backoff
+            actions.transitions.push((id, "warm".into(), "hot".into()));
+            for g in &peer.groups {
+                if let Some(deficit) = deficits.get_mut(g) {
+                    *deficit -= 1;
+                }
+            }
+            deficits.retain(|_, deficit| *deficit > 0);
+        }
+
+        for (group, deficit) in &deficits {
+            tracing::debug!(
+                group = group.as_str(),
+                deficit,
+                "gov: no warm peer to cover group"
+            );
+        }
+    }
+
     fn promote_warm_to_hot(&mut self, actions: &mut GovernorActions) {
+        let now = self.clock.now();
         let (hot, _, _, _) = self.counts();
         if hot >= self.targets.hot_min {
             // Check if any warm peer outperforms worst hot
@@ -586,7 +1345,7 @@ impl Governor {
                 .peers
                 .values()
                 .filter(|p| p.state == PeerState::Hot)
-                .map(|p| p.score())
+                .map(|p| p.score(now))
                 .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
                 .unwrap_or(f64::MAX);
 
@@ -595,18 +1354,20 @@ impl Governor {
                 .values()
                 .filter(|p| {
                     p.state == PeerState::Warm
-                        && p.demoted_at.is_none_or(|d| d.elapsed() > DEAD_TIMEOUT)
+                        && p.demoted_at
+                            .is_none_or(|d| now.saturating_duration_since(d) > DEAD_TIMEOUT)
+                        && !self.subnet_full(p, is_hot)
                 })
                 .max_by(|a, b| {
-                    a.score()
-                        .partial_cmp(&b.score())
+                    a.score(now)
+                        .partial_cmp(&b.score(now))
                         .unwrap_or(std::cmp::Ordering::Equal)
                 });
 
             if let Some(warm) = best_warm {
-                if hot < self.targets.hot_max && warm.score() > worst_hot_score {
+                if hot < self.targets.hot_max && warm.score(now) > worst_hot_score {
                     let id = warm.node_id;
-                    let warm_score = warm.score();
+                    let warm_score = warm.score(now);
                     if let Some(peer) = self.peers.get_mut(&id) {
                         tracing::info!(
                             peer = %id,
@@ -630,15 +1391,30 @@ impl Governor {
             .values()
             .filter(|p| {
                 p.state == PeerState::Warm
-                    && p.demoted_at.is_none_or(|d| d.elapsed() > DEAD_TIMEOUT)
+                    && p.demoted_at
+                        .is_none_or(|d| now.saturating_duration_since(d) > DEAD_TIMEOUT)
             })
-            .map(|p| (p.node_id, p.score()))
+            .map(|p| (p.node_id, p.score(now)))
             .collect();
 
         warm_peers.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
-        for (id, score) in warm_peers.into_iter().take(needed) {
+        let mut promoted = 0;
+        for (id, score) in warm_peers {
+            if promoted == needed {
+                break;
+            }
+            let full = self
+                .peers
+                .get(&id)
+                .is_some_and(|p| self.subnet_full(p, is_hot));
+            if full {
+                self.diversity.subnet_rejections += 1;
+                tracing::debug!(peer = %id, "gov: hot promotion skipped (subnet full)");
+                continue;
+            }
             if let Some(peer) = self.peers.get_mut(&id) {
+                promoted += 1;
                 tracing::info!(
                     peer = %id,
                     score = format!("{score:.4}"),
@@ -658,15 +1434,16 @@ impl Governor {
         }
 
         let excess = hot - self.targets.hot_max;
+        let now = self.clock.now();
 
         // Demote stale (no items for STALE_TIMEOUT) first, then worst performers
         let mut hot_peers: Vec<(NodeId, f64, bool)> = self
             .peers
             .values()
-            .filter(|p| p.state == PeerState::Hot)
+            .filter(|p| p.state == PeerState::Hot && !p.pinned)
             .map(|p| {
-                let is_stale = p.last_activity.elapsed() > STALE_TIMEOUT;
-                (p.node_id, p.score(), is_stale)
+                let is_stale = now.saturating_duration_since(p.last_activity) > STALE_TIMEOUT;
+                (p.node_id, p.score(now), is_stale)
             })
             .collect();
 
@@ -676,8 +1453,33 @@ impl Governor {
                 .then(a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
         });
 
-        for (id, score, is_stale) in hot_peers.into_iter().take(excess) {
+        // Hot carriers per group: a group's last `hot_per_group` stay hot
+        let mut carriers: HashMap<GroupId, usize> = self
+            .group_coverage()
+            .into_iter()
+            .map(|c| (c.group_id, c.hot))
+            .collect();
+        let target = self.targets.hot_per_group;
+
+        let mut demoted = 0;
+        for (id, score, is_stale) in hot_peers {
+            if demoted == excess {
+                break;
+            }
             if let Some(peer) = self.peers.get_mut(&id) {
+                let needed = peer
+                    .groups
+                    .iter()
+                    .any(|g| carriers.get(g).is_some_and(|n| *n <= target));
+                if needed {
+                    continue;
+                }
+                for g in &peer.groups {
+                    if let Some(n) = carriers.get_mut(g) {
+                        *n -= 1;
+                    }
+                }
+                demoted += 1;
                 tracing::info!(
                     peer = %id,
                     score = format!("{score:.4}"),
@@ -691,10 +1493,13 @@ impl Governor {
     }
 
     fn churn(&mut self, actions: &mut GovernorActions) {
-        if self.last_churn.elapsed() < Duration::from_secs(self.targets.churn_interval_secs) {
+        let now = self.clock.now();
+        if now.saturating_duration_since(self.last_churn)
+            < Duration::from_secs(self.targets.churn_interval_secs)
+        {
             return;
         }
-        self.last_churn = Instant::now();
+        self.last_churn = now;
 
         let (_, warm, cold, _) = self.counts();
         let churn_count = (warm as f64 * self.targets.churn_fraction).ceil() as usize;
@@ -705,11 +1510,12 @@ impl Governor {
 
         tracing::info!(warm, cold, churn_count, "gov: periodic churn cycle");
 
-        // Demote random warm → cold
+        // Demote random warm → cold, never a protected peer
+        let protected = self.protected_peers();
         let warm_ids: Vec<NodeId> = self
             .peers
             .values()
-            .filter(|p| p.state == PeerState::Warm)
+            .filter(|p| p.state == PeerState::Warm && !p.pinned && !protected.contains(&p.node_id))
             .take(churn_count)
             .map(|p| p.node_id)
             .collect();
@@ -731,9 +1537,9 @@ impl Governor {
             .peers
             .values()
             .filter(|p| matches!(p.state, PeerState::Cold) && self.is_dialable(p))
-            .take(churn_count)
             .map(|p| p.node_id)
             .collect();
+        let cold_ids = self.diverse_dials(cold_ids, churn_count);
 
         for id in cold_ids {
             actions.connect.push(id);
@@ -757,7 +1563,7 @@ impl Governor {
         let mut cold_peers: Vec<(NodeId, Instant)> = self
             .peers
             .values()
-            .filter(|p| matches!(p.state, PeerState::Cold))
+            .filter(|p| matches!(p.state, PeerState::Cold) && !p.pinned)
             .map(|p| (p.node_id, p.last_activity))
             .collect();
 
@@ -773,14 +1579,86 @@ impl Governor {
     pub fn all_peers(&self) -> impl Iterator<Item = &PeerInfo> {
         self.peers.values()
     }
-}
 
-/// Extension trait for sorting iterators (avoiding external dep).
-trait SortedBy: Iterator + Sized {
-    fn sorted_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, f: F) -> Vec<Self::Item> {
-        let mut v: Vec<Self::Item> = self.collect();
-        v.sort_by_key(f);
-        v
+    /// Known peers with an address, for the persisted peer book. Addresses
+    /// are saved best first (see [`Governor::dial_addrs`]).
+    pub fn peer_book(&self) -> Vec<PeerRecord> {
+        let now = self.clock.now();
+        let wall = self.clock.wall();
+        self.peers
+            .values()
+            .filter(|p| !p.addrs.is_empty())
+            .map(|p| PeerRecord {
+                node_id: p.node_id,
+                addrs: self.dial_addrs(&p.node_id),
+                groups: p.groups.clone(),
+                is_relay: p.is_relay,
+                rtt_ms: p.rtt_ms,
+                disconnect_count: p.disconnect_count,
+                ban_escalation: p.ban_escalation,
+                ban: match &p.state {
+                    PeerState::Banned { until, reason, .. } => wall
+                        .checked_add(until.saturating_duration_since(now))
+                        .map(|until| (until, reason.clone())),
+                    _ => None,
+                },
+                last_seen: wall - now.duration_since(p.last_activity),
+            })
+            .collect()
+    }
+
+    /// Load a saved peer book: peers come back Cold (or still Banned), with
+    /// their backoff and ban history. Entries older than
+    /// [`PEER_BOOK_MAX_AGE`] are dropped, as are peers already known.
+    /// Returns the number restored.
+    pub fn restore_peer_book(&mut self, records: Vec<PeerRecord>) -> usize {
+        let now = self.clock.now();
+        let wall = self.clock.wall();
+        let mut restored = 0;
+        for record in records {
+            if self.peers.contains_key(&record.node_id) || record.addrs.is_empty() {
+                continue;
+            }
+            let banned_for = record
+                .ban
+                .as_ref()
+                .and_then(|(until, _)| until.duration_since(wall).ok());
+            let age = wall.duration_since(record.last_seen).unwrap_or_default();
+            if age > PEER_BOOK_MAX_AGE && banned_for.is_none() {
+                continue;
+            }
+
+            let mut peer = PeerInfo::new(record.node_id, record.addrs, record.groups);
+            peer.is_relay = record.is_relay;
+            peer.rtt_ms = record.rtt_ms;
+            peer.disconnect_count = record.disconnect_count;
+            peer.ban_escalation = record.ban_escalation;
+            peer.last_activity = now.checked_sub(age).unwrap_or(now);
+            let ban_until = banned_for.and_then(|d| now.checked_add(d.min(MAX_BAN_DURATION)));
+            if let (Some(until), Some((_, reason))) = (ban_until, record.ban) {
+                peer.state = PeerState::Banned {
+                    until,
+                    reason,
+                    escalation: record.ban_escalation,
+                };
+            }
+            self.peers.insert(record.node_id, peer);
+            restored += 1;
+        }
+        restored
+    }
+}
+
+fn is_hot(state: &PeerState) -> bool {
+    *state == PeerState::Hot
+}
+
+/// Extension trait for sorting iterators (avoiding external dep).
+trait SortedBy: Iterator + Sized {
+    fn sorted_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, f: F) -> Vec<Self::Item> {
+        let mut v: Vec<Self::Item> = self.collect();
+        v.sort_by_key(f);
+        v
     }
 }
 
@@ -802,6 +1680,262 @@ mod tests {
         vec!["/ip4/127.0.0.1/udp/9474/quic-v1".parse().unwrap()]
     }
 
+    fn public_addr(subnet: u8, host: u8) -> Vec<Multiaddr> {
+        vec![format!("/ip4/203.0.{subnet}.{host}/udp/9474/quic-v1")
+            .parse()
+            .unwrap()]
+    }
+
+    #[test]
+    fn test_subnet_of() {
+        assert_eq!(
+            subnet_of(&public_addr(113, 7)[0]).as_deref(),
+            Some("203.0.113.0/24")
+        );
+        let v6: Multiaddr = "/ip6/2001:db8:abcd:12::1/udp/9474/quic-v1".parse().unwrap();
+        assert_eq!(subnet_of(&v6).as_deref(), Some("2001:db8:abcd::/48"));
+        // LAN and loopback peers are not capped, nor are relayed ones
+        for lan in [
+            "/ip4/127.0.0.1/udp/9474/quic-v1",
+            "/ip4/192.168.1.20/udp/9474/quic-v1",
+            "/ip4/10.1.2.3/udp/9474/quic-v1",
+            "/ip6/fd00::1/udp/9474/quic-v1",
+            "/ip6/fe80::1/udp/9474/quic-v1",
+            "/ip4/203.0.113.7/udp/9474/quic-v1/p2p-circuit",
+        ] {
+            assert_eq!(subnet_of(&lan.parse().unwrap()), None, "{lan}");
+        }
+
+        // A private first address does not hide the public one behind it
+        let mut peer = PeerInfo::new(
+            make_peer_id(1),
+            vec![
+                "/ip4/192.168.1.20/udp/9474/quic-v1".parse().unwrap(),
+                public_addr(113, 7)[0].clone(),
+            ],
+            vec![],
+        );
+        assert_eq!(peer.subnet().as_deref(), Some("203.0.113.0/24"));
+        // Once connected, the connection's address is what counts
+        peer.connected_addr = Some(public_addr(114, 9)[0].clone());
+        assert_eq!(peer.subnet().as_deref(), Some("203.0.114.0/24"));
+    }
+
+    #[test]
+    fn test_peer_counted_in_one_subnet() {
+        let targets = GovernorTargets {
+            hot_min: 0,
+            max_per_subnet: 1,
+            warm_min: 4,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec![]);
+        // A connected peer claiming addresses in every subnet we might dial
+        let sybil = make_peer_id(1);
+        let claimed: Vec<Multiaddr> = (1..=20).map(|i| public_addr(i, 1).remove(0)).collect();
+        gov.add_peer(sybil, claimed, vec![]);
+        assert_eq!(gov.peer_info(&sybil).unwrap().addrs.len(), MAX_PEER_ADDRS);
+        gov.mark_connected(&sybil);
+        gov.record_connection_addr(&sybil, &public_addr(1, 1)[0]);
+
+        // It only fills the subnet it is connected from
+        for i in 2..=4 {
+            gov.add_peer(make_peer_id(i + 10), public_addr(i, 2), vec![]);
+        }
+        gov.add_peer(make_peer_id(20), public_addr(1, 2), vec![]);
+        let mut connect = gov.tick().connect;
+        connect.sort();
+        let mut expected: Vec<NodeId> = (2..=4).map(|i| make_peer_id(i + 10)).collect();
+        expected.sort();
+        assert_eq!(connect, expected);
+        assert_eq!(gov.diversity_stats().subnet_rejections, 1);
+
+        // Disconnected, it counts by its first dial address again
+        gov.mark_disconnected(&sybil);
+        assert_eq!(gov.peer_info(&sybil).unwrap().connected_addr, None);
+    }
+
+    #[test]
+    fn test_dial_addrs_prefer_working_and_skip_private() {
+        let mut gov = Governor::new(GovernorTargets::default(), vec![]);
+        let id = make_peer_id(1);
+        let lan: Multiaddr = "/ip4/192.168.1.5/tcp/9474".parse().unwrap();
+        let v6: Multiaddr = "/ip6/2001:db8::5/tcp/9474".parse().unwrap();
+        let public = public_addr(7, 5).remove(0);
+        gov.add_peer(id, vec![lan.clone(), v6.clone(), public.clone()], vec![]);
+
+        // Non-local peer: LAN address dropped, order kept until one works
+        assert_eq!(gov.dial_addrs(&id), vec![v6.clone(), public.clone()]);
+        let dialled = format!("{public}/p2p/{id}").parse().unwrap();
+        gov.record_addr_success(&id, &dialled);
+        assert_eq!(gov.dial_addrs(&id), vec![public, v6]);
+
+        // A LAN-only peer keeps its private addresses
+        let lan_peer = make_peer_id(2);
+        gov.add_peer(lan_peer, vec![lan.clone()], vec![]);
+        assert_eq!(gov.dial_addrs(&lan_peer), vec![lan]);
+    }
+
+    #[test]
+    fn test_failing_addr_pruned() {
+        let mut gov = Governor::new(GovernorTargets::default(), vec![]);
+        let id = make_peer_id(1);
+        let (dead, good) = (public_addr(1, 1).remove(0), public_addr(2, 1).remove(0));
+        gov.add_peer(id, vec![dead.clone(), good.clone()], vec![]);
+
+        for _ in 1..ADDR_PRUNE_FAILURES {
+            gov.record_addr_failure(&id, &dead);
+        }
+        assert_eq!(gov.dial_addrs(&id), vec![good.clone(), dead.clone()]);
+        gov.record_addr_failure(&id, &dead);
+        assert_eq!(gov.dial_addrs(&id), vec![good.clone()]);
+
+        // Stays pruned when identify re-announces it
+        gov.add_peer(id, vec![dead.clone(), good.clone()], vec![]);
+        assert_eq!(gov.dial_addrs(&id), vec![good.clone()]);
+
+        // The last address is never pruned
+        for _ in 0..ADDR_PRUNE_FAILURES {
+            gov.record_addr_failure(&id, &good);
+        }
+        assert_eq!(gov.dial_addrs(&id), vec![good]);
+    }
+
+    #[test]
+    fn test_dials_capped_per_subnet() {
+        let targets = GovernorTargets {
+            max_per_subnet: 2,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec![]);
+        // Five peers in one /24 (some listing a private address first, which
+        // must not exempt them), two elsewhere
+        for i in 0..5 {
+            let mut addrs = public_addr(113, i + 1);
+            if i % 2 == 1 {
+                addrs.insert(0, "/ip4/10.0.0.1/udp/9474/quic-v1".parse().unwrap());
+            }
+            gov.add_peer(make_peer_id(i), addrs, vec![]);
+        }
+        gov.add_peer(make_peer_id(10), public_addr(114, 1), vec![]);
+        gov.add_peer(make_peer_id(11), public_addr(115, 1), vec![]);
+
+        let actions = gov.tick();
+        assert_eq!(actions.connect.len(), 4);
+        let same_subnet = actions
+            .connect
+            .iter()
+            .filter(|id| gov.peer_info(id).unwrap().subnet().as_deref() == Some("203.0.113.0/24"))
+            .count();
+        assert_eq!(same_subnet, 2);
+        assert_eq!(gov.diversity_stats().subnet_rejections, 3);
+    }
+
+    #[test]
+    fn test_hot_promotion_capped_per_subnet() {
+        let targets = GovernorTargets {
+            hot_min: 3,
+            hot_per_group: 0,
+            max_per_subnet: 1,
+            warm_min: 0,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec![]);
+        for i in 0..3 {
+            let id = make_peer_id(i);
+            gov.add_peer(id, public_addr(113, i + 1), vec![]);
+            gov.mark_connected(&id);
+        }
+        let other = make_peer_id(9);
+        gov.add_peer(other, public_addr(114, 1), vec![]);
+        gov.mark_connected(&other);
+
+        gov.tick();
+        // One hot peer per subnet, even though hot_min is not reached
+        assert_eq!(gov.counts().0, 2);
+        assert_eq!(*gov.peer_state(&other).unwrap(), PeerState::Hot);
+        assert!(gov.diversity_stats().subnet_rejections >= 2);
+    }
+
+    #[test]
+    fn test_share_source_cap() {
+        let targets = GovernorTargets {
+            max_per_share_source: 2,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec![]);
+        let (source, other) = (make_peer_id(100), make_peer_id(101));
+        for i in 0..4 {
+            gov.add_shared_peer(make_peer_id(i), make_addr(), vec![], source);
+        }
+        assert_eq!(gov.counts().2, 2);
+        assert_eq!(gov.diversity_stats().share_source_rejections, 2);
+        // The source may update the peers it shared; other sources are
+        // unaffected
+        assert!(gov.add_shared_peer(make_peer_id(0), make_addr(), vec!["g1".into()], source));
+        assert!(gov.add_shared_peer(make_peer_id(5), make_addr(), vec![], other));
+        assert_eq!(
+            gov.peer_info(&make_peer_id(5)).unwrap().learned_from,
+            Some(other)
+        );
+
+        // But it cannot rewrite a peer another source shared, nor one we
+        // know first-hand
+        let decoy = public_addr(113, 66);
+        assert!(!gov.add_shared_peer(make_peer_id(5), decoy.clone(), vec![], source));
+        let confirmed = make_peer_id(6);
+        gov.add_peer(confirmed, public_addr(7, 1), vec!["g1".into()]);
+        assert!(!gov.add_shared_peer(confirmed, decoy.clone(), vec![], source));
+        assert!(!gov.add_shared_peer(confirmed, decoy.clone(), vec![], other));
+        let peer = gov.peer_info(&confirmed).unwrap();
+        assert_eq!(peer.addrs, public_addr(7, 1));
+        assert_eq!(peer.groups, vec!["g1".to_string()]);
+        assert_eq!(peer.learned_from, None);
+        // Once identify confirms a shared peer, its source cannot either
+        gov.add_peer(make_peer_id(0), public_addr(8, 1), vec![]);
+        assert!(!gov.add_shared_peer(make_peer_id(0), decoy, vec![], source));
+        assert_eq!(
+            gov.peer_info(&make_peer_id(0)).unwrap().addrs,
+            public_addr(8, 1)
+        );
+
+        // Shared address lists are bounded
+        let many: Vec<Multiaddr> = (1..=20).map(|i| public_addr(i, 3).remove(0)).collect();
+        assert!(gov.add_shared_peer(make_peer_id(7), many, vec![], other));
+        assert_eq!(
+            gov.peer_info(&make_peer_id(7)).unwrap().addrs.len(),
+            MAX_PEER_ADDRS
+        );
+    }
+
+    #[test]
+    fn test_churn_spares_protected_peers() {
+        let targets = GovernorTargets {
+            hot_min: 0,
+            hot_per_group: 0,
+            warm_min: 0,
+            warm_max: 2,
+            protected_peers: 1,
+            churn_interval_secs: 0,
+            churn_fraction: 1.0,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec![]);
+        let (veteran, newcomer) = (make_peer_id(1), make_peer_id(2));
+        for id in [veteran, newcomer] {
+            gov.add_peer(id, make_addr(), vec![]);
+            gov.mark_connected(&id);
+        }
+        gov.peers.get_mut(&veteran).unwrap().connected_since =
+            Some(Instant::now() - PROTECTED_TENURE - Duration::from_secs(1));
+        gov.add_peer(make_peer_id(3), make_addr(), vec![]);
+        assert_eq!(gov.protected_peers(), HashSet::from([veteran]));
+
+        let actions = gov.tick();
+        assert_eq!(actions.disconnect, vec![newcomer]);
+        assert_eq!(*gov.peer_state(&veteran).unwrap(), PeerState::Warm);
+    }
+
     #[test]
     fn test_add_peer() {
         let mut gov = Governor::new(GovernorTargets::default(), vec!["g1".into()]);
@@ -852,17 +1986,177 @@ mod tests {
         let id = make_peer_id(1);
         gov.add_peer(id, make_addr(), vec![]);
 
-        gov.ban_peer(&id, "protocol violation".into());
+        gov.ban_peer(&id, "protocol violation".into(), None);
         assert!(gov.peer_state(&id).unwrap().is_banned());
 
         // Escalation
-        gov.ban_peer(&id, "repeat offense".into());
+        gov.ban_peer(&id, "repeat offense".into(), None);
         match gov.peer_state(&id).unwrap() {
             PeerState::Banned { escalation, .. } => assert_eq!(*escalation, 2),
             _ => panic!("should be banned"),
         }
     }
 
+    #[test]
+    fn test_ban_escalation_outlives_ban() {
+        let mut gov = Governor::new(GovernorTargets::default(), vec![]);
+        let id = make_peer_id(1);
+        gov.add_peer(id, make_addr(), vec![]);
+
+        gov.ban_peer(&id, "protocol violation".into(), None);
+        // Ban lapses: back to cold, history kept
+        if let Some(peer) = gov.peers.get_mut(&id) {
+            peer.state = PeerState::Cold;
+        }
+        gov.ban_peer(&id, "again".into(), None);
+        match gov.peer_state(&id).unwrap() {
+            PeerState::Banned {
+                escalation, until, ..
+            } => {
+                assert_eq!(*escalation, 2);
+                assert!(*until > Instant::now() + DEFAULT_BAN_DURATION);
+            }
+            _ => panic!("should be banned"),
+        }
+    }
+
+    #[test]
+    fn test_operator_ban_and_unban() {
+        let (mut gov, clock) = manual_governor(GovernorTargets::default(), vec![]);
+        let id = make_peer_id(1);
+        assert!(!gov.ban_peer(&id, "unknown".into(), None));
+        gov.add_peer(id, make_addr(), vec![]);
+        gov.mark_connected(&id);
+        assert!(gov.set_pinned(&id, true));
+
+        // Explicit duration overrides escalation; a ban clears the pin
+        assert!(gov.ban_peer(
+            &id,
+            "misbehaving relay".into(),
+            Some(Duration::from_secs(60))
+        ));
+        assert!(!gov.peer_info(&id).unwrap().pinned);
+        assert!(!gov.set_pinned(&id, true), "banned peers cannot be pinned");
+        clock.advance(Duration::from_secs(61));
+        gov.tick();
+        assert_eq!(*gov.peer_state(&id).unwrap(), PeerState::Cold);
+
+        // Lifted early by the operator, history kept
+        gov.ban_peer(&id, "again".into(), None);
+        assert!(gov.unban_peer(&id));
+        assert!(!gov.unban_peer(&id), "not banned any more");
+        assert_eq!(*gov.peer_state(&id).unwrap(), PeerState::Cold);
+        assert_eq!(gov.peer_info(&id).unwrap().ban_escalation, 2);
+
+        // Overlong durations are capped rather than overflowing the clock
+        assert!(gov.ban_peer(&id, "forever".into(), Some(Duration::MAX)));
+        clock.advance(MAX_BAN_DURATION);
+        gov.tick();
+        assert_eq!(*gov.peer_state(&id).unwrap(), PeerState::Cold);
+    }
+
+    #[test]
+    fn test_pinned_peer_not_churned_or_demoted() {
+        let targets = GovernorTargets {
+            hot_min: 0,
+            hot_max: 0,
+            hot_per_group: 0,
+            warm_min: 0,
+            warm_max: 2,
+            protected_peers: 0,
+            churn_interval_secs: 0,
+            churn_fraction: 1.0,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec![]);
+        let (pinned_hot, pinned_warm, other) = (make_peer_id(1), make_peer_id(2), make_peer_id(3));
+        for id in [pinned_hot, pinned_warm, other] {
+            gov.add_peer(id, make_addr(), vec![]);
+            gov.mark_connected(&id);
+        }
+        gov.peers.get_mut(&pinned_hot).unwrap().state = PeerState::Hot;
+        gov.set_pinned(&pinned_hot, true);
+        gov.set_pinned(&pinned_warm, true);
+        gov.add_peer(make_peer_id(4), make_addr(), vec![]);
+
+        let actions = gov.tick();
+        assert_eq!(actions.disconnect, vec![other]);
+        assert_eq!(*gov.peer_state(&pinned_hot).unwrap(), PeerState::Hot);
+        assert_eq!(*gov.peer_state(&pinned_warm).unwrap(), PeerState::Warm);
+    }
+
+    #[test]
+    fn test_peer_book_round_trip() {
+        let mut gov = Governor::new(GovernorTargets::default(), vec!["g1".into()]);
+        let (good, bad, placeholder) = (make_peer_id(1), make_peer_id(2), make_peer_id(3));
+        gov.add_peer(good, make_addr(), vec!["g1".into()]);
+        gov.set_peer_relay(&good, true);
+        gov.record_activity(&good, Some(42.0));
+        gov.mark_dial_failed(&good);
+        gov.add_peer(bad, make_addr(), vec![]);
+        gov.ban_peer(&bad, "spam".into(), None);
+        gov.add_peer(placeholder, vec![], vec![]);
+
+        let book = gov.peer_book();
+        assert_eq!(book.len(), 2, "peers without an address are not saved");
+
+        // A restarted node gets the peers back, minus their connections
+        let mut restarted = Governor::new(GovernorTargets::default(), vec!["g1".into()]);
+        assert_eq!(restarted.restore_peer_book(book.clone()), 2);
+        let peer = restarted.peer_info(&good).unwrap();
+        assert_eq!(peer.state, PeerState::Cold);
+        assert!(peer.is_relay);
+        assert_eq!(peer.rtt_ms, Some(42.0));
+        assert_eq!(peer.disconnect_count, 1);
+        assert_eq!(peer.groups, vec!["g1".to_string()]);
+        match restarted.peer_state(&bad).unwrap() {
+            PeerState::Banned {
+                escalation, reason, ..
+            } => {
+                assert_eq!(*escalation, 1);
+                assert_eq!(reason, "spam");
+            }
+            other => panic!("ban should survive a restart, got {other:?}"),
+        }
+        // Restoring twice does not duplicate or clobber live entries
+        assert_eq!(restarted.restore_peer_book(book.clone()), 0);
+
+        // Aged-out entries are dropped; a ban in force keeps its entry
+        let long_ago = SystemTime::now() - PEER_BOOK_MAX_AGE - Duration::from_secs(60);
+        let aged: Vec<PeerRecord> = book
+            .into_iter()
+            .map(|r| PeerRecord {
+                last_seen: long_ago,
+                ..r
+            })
+            .collect();
+        let mut stale = Governor::new(GovernorTargets::default(), vec![]);
+        assert_eq!(stale.restore_peer_book(aged), 1);
+        assert!(stale.peer_info(&good).is_none());
+        assert!(stale.peer_state(&bad).unwrap().is_banned());
+
+        // A far-future ban in a tampered book is capped, not overflowed
+        let record = PeerRecord {
+            ban: Some((
+                SystemTime::UNIX_EPOCH + Duration::from_secs(u64::MAX / 2),
+                "x".into(),
+            )),
+            ..restarted
+                .peer_book()
+                .into_iter()
+                .find(|r| r.node_id == bad)
+                .unwrap()
+        };
+        let mut tampered = Governor::new(GovernorTargets::default(), vec![]);
+        assert_eq!(tampered.restore_peer_book(vec![record]), 1);
+        match tampered.peer_state(&bad).unwrap() {
+            PeerState::Banned { until, .. } => {
+                assert!(*until <= Instant::now() + MAX_BAN_DURATION)
+            }
+            other => panic!("ban should be restored, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_hot_peers_for_group() {
         let mut gov = Governor::new(GovernorTargets::default(), vec!["g1".into(), "g2".into()]);
@@ -881,6 +2175,75 @@ mod tests {
         assert_eq!(g1_hot[0].node_id, id1);
     }
 
+    #[test]
+    fn test_uncovered_group_gets_hot_peer() {
+        let targets = GovernorTargets {
+            hot_min: 1,
+            hot_max: 1,
+            warm_min: 0,
+            ..Default::default()
+        };
+        let groups = vec!["g1".to_string(), "g2".to_string(), "g3".to_string()];
+        let mut gov = Governor::new(targets, groups);
+        let (busy, quiet, both) = (make_peer_id(1), make_peer_id(2), make_peer_id(3));
+        gov.add_peer(busy, make_addr(), vec!["g1".into()]);
+        gov.add_peer(quiet, make_addr(), vec!["g2".into()]);
+        gov.add_peer(both, make_addr(), vec!["g2".into(), "g3".into()]);
+        for id in [busy, quiet, both] {
+            gov.mark_connected(&id);
+        }
+        // The busy peer scores best, but only serves g1
+        gov.set_contribution(
+            &busy,
+            PeerContribution {
+                items_stored: 100,
+                ..PeerContribution::default()
+            },
+        );
+
+        gov.tick();
+        // g1 and g2+g3 each get a carrier, even above hot_max
+        assert_eq!(*gov.peer_state(&busy).unwrap(), PeerState::Hot);
+        assert_eq!(*gov.peer_state(&both).unwrap(), PeerState::Hot);
+        assert_eq!(*gov.peer_state(&quiet).unwrap(), PeerState::Warm);
+        let coverage = gov.group_coverage();
+        assert!(
+            coverage.iter().all(GroupCoverage::is_covered),
+            "{coverage:?}"
+        );
+        assert_eq!(coverage[1].warm, 1);
+
+        // Further ticks do not demote a group's only carrier
+        gov.tick();
+        assert_eq!(gov.counts().0, 2);
+        assert!(gov.group_coverage().iter().all(GroupCoverage::is_covered));
+    }
+
+    #[test]
+    fn test_excess_demotion_keeps_coverage() {
+        let targets = GovernorTargets {
+            hot_min: 0,
+            hot_max: 1,
+            warm_min: 0,
+            ..Default::default()
+        };
+        let mut gov = Governor::new(targets, vec!["g1".into(), "g2".into()]);
+        let (a, b, c) = (make_peer_id(1), make_peer_id(2), make_peer_id(3));
+        gov.add_peer(a, make_addr(), vec!["g1".into()]);
+        gov.add_peer(b, make_addr(), vec!["g1".into()]);
+        gov.add_peer(c, make_addr(), vec!["g2".into()]);
+        for id in [a, b, c] {
+            gov.mark_connected(&id);
+            gov.peers.get_mut(&id).unwrap().state = PeerState::Hot;
+        }
+
+        gov.tick();
+        // One g1 carrier goes; the last g1 and only g2 carrier stay
+        assert_eq!(gov.counts().0, 2);
+        assert_eq!(*gov.peer_state(&c).unwrap(), PeerState::Hot);
+        assert!(gov.group_coverage().iter().all(GroupCoverage::is_covered));
+    }
+
     #[test]
     fn test_replace_node_id() {
         let mut gov = Governor::new(GovernorTargets::default(), vec!["g1".into()]);
@@ -904,12 +2267,60 @@ mod tests {
     #[test]
     fn test_peer_score() {
         let mut peer = PeerInfo::new(make_peer_id(1), make_addr(), vec![]);
-        peer.connected_since = Some(Instant::now() - Duration::from_secs(100));
-        peer.items_delivered = 50;
+        let now = Instant::now();
+        peer.connected_since = Some(now - Duration::from_secs(100));
+        peer.contribution.items_stored = 50;
         peer.rtt_ms = Some(10.0);
 
-        let score = peer.score();
+        let score = peer.score(now);
         assert!(score > 0.0);
+
+        // First deliveries and answered requests add to the score
+        let mut better = peer.clone();
+        better.contribution.first_deliveries = 50;
+        assert!(better.score(now) > score);
+
+        // Rejected items are penalised
+        let mut spammy = peer.clone();
+        spammy.contribution.items_rejected = 5;
+        assert!(spammy.score(now) < score);
+        spammy.contribution.items_rejected = 20;
+        assert_eq!(spammy.score(now), 0.0);
+
+        // As are failed requests
+        let mut flaky = peer.clone();
+        flaky.contribution.requests_failed = 3;
+        assert!(flaky.score(now) < score);
+    }
+
+    #[test]
+    fn test_contributor_outranks_pushed_to_peer() {
+        let targets = GovernorTargets {
+            hot_min: 1,
+            ..GovernorTargets::default()
+        };
+        let mut gov = Governor::new(targets, vec!["g1".into()]);
+        let (idle, useful) = (make_peer_id(1), make_peer_id(2));
+        for id in [idle, useful] {
+            gov.add_peer(id, make_addr(), vec!["g1".into()]);
+            gov.mark_connected(&id);
+        }
+        gov.set_contribution(
+            &useful,
+            PeerContribution {
+                items_stored: 10,
+                first_deliveries: 10,
+                requests_served: 2,
+                ..PeerContribution::default()
+            },
+        );
+
+        let actions = gov.tick();
+        assert_eq!(
+            actions.transitions,
+            vec![(useful, "warm".to_string(), "hot".to_string())]
+        );
+        assert_eq!(*gov.peer_state(&idle).unwrap(), PeerState::Warm);
     }
 
     #[test]
@@ -1124,6 +2535,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lan_peers_dialable_when_enabled() {
+        let targets = GovernorTargets {
+            warm_min: 5,
+            ..Default::default()
+        };
+        let mut gov =
+            Governor::with_dial_policy(targets, vec!["g1".into()], DialPolicy::RelaysOnly);
+
+        let lan: Multiaddr = "/ip4/192.168.1.20/tcp/9474".parse().unwrap();
+        let lan_id = make_peer_id(1);
+        let known_id = make_peer_id(2);
+        gov.add_peer(known_id, make_addr(), vec!["g1".into()]);
+        gov.add_lan_peer(lan_id, vec![lan.clone()]);
+        gov.add_lan_peer(known_id, vec![lan.clone()]);
+
+        // Known peer keeps its groups and gains the LAN address
+        let known = gov.peer_info(&known_id).unwrap();
+        assert_eq!(known.groups, vec!["g1".to_string()]);
+        assert_eq!(known.addrs.len(), 2);
+        assert!(matches!(gov.peer_state(&lan_id), Some(PeerState::Cold)));
+
+        // Not dialable under RelaysOnly until LAN dialling is enabled
+        assert!(gov.tick().connect.is_empty());
+        gov.set_dial_lan_peers(true);
+        let actions = gov.tick();
+        assert!(actions.connect.contains(&lan_id));
+        assert!(actions.connect.contains(&known_id));
+
+        // An expired peer falls back to the dial policy
+        let other_id = make_peer_id(3);
+        gov.add_lan_peer(other_id, vec![lan]);
+        gov.expire_lan_peer(&other_id);
+        assert!(!gov.tick().connect.contains(&other_id));
+    }
+
+    #[test]
+    fn test_dht_peers_are_plain_cold_peers() {
+        let targets = GovernorTargets {
+            warm_min: 5,
+            max_per_share_source: 2,
+            ..Default::default()
+        };
+        let mut gov =
+            Governor::with_dial_policy(targets, vec!["g1".into()], DialPolicy::RelaysOnly);
+
+        // A known peer gains the address, keeps its groups, claims nothing
+        let id = make_peer_id(1);
+        let other: Multiaddr = "/ip4/203.0.113.9/tcp/9474".parse().unwrap();
+        gov.add_peer(id, make_addr(), vec!["g2".into()]);
+        assert!(gov.add_dht_peer(id, vec![other]));
+        let peer = gov.peer_info(&id).unwrap();
+        assert!(!peer.is_relay);
+        assert!(!peer.from_dht);
+        assert_eq!(peer.addrs.len(), 2);
+        assert_eq!(peer.groups, vec!["g2".to_string()]);
+
+        // Not a relay until it shows itself as one: RelaysOnly skips it,
+        // and it covers none of our groups
+        let new_id = make_peer_id(2);
+        assert!(gov.add_dht_peer(new_id, make_addr()));
+        assert_eq!(*gov.peer_state(&new_id).unwrap(), PeerState::Cold);
+        assert!(!gov.tick().connect.contains(&new_id));
+        assert_eq!(gov.group_coverage()[0].warm, 0);
+        gov.set_peer_relay(&new_id, true);
+        assert!(gov.tick().connect.contains(&new_id));
+
+        // The DHT is one source for the share cap
+        assert!(gov.add_dht_peer(make_peer_id(3), make_addr()));
+        assert!(!gov.add_dht_peer(make_peer_id(4), make_addr()));
+        assert!(
+            gov.add_dht_peer(id, make_addr()),
+            "known peers still update"
+        );
+        assert_eq!(gov.diversity_stats().share_source_rejections, 1);
+    }
+
+    #[test]
+    fn test_dial_policy_relays_only_allows_circuit_group_mates() {
+        let targets = GovernorTargets {
+            warm_min: 5,
+            max_per_subnet: 1,
+            ..Default::default()
+        };
+        let mut gov =
+            Governor::with_dial_policy(targets, vec!["g1".into()], DialPolicy::RelaysOnly);
+
+        let relay_id = make_peer_id(1);
+        let circuit = |peer: NodeId| -> Multiaddr {
+            format!("/ip4/203.0.113.1/tcp/9474/p2p/{relay_id}/p2p-circuit/p2p/{peer}")
+                .parse()
+                .unwrap()
+        };
+        let mate_id = make_peer_id(2);
+        let mate2_id = make_peer_id(3);
+        let stranger_id = make_peer_id(4);
+        gov.add_peer(mate_id, vec![circuit(mate_id)], vec!["g1".into()]);
+        gov.add_peer(mate2_id, vec![circuit(mate2_id)], vec!["g1".into()]);
+        gov.add_peer(stranger_id, vec![circuit(stranger_id)], vec!["g2".into()]);
+
+        // Both group-mates dialled: the relay's subnet is not theirs
+        let actions = gov.tick();
+        assert!(actions.connect.contains(&mate_id));
+        assert!(actions.connect.contains(&mate2_id));
+        assert!(!actions.connect.contains(&stranger_id));
+
+        // A hole-punched address is preferred from then on
+        let direct: Multiaddr = "/ip4/198.51.100.7/tcp/40000".parse().unwrap();
+        gov.record_direct_addr(&mate_id, &direct);
+        assert_eq!(gov.dial_addrs(&mate_id), vec![direct, circuit(mate_id)]);
+    }
+
     #[test]
     fn test_dial_policy_trusted_only() {
         let trusted_id = make_peer_id(1);
@@ -1232,4 +2755,152 @@ mod tests {
             "peer should be promoted after cooldown expires"
         );
     }
+
+    fn manual_governor(
+        targets: GovernorTargets,
+        groups: Vec<GroupId>,
+    ) -> (Governor, Arc<ManualClock>) {
+        let clock = Arc::new(ManualClock::new());
+        let gov = Governor::with_clock(targets, groups, DialPolicy::All, clock.clone());
+        (gov, clock)
+    }
+
+    #[test]
+    fn test_clock_reaps_silent_peer() {
+        let targets = GovernorTargets {
+            hot_min: 0,
+            ..Default::default()
+        };
+        let (mut gov, clock) = manual_governor(targets, vec![]);
+        let id = make_peer_id(1);
+        gov.add_peer(id, make_addr(), vec![]);
+        gov.mark_connected(&id);
+
+        clock.advance(DEAD_TIMEOUT);
+        gov.tick();
+        assert!(gov.peer_state(&id).unwrap().is_active());
+
+        clock.advance(Duration::from_secs(1));
+        let actions = gov.tick();
+        assert_eq!(actions.disconnect, vec![id]);
+        assert_eq!(*gov.peer_state(&id).unwrap(), PeerState::Cold);
+    }
+
+    #[test]
+    fn test_clock_ban_expiry_and_escalation() {
+        let (mut gov, clock) = manual_governor(GovernorTargets::default(), vec![]);
+        let id = make_peer_id(1);
+        gov.add_peer(id, make_addr(), vec![]);
+
+        // First ban: one base duration
+        gov.ban_peer(&id, "protocol violation".into(), None);
+        clock.advance(DEFAULT_BAN_DURATION - Duration::from_secs(1));
+        gov.tick();
+        assert!(gov.peer_state(&id).unwrap().is_banned());
+        clock.advance(Duration::from_secs(1));
+        gov.tick();
+        assert_eq!(*gov.peer_state(&id).unwrap(), PeerState::Cold);
+
+        // A day later it reoffends: twice as long
+        clock.advance(Duration::from_secs(86_400));
+        gov.ban_peer(&id, "again".into(), None);
+        clock.advance(DEFAULT_BAN_DURATION);
+        gov.tick();
+        assert!(gov.peer_state(&id).unwrap().is_banned());
+        clock.advance(DEFAULT_BAN_DURATION);
+        gov.tick();
+        assert_eq!(*gov.peer_state(&id).unwrap(), PeerState::Cold);
+        assert_eq!(gov.peer_info(&id).unwrap().ban_escalation, 2);
+    }
+
+    #[test]
+    fn test_clock_backoff_grows_to_cap() {
+        let targets = GovernorTargets {
+            warm_min: 5,
+            ..Default::default()
+        };
+        let (mut gov, clock) = manual_governor(targets, vec!["g1".into()]);
+        let id = make_peer_id(1);
+        gov.add_peer(id, make_addr(), vec!["g1".into()]);
+
+        for count in 1..=6 {
+            gov.mark_connected(&id);
+            gov.mark_disconnected(&id);
+            let backoff = Governor::reconnect_backoff(count);
+
+            clock.advance(backoff - Duration::from_secs(1));
+            assert!(
+                !gov.tick().connect.contains(&id),
+                "redialled inside backoff #{count}"
+            );
+            clock.advance(Duration::from_secs(2));
+            assert!(
+                gov.tick().connect.contains(&id),
+                "not redialled after backoff #{count}"
+            );
+        }
+        assert_eq!(
+            Governor::reconnect_backoff(6),
+            Duration::from_secs(ERA_0.reconnect_backoff_max_secs)
+        );
+    }
+
+    #[test]
+    fn test_clock_simulates_days_of_churn() {
+        let targets = GovernorTargets {
+            hot_min: 2,
+            hot_max: 4,
+            warm_min: 0,
+            warm_max: 8,
+            protected_peers: 2,
+            ..Default::default()
+        };
+        let (mut gov, clock) = manual_governor(targets, vec!["g1".into()]);
+        for i in 0..30 {
+            gov.add_peer(make_peer_id(i), make_addr(), vec!["g1".into()]);
+        }
+        let troublemaker = make_peer_id(29);
+        let tick = Duration::from_secs(ERA_0.tick_interval_secs);
+        let days = 3;
+
+        let mut churned = 0;
+        let mut protected_ever: HashSet<NodeId> = HashSet::new();
+        let ticks = days * 86_400 / ERA_0.tick_interval_secs;
+        for n in 0..ticks {
+            if n == 100 {
+                gov.ban_peer(&troublemaker, "bad signature".into(), None);
+            }
+            let protected = gov.protected_peers();
+            protected_ever.extend(&protected);
+
+            let actions = gov.tick();
+            for id in &actions.disconnect {
+                assert!(!protected.contains(id), "churn evicted a protected peer");
+                churned += 1;
+            }
+            for id in &actions.connect {
+                gov.mark_connected(id);
+            }
+            let active: Vec<NodeId> = gov
+                .peers
+                .values()
+                .filter(|p| p.state.is_active())
+                .map(|p| p.node_id)
+                .collect();
+            for id in active {
+                gov.record_activity(&id, Some(20.0));
+            }
+            clock.advance(tick);
+        }
+
+        // Hourly churn ran every hour of the simulation
+        let cycles = days * 24;
+        assert!(churned >= cycles, "churned {churned} in {cycles} cycles");
+        assert!(!protected_ever.is_empty());
+        assert!(!gov.peer_state(&troublemaker).unwrap().is_banned());
+        let (hot, warm, _, banned) = gov.counts();
+        assert_eq!(banned, 0);
+        assert!(hot >= 2 && hot + warm <= 8, "hot {hot}, warm {warm}");
+        assert!(clock.elapsed() >= Duration::from_secs(days * 86_400));
+    }
 }
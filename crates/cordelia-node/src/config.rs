@@ -115,6 +115,8 @@ pub struct NodeConfig {
     pub replication: ReplicationSection,
     #[serde(default)]
     pub relay: Option<RelaySection>,
+    #[serde(default)]
+    pub metrics: MetricsSection,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,6 +140,18 @@ pub struct NodeSection {
     pub groups: Vec<String>,
 }
 
+/// Prometheus `/metrics` exposure. The main API always serves `/metrics`
+/// behind the bearer token; `listen_addr` adds a separate scrape listener.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricsSection {
+    /// Separate listen address for `/metrics` (e.g. "0.0.0.0:9474").
+    #[serde(default)]
+    pub listen_addr: Option<String>,
+    /// Require the bearer token on the separate listener (default: no).
+    #[serde(default)]
+    pub require_token: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NetworkSection {
     #[serde(default = "default_listen_addr")]
@@ -151,6 +165,55 @@ pub struct NetworkSection {
     /// public IPs. Personal nodes behind NAT should leave this unset (learned via quorum).
     #[serde(default)]
     pub external_addr: Option<String>,
+    #[serde(default)]
+    pub nat: NatSection,
+    #[serde(default)]
+    pub mdns: MdnsSection,
+}
+
+/// NAT traversal: circuit relay v2 reservations, DCUtR hole punching and
+/// AutoNAT reachability probes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NatSection {
+    /// Personal nodes: relays to hold a circuit reservation with, so other
+    /// nodes can reach us through them and hole punch (0 = none).
+    #[serde(default = "default_2")]
+    pub relay_reservations: usize,
+    /// Only use and serve AutoNAT probes over public IPs. Turn off for
+    /// LAN-only deployments.
+    #[serde(default = "default_true")]
+    pub autonat_only_global: bool,
+}
+
+impl Default for NatSection {
+    fn default() -> Self {
+        Self {
+            relay_reservations: 2,
+            autonat_only_global: true,
+        }
+    }
+}
+
+/// Local-link peer discovery for LAN and offline deployments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MdnsSection {
+    /// Discover peers on the local network via mDNS (default: off).
+    #[serde(default)]
+    pub enabled: bool,
+    /// Dial LAN-discovered peers even where the role's dial policy would
+    /// not: personal nodes otherwise dial only relays, keepers only their
+    /// trusted relays. Relays dial every peer regardless.
+    #[serde(default = "default_true")]
+    pub dial_lan_peers: bool,
+}
+
+impl Default for MdnsSection {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dial_lan_peers: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -164,6 +227,18 @@ pub struct GovernorSection {
     pub hot_min: usize,
     #[serde(default = "default_20")]
     pub hot_max: usize,
+    /// Hot peers to keep per group we are in.
+    #[serde(default = "default_1")]
+    pub hot_per_group: usize,
+    /// Max connected peers per public IPv4 /24 or IPv6 /48 (0 = no limit).
+    #[serde(default = "default_2")]
+    pub max_per_subnet: usize,
+    /// Max known peers learned from one peer-share source (0 = no limit).
+    #[serde(default = "default_10")]
+    pub max_per_share_source: usize,
+    /// Long-lived good peers churn never evicts.
+    #[serde(default = "default_2")]
+    pub protected_peers: usize,
     #[serde(default = "default_10")]
     pub warm_min: usize,
     #[serde(default = "default_50")]
@@ -181,6 +256,10 @@ impl Default for GovernorSection {
         Self {
             hot_min: 2,
             hot_max: 20,
+            hot_per_group: 1,
+            max_per_subnet: 2,
+            max_per_share_source: 10,
+            protected_peers: 2,
             warm_min: 10,
             warm_max: 50,
             cold_max: 100,
@@ -201,6 +280,9 @@ pub struct ReplicationSection {
     pub tombstone_retention_days: u32,
     #[serde(default = "default_batch")]
     pub max_batch_size: u32,
+    /// Reject received items dated more than this many seconds in the future.
+    #[serde(default = "default_clock_skew")]
+    pub max_clock_skew_secs: u64,
 }
 
 impl Default for ReplicationSection {
@@ -210,6 +292,7 @@ impl Default for ReplicationSection {
             sync_interval_taciturn_secs: cordelia_protocol::SYNC_INTERVAL_TACITURN_SECS,
             tombstone_retention_days: cordelia_protocol::TOMBSTONE_RETENTION_DAYS,
             max_batch_size: cordelia_protocol::MAX_BATCH_SIZE,
+            max_clock_skew_secs: cordelia_protocol::MAX_CLOCK_SKEW_SECS,
         }
     }
 }
@@ -230,6 +313,12 @@ fn default_entity_id() -> String {
 fn default_listen_addr() -> String {
     "0.0.0.0:9474".into()
 }
+fn default_1() -> usize {
+    1
+}
+fn default_true() -> bool {
+    true
+}
 fn default_2() -> usize {
     2
 }
@@ -263,6 +352,9 @@ fn default_7() -> u32 {
 fn default_batch() -> u32 {
     cordelia_protocol::MAX_BATCH_SIZE
 }
+fn default_clock_skew() -> u64 {
+    cordelia_protocol::MAX_CLOCK_SKEW_SECS
+}
 fn default_role() -> String {
     "personal".into()
 }
@@ -352,6 +444,7 @@ impl Default for NodeConfig {
             governor: GovernorSection::default(),
             replication: ReplicationSection::default(),
             relay: None,
+            metrics: MetricsSection::default(),
         }
     }
 }
@@ -365,7 +458,10 @@ mod tests {
         let cfg = NodeConfig::default();
         assert_eq!(cfg.governor.hot_min, 2);
         assert_eq!(cfg.governor.hot_max, 20);
+        assert_eq!(cfg.governor.hot_per_group, 1);
+        assert_eq!(cfg.governor.max_per_subnet, 2);
         assert_eq!(cfg.replication.max_batch_size, 100);
+        assert_eq!(cfg.replication.max_clock_skew_secs, 300);
     }
 
     #[test]
@@ -408,6 +504,59 @@ tombstone_retention_days = 7
         assert_eq!(cfg.governor.hot_min, 2);
     }
 
+    #[test]
+    fn test_parse_metrics_section() {
+        let cfg = NodeConfig::default();
+        assert!(cfg.metrics.listen_addr.is_none());
+
+        let toml_str = r#"
+[node]
+entity_id = "boot1"
+
+[metrics]
+listen_addr = "0.0.0.0:9475"
+"#;
+        let cfg: NodeConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.metrics.listen_addr.as_deref(), Some("0.0.0.0:9475"));
+        assert!(!cfg.metrics.require_token);
+    }
+
+    #[test]
+    fn test_parse_nat_section() {
+        let cfg = NodeConfig::default();
+        assert_eq!(cfg.network.nat.relay_reservations, 2);
+        assert!(cfg.network.nat.autonat_only_global);
+
+        let toml_str = r#"
+[node]
+entity_id = "laptop"
+
+[network.nat]
+relay_reservations = 0
+"#;
+        let cfg: NodeConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.network.nat.relay_reservations, 0);
+        assert!(cfg.network.nat.autonat_only_global);
+    }
+
+    #[test]
+    fn test_parse_mdns_section() {
+        let cfg = NodeConfig::default();
+        assert!(!cfg.network.mdns.enabled);
+        assert!(cfg.network.mdns.dial_lan_peers);
+
+        let toml_str = r#"
+[node]
+entity_id = "office"
+
+[network.mdns]
+enabled = true
+"#;
+        let cfg: NodeConfig = toml::from_str(toml_str).unwrap();
+        assert!(cfg.network.mdns.enabled);
+        assert!(cfg.network.mdns.dial_lan_peers);
+    }
+
     #[test]
     fn test_default_role_is_personal() {
         let cfg = NodeConfig::default();
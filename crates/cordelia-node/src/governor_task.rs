@@ -10,8 +10,12 @@
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
-use cordelia_governor::Governor;
+use chrono::{DateTime, SecondsFormat, Utc};
+use cordelia_api::{PeerAdminAction, PeerAdminError};
+use cordelia_governor::{Governor, PeerRecord};
+use cordelia_storage::{PeerBookRow, Storage};
 use libp2p::{Multiaddr, PeerId};
 use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
 
@@ -19,6 +23,12 @@ use crate::config::BootnodeEntry;
 use crate::peer_pool::PeerPool;
 use crate::swarm_task::{SwarmCommand, SwarmEvent2};
 
+/// Peers with a clock sample needed before we suspect our own clock.
+const MIN_CLOCK_SAMPLES: usize = 3;
+
+/// Governor ticks between peer book saves (5 min at the era's 10s tick).
+const PEER_BOOK_SAVE_TICKS: u64 = 30;
+
 #[allow(clippy::too_many_arguments)]
 /// Run the governor loop until shutdown.
 ///
@@ -34,8 +44,11 @@ pub async fn run_governor_loop(
     shared_groups: Arc<RwLock<Vec<String>>>,
     relay_learned_groups: Option<Arc<RwLock<HashSet<String>>>>,
     our_peer_id: PeerId,
+    storage: Arc<dyn Storage>,
     mut shutdown: broadcast::Receiver<()>,
 ) {
+    // Peers known before the restart: dialable even if every bootnode is down
+    load_peer_book(&mut *governor.lock().await, storage.as_ref(), our_peer_id);
     if bootnodes.is_empty() {
         tracing::warn!("no bootnodes configured -- node will only accept inbound connections");
     }
@@ -44,11 +57,13 @@ pub async fn run_governor_loop(
     let mut unresolved_bootnodes: Vec<BootnodeEntry> = Vec::new();
     // Track resolved bootnode addrs for persistent reconnection
     let mut resolved_bootnode_addrs: Vec<(BootnodeEntry, Multiaddr)> = Vec::new();
+    // Bootnode PeerIds invented by seed_bootnode, until the real one is known
+    let mut placeholders: HashSet<PeerId> = HashSet::new();
     {
         let mut gov = governor.lock().await;
         for boot in &bootnodes {
             if let Some(addr) = parse_bootnode_multiaddr(boot).await {
-                seed_bootnode(&mut gov, &boot.addr, addr.clone());
+                placeholders.extend(seed_bootnode(&mut gov, &boot.addr, addr.clone()));
                 resolved_bootnode_addrs.push((boot.clone(), addr));
             } else {
                 tracing::info!(bootnode = &boot.addr, "bootnode DNS pending, will retry");
@@ -65,15 +80,21 @@ pub async fn run_governor_loop(
     let mut tick_count: u64 = 0;
     let group_exchange_every = cordelia_protocol::GROUP_EXCHANGE_TICKS;
     let peer_discovery_every = cordelia_protocol::PEER_DISCOVERY_TICKS;
+    // Whether peers currently agree our clock is off (warn on transitions only)
+    let mut clock_suspect = false;
 
     loop {
         tokio::select! {
             _ = tick_timer.tick() => {
-                // Sync dynamic groups into governor before tick
+                // Sync dynamic groups and peer contributions into governor before tick
+                let contributions = pool.contributions().await;
                 let actions = {
                     let mut gov = governor.lock().await;
                     let current_groups = shared_groups.read().await.clone();
                     gov.set_groups(current_groups);
+                    for (node_id, contribution) in contributions {
+                        gov.set_contribution(&node_id, contribution);
+                    }
                     gov.tick()
                 };
 
@@ -106,29 +127,34 @@ pub async fn run_governor_loop(
                     }
                 }
 
-                // Connect to promoted peers
+                // Connect to promoted peers, on all their addresses
                 for node_id in &actions.connect {
-                    let addr = {
-                        let gov = governor.lock().await;
-                        gov.peer_info(node_id)
-                            .and_then(|p| p.addrs.first().cloned())
-                    };
-
-                    if let Some(addr) = addr {
-                        tracing::debug!(
-                            peer = %node_id,
-                            %addr,
-                            "gov: initiating dial to promoted peer"
-                        );
-                        pending_dials.insert(addr.clone(), *node_id);
-                        if let Err(e) = cmd_tx.send(SwarmCommand::Dial(addr.clone())).await {
-                            tracing::warn!(%addr, "gov: failed to send dial command: {e}");
-                        }
-                    } else {
+                    let addrs = governor.lock().await.dial_addrs(node_id);
+                    if addrs.is_empty() {
                         tracing::warn!(
                             peer = %node_id,
                             "gov: connect requested but no address known"
                         );
+                        continue;
+                    }
+                    tracing::debug!(
+                        peer = %node_id,
+                        addrs = ?addrs,
+                        "gov: initiating dial to promoted peer"
+                    );
+                    if placeholders.contains(node_id) {
+                        // Real PeerId unknown until connected: dial by address
+                        for addr in addrs {
+                            pending_dials.insert(addr.clone(), *node_id);
+                            if let Err(e) = cmd_tx.send(SwarmCommand::Dial(addr.clone())).await {
+                                tracing::warn!(%addr, "gov: failed to send dial command: {e}");
+                            }
+                        }
+                    } else if let Err(e) = cmd_tx
+                        .send(SwarmCommand::DialPeer { peer: *node_id, addrs })
+                        .await
+                    {
+                        tracing::warn!(peer = %node_id, "gov: failed to send dial command: {e}");
                     }
                 }
 
@@ -146,6 +172,10 @@ pub async fn run_governor_loop(
 
                 tick_count += 1;
 
+                if tick_count.is_multiple_of(PEER_BOOK_SAVE_TICKS) {
+                    save_peer_book(&*governor.lock().await, storage.as_ref());
+                }
+
                 // Retry unresolved bootnodes (DNS may not have been ready at startup)
                 if !unresolved_bootnodes.is_empty() && tick_count <= 6 {
                     let mut still_unresolved = Vec::new();
@@ -153,7 +183,7 @@ pub async fn run_governor_loop(
                     for boot in unresolved_bootnodes.drain(..) {
                         if let Some(addr) = parse_bootnode_multiaddr(&boot).await {
                             tracing::info!(bootnode = &boot.addr, "bootnode resolved on retry");
-                            seed_bootnode(&mut gov, &boot.addr, addr.clone());
+                            placeholders.extend(seed_bootnode(&mut gov, &boot.addr, addr.clone()));
                             resolved_bootnode_addrs.push((boot, addr));
                         } else {
                             still_unresolved.push(boot);
@@ -236,6 +266,9 @@ pub async fn run_governor_loop(
                                 request: cordelia_protocol::messages::GroupExchange {
                                     groups: groups.clone(),
                                     descriptors: None, // Enriched by swarm_task (R4-030)
+                                    membership: None, // Enriched by swarm_task
+                                    membership_heads: None, // Enriched by swarm_task
+                                    sent_at: None,    // Stamped by swarm_task
                                 },
                                 response_tx: resp_tx,
                             }).await;
@@ -291,13 +324,29 @@ pub async fn run_governor_loop(
                     tokio::spawn(async move {
                         discover_peers(&pool2, &gov2, &cmd_tx2, local_id).await;
                     });
+
+                    // DHT: random walk, our provider records (relays), and
+                    // the relays carrying any group we lack hot peers for
+                    let _ = cmd_tx.send(SwarmCommand::DhtRandomWalk).await;
+                    let _ = cmd_tx.send(SwarmCommand::ProvideGroups).await;
+                    let uncovered: Vec<String> = governor
+                        .lock()
+                        .await
+                        .group_coverage()
+                        .into_iter()
+                        .filter(|c| !c.is_covered())
+                        .map(|c| c.group_id)
+                        .collect();
+                    for group_id in uncovered {
+                        let _ = cmd_tx.send(SwarmCommand::FindGroupProviders { group_id }).await;
+                    }
                 }
             }
 
             // Handle swarm events
             event = event_rx.recv() => {
                 match event {
-                    Ok(SwarmEvent2::PeerConnected { peer_id, addrs }) => {
+                    Ok(SwarmEvent2::PeerConnected { peer_id, addrs, dialed_addr }) => {
                         tracing::info!(
                             %peer_id,
                             addr = ?addrs.first(),
@@ -312,8 +361,17 @@ pub async fn run_governor_loop(
                             .find_map(|a| pending_dials.remove(a));
 
                         let mut gov = governor.lock().await;
+                        if gov.peer_state(&peer_id).is_some_and(|s| s.is_banned()) {
+                            tracing::info!(%peer_id, "gov: dropping connection from banned peer");
+                            drop(gov);
+                            if let Err(e) = cmd_tx.send(SwarmCommand::Disconnect(peer_id)).await {
+                                tracing::warn!(%peer_id, "gov: disconnect command send failed: {e}");
+                            }
+                            continue;
+                        }
                         if let Some(old_id) = placeholder {
                             if old_id != peer_id {
+                                placeholders.remove(&old_id);
                                 gov.replace_node_id(&old_id, peer_id, vec![]);
                                 tracing::info!(
                                     %peer_id,
@@ -328,6 +386,12 @@ pub async fn run_governor_loop(
                             gov.add_peer(peer_id, addrs.clone(), vec![]);
                             gov.mark_connected(&peer_id);
                         }
+                        if let Some(addr) = &dialed_addr {
+                            gov.record_addr_success(&peer_id, addr);
+                        }
+                        if let Some(addr) = addrs.first() {
+                            gov.record_connection_addr(&peer_id, addr);
+                        }
                         // Check if governor knows this peer is a relay (e.g. seeded bootnode)
                         let is_relay = gov.peer_info(&peer_id)
                             .map(|p| p.is_relay)
@@ -367,6 +431,9 @@ pub async fn run_governor_loop(
                                 request: cordelia_protocol::messages::GroupExchange {
                                     groups: groups.clone(),
                                     descriptors: None, // Enriched by swarm_task (R4-030)
+                                    membership: None, // Enriched by swarm_task
+                                    membership_heads: None, // Enriched by swarm_task
+                                    sent_at: None,    // Stamped by swarm_task
                                 },
                                 response_tx: resp_tx,
                             }).await {
@@ -415,9 +482,37 @@ pub async fn run_governor_loop(
                         governor.lock().await.record_activity(&peer_id, Some(rtt_ms));
                         pool.update_rtt(&peer_id, rtt_ms).await;
                     }
+                    Ok(SwarmEvent2::PeerClock { peer_id, remote_time, received_at }) => {
+                        let Some(offset_ms) =
+                            pool.record_clock_sample(&peer_id, remote_time, received_at).await
+                        else {
+                            continue;
+                        };
+                        let skew_ms = cordelia_protocol::MAX_CLOCK_SKEW_SECS as i64 * 1000;
+                        if offset_ms.abs() > skew_ms {
+                            tracing::info!(%peer_id, offset_ms, "gov: peer clock skewed");
+                        } else {
+                            tracing::trace!(%peer_id, offset_ms, "gov: peer clock offset");
+                        }
+                        // Most peers agreeing we are off means our clock is wrong
+                        if let Some((median_ms, samples)) = pool.median_clock_offset().await {
+                            let suspect = samples >= MIN_CLOCK_SAMPLES && median_ms.abs() > skew_ms;
+                            if suspect && !clock_suspect {
+                                tracing::warn!(
+                                    median_offset_ms = median_ms,
+                                    peers = samples,
+                                    "gov: local clock looks wrong -- peers disagree by more than the allowed skew; our writes may be rejected or lose conflicts"
+                                );
+                            } else if !suspect && clock_suspect {
+                                tracing::info!(median_offset_ms = median_ms, "gov: local clock agrees with peers again");
+                            }
+                            clock_suspect = suspect;
+                        }
+                    }
                     Ok(SwarmEvent2::IdentifyReceived {
                         peer_id,
                         listen_addrs,
+                        is_relay,
                         ..
                     }) => {
                         let mut gov = governor.lock().await;
@@ -441,6 +536,7 @@ pub async fn run_governor_loop(
                             .map(|p| p.node_id);
 
                         if let Some(old_id) = placeholder {
+                            placeholders.remove(&old_id);
                             gov.replace_node_id(&old_id, peer_id, vec![]);
                             tracing::info!(
                                 %peer_id,
@@ -448,6 +544,12 @@ pub async fn run_governor_loop(
                                 "replaced bootnode placeholder via identify"
                             );
                         }
+                        // A peer serving circuit reservations is a relay (the
+                        // DHT does not tell us)
+                        if is_relay {
+                            gov.set_peer_relay(&peer_id, true);
+                            pool.set_relay(&peer_id, true).await;
+                        }
 
                         // Update peer addresses from identify.
                         // Filter: always remove loopback. If the peer announces
@@ -476,15 +578,59 @@ pub async fn run_governor_loop(
                             }
                         }
                     }
-                    Ok(SwarmEvent2::DialFailure { peer_id }) => {
+                    Ok(SwarmEvent2::DialFailure { peer_id, failed_addrs }) => {
                         if let Some(peer_id) = peer_id {
-                            tracing::debug!(%peer_id, "gov: dial failure, marking failed");
-                            governor.lock().await.mark_dial_failed(&peer_id);
+                            tracing::debug!(
+                                %peer_id,
+                                failed = failed_addrs.len(),
+                                "gov: dial failure, marking failed"
+                            );
+                            let mut gov = governor.lock().await;
+                            for addr in &failed_addrs {
+                                gov.record_addr_failure(&peer_id, addr);
+                            }
+                            gov.mark_dial_failed(&peer_id);
                         }
                     }
                     Ok(SwarmEvent2::ExternalAddrConfirmed { addr }) => {
                         tracing::info!(%addr, "external address confirmed");
                     }
+                    Ok(SwarmEvent2::DirectConnection { peer_id, addr }) => {
+                        tracing::info!(%peer_id, %addr, "gov: direct connection to peer");
+                        let mut gov = governor.lock().await;
+                        gov.record_direct_addr(&peer_id, &addr);
+                        let addrs = gov.dial_addrs(&peer_id);
+                        if !addrs.is_empty() {
+                            pool.update_addrs(&peer_id, addrs).await;
+                        }
+                    }
+                    Ok(SwarmEvent2::LanPeerDiscovered { peer_id, addrs }) => {
+                        if peer_id == our_peer_id {
+                            continue;
+                        }
+                        tracing::debug!(%peer_id, ?addrs, "gov: LAN peer discovered");
+                        governor.lock().await.add_lan_peer(peer_id, addrs);
+                    }
+                    Ok(SwarmEvent2::LanPeerExpired { peer_id }) => {
+                        governor.lock().await.expire_lan_peer(&peer_id);
+                    }
+                    Ok(SwarmEvent2::DhtPeerDiscovered { peer_id, addrs }) => {
+                        if peer_id == our_peer_id || addrs.is_empty() {
+                            continue;
+                        }
+                        tracing::debug!(%peer_id, ?addrs, "gov: DHT peer discovered");
+                        governor.lock().await.add_dht_peer(peer_id, addrs);
+                    }
+                    Ok(SwarmEvent2::GroupProvidersFound { group_id, providers }) => {
+                        let mut gov = governor.lock().await;
+                        for (peer_id, addrs) in providers {
+                            if peer_id == our_peer_id || addrs.is_empty() {
+                                continue;
+                            }
+                            tracing::debug!(%peer_id, group = %group_id, "gov: group provider found");
+                            gov.add_dht_peer(peer_id, addrs);
+                        }
+                    }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
                         tracing::warn!("governor event receiver lagged by {n}");
                     }
@@ -497,6 +643,7 @@ pub async fn run_governor_loop(
             _ = shutdown.recv() => {
                 let (warm, hot) = pool.peer_count_by_state().await;
                 tracing::info!(warm, hot, ticks = tick_count, "gov: shutting down");
+                save_peer_book(&*governor.lock().await, storage.as_ref());
                 return;
             }
         }
@@ -544,11 +691,11 @@ async fn discover_peers(
         futs.push((relay.node_id, resp_rx));
     }
 
-    // Collect all responses
+    // Collect all responses, remembering which relay shared each peer
     let mut all_peers = Vec::new();
     for (relay_id, resp_rx) in futs {
         match resp_rx.await {
-            Ok(Ok(resp)) => all_peers.extend(resp.peers),
+            Ok(Ok(resp)) => all_peers.extend(resp.peers.into_iter().map(|pa| (relay_id, pa))),
             Ok(Err(e)) => {
                 tracing::debug!(relay = %relay_id, "peer discovery failed: {e}");
             }
@@ -564,7 +711,8 @@ async fn discover_peers(
 
     let mut gov = governor.lock().await;
     let mut added = 0u32;
-    for pa in &all_peers {
+    let mut refused = 0u32;
+    for (source, pa) in &all_peers {
         let peer_id: PeerId = match pa.peer_id.parse() {
             Ok(id) => id,
             Err(_) => continue,
@@ -588,18 +736,22 @@ async fn discover_peers(
         if addrs.is_empty() {
             continue;
         }
-        gov.add_peer(peer_id, addrs, pa.groups.clone());
-        if pa.role == "relay" {
-            gov.set_peer_relay(&peer_id, true);
+        // Capped per source: one relay cannot fill our cold set. Its claim
+        // that a peer is a relay is not taken: identify tells us once
+        // connected.
+        if !gov.add_shared_peer(peer_id, addrs, pa.groups.clone(), *source) {
+            refused += 1;
+            continue;
         }
         added += 1;
     }
     drop(gov);
 
-    if added > 0 {
+    if added > 0 || refused > 0 {
         tracing::info!(
             relays_queried = targets.len(),
             discovered = added,
+            refused,
             "peer discovery: registered new peers via gossip"
         );
     }
@@ -636,9 +788,183 @@ async fn parse_bootnode_multiaddr(boot: &BootnodeEntry) -> Option<Multiaddr> {
     }
 }
 
+/// Per-group hot-peer coverage for the API.
+pub fn group_coverage(gov: &Governor) -> Vec<cordelia_api::GroupCoverage> {
+    gov.group_coverage()
+        .into_iter()
+        .map(|c| cordelia_api::GroupCoverage {
+            covered: c.is_covered(),
+            group_id: c.group_id,
+            hot: c.hot,
+            warm: c.warm,
+            target: c.target,
+        })
+        .collect()
+}
+
+/// Carry out an operator peer action (`peers/*` admin routes). Network
+/// effects go through the swarm; bans, unbans and pins through the governor.
+pub async fn peer_admin(
+    governor: &Mutex<Governor>,
+    pool: &PeerPool,
+    cmd_tx: &mpsc::Sender<SwarmCommand>,
+    action: PeerAdminAction,
+) -> Result<(), PeerAdminError> {
+    let parse_peer = |id: &str| {
+        id.parse::<PeerId>()
+            .map_err(|e| PeerAdminError::Invalid(format!("invalid peer_id: {e}")))
+    };
+    let send = |cmd: SwarmCommand| async move {
+        cmd_tx
+            .send(cmd)
+            .await
+            .map_err(|e| PeerAdminError::Failed(format!("swarm unavailable: {e}")))
+    };
+
+    match action {
+        PeerAdminAction::Connect { addr } => {
+            let addr: Multiaddr = addr
+                .parse()
+                .map_err(|e| PeerAdminError::Invalid(format!("invalid multiaddr: {e}")))?;
+            tracing::info!(%addr, "gov: operator dial");
+            send(SwarmCommand::Dial(addr)).await
+        }
+        PeerAdminAction::Disconnect { peer_id } => {
+            let peer_id = parse_peer(&peer_id)?;
+            if pool.get(&peer_id).await.is_none() {
+                return Err(PeerAdminError::NotFound("peer not connected".into()));
+            }
+            tracing::info!(%peer_id, "gov: operator disconnect");
+            send(SwarmCommand::Disconnect(peer_id)).await
+        }
+        PeerAdminAction::Ban {
+            peer_id,
+            reason,
+            duration_secs,
+        } => {
+            let peer_id = parse_peer(&peer_id)?;
+            let banned = governor.lock().await.ban_peer(
+                &peer_id,
+                reason,
+                duration_secs.map(Duration::from_secs),
+            );
+            if !banned {
+                return Err(PeerAdminError::NotFound("unknown peer".into()));
+            }
+            // Out of the pool now, so nothing more is pushed to it
+            if pool.remove(&peer_id).await.is_some() {
+                send(SwarmCommand::Disconnect(peer_id)).await?;
+            }
+            Ok(())
+        }
+        PeerAdminAction::Unban { peer_id } => {
+            let peer_id = parse_peer(&peer_id)?;
+            if !governor.lock().await.unban_peer(&peer_id) {
+                return Err(PeerAdminError::NotFound("peer is not banned".into()));
+            }
+            Ok(())
+        }
+        PeerAdminAction::Pin { peer_id, pinned } => {
+            let peer_id = parse_peer(&peer_id)?;
+            if !governor.lock().await.set_pinned(&peer_id, pinned) {
+                return Err(PeerAdminError::NotFound("unknown or banned peer".into()));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Restore known peers saved by a previous run into the governor.
+fn load_peer_book(gov: &mut Governor, storage: &dyn Storage, our_peer_id: PeerId) {
+    let rows = match storage.load_peer_book() {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::warn!("gov: failed to load peer book: {e}");
+            return;
+        }
+    };
+    let saved = rows.len();
+    let records: Vec<PeerRecord> = rows
+        .into_iter()
+        .filter_map(peer_record_from_row)
+        .filter(|r| r.node_id != our_peer_id)
+        .collect();
+    let restored = gov.restore_peer_book(records);
+    if saved > 0 {
+        tracing::info!(
+            restored,
+            dropped = saved - restored,
+            "gov: restored peers from peer book"
+        );
+    }
+}
+
+/// Persist the governor's known peers (periodically and on shutdown).
+fn save_peer_book(gov: &Governor, storage: &dyn Storage) {
+    let rows: Vec<PeerBookRow> = gov.peer_book().iter().map(peer_book_row).collect();
+    match storage.save_peer_book(&rows) {
+        Ok(()) => tracing::debug!(peers = rows.len(), "gov: peer book saved"),
+        Err(e) => tracing::warn!("gov: failed to save peer book: {e}"),
+    }
+}
+
+fn peer_book_row(record: &PeerRecord) -> PeerBookRow {
+    let timestamp = |t: std::time::SystemTime| {
+        DateTime::<Utc>::from(t).to_rfc3339_opts(SecondsFormat::Secs, true)
+    };
+    PeerBookRow {
+        peer_id: record.node_id.to_string(),
+        addrs: record.addrs.iter().map(Multiaddr::to_string).collect(),
+        groups: record.groups.clone(),
+        is_relay: record.is_relay,
+        rtt_ms: record.rtt_ms,
+        disconnect_count: record.disconnect_count,
+        ban_escalation: record.ban_escalation,
+        banned_until: record.ban.as_ref().map(|(until, _)| timestamp(*until)),
+        ban_reason: record.ban.as_ref().map(|(_, reason)| reason.clone()),
+        last_seen: timestamp(record.last_seen),
+    }
+}
+
+/// None for rows that no longer parse (they are dropped on the next save).
+fn peer_record_from_row(row: PeerBookRow) -> Option<PeerRecord> {
+    let time = |ts: &str| {
+        DateTime::parse_from_rfc3339(ts)
+            .ok()
+            .map(|t| std::time::SystemTime::from(t.with_timezone(&Utc)))
+    };
+    let ban = match (row.banned_until.as_deref().and_then(time), row.ban_reason) {
+        (Some(until), reason) => Some((until, reason.unwrap_or_default())),
+        _ => None,
+    };
+    Some(PeerRecord {
+        node_id: row.peer_id.parse().ok()?,
+        addrs: row.addrs.iter().filter_map(|a| a.parse().ok()).collect(),
+        groups: row.groups,
+        is_relay: row.is_relay,
+        rtt_ms: row.rtt_ms,
+        disconnect_count: row.disconnect_count,
+        ban_escalation: row.ban_escalation,
+        ban,
+        last_seen: time(&row.last_seen)?,
+    })
+}
+
 /// Seed a resolved bootnode into the governor as a cold relay peer.
 /// Uses a deterministic PeerId derived from the address (replaced on handshake).
-fn seed_bootnode(gov: &mut Governor, bootnode_addr: &str, addr: Multiaddr) {
+/// A bootnode already known from the peer book keeps its entry.
+/// Returns the placeholder PeerId, if one was created.
+fn seed_bootnode(gov: &mut Governor, bootnode_addr: &str, addr: Multiaddr) -> Option<PeerId> {
+    let known = gov
+        .all_peers()
+        .find(|p| p.addrs.contains(&addr))
+        .map(|p| p.node_id);
+    if let Some(node_id) = known {
+        gov.set_peer_relay(&node_id, true);
+        tracing::info!(bootnode = bootnode_addr, addr = %addr, peer = %node_id, "bootnode known from peer book");
+        return None;
+    }
+
     // Generate a deterministic PeerId from the address hash.
     // This gets replaced with the real PeerId on first connect (via identify).
     let hash = cordelia_crypto::sha256_hex(bootnode_addr.as_bytes());
@@ -653,6 +979,7 @@ fn seed_bootnode(gov: &mut Governor, bootnode_addr: &str, addr: Multiaddr) {
     gov.add_peer(placeholder_id, vec![addr.clone()], vec![]);
     gov.set_peer_relay(&placeholder_id, true);
     tracing::info!(bootnode = bootnode_addr, addr = %addr, "seeded bootnode (relay)");
+    Some(placeholder_id)
 }
 
 /// Filter identify listen_addrs for storage in governor/pool.
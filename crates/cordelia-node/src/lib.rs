@@ -41,6 +41,26 @@ impl cordelia_storage::Storage for StorageClone {
     fn delete_l2_item(&self, id: &str) -> cordelia_storage::Result<bool> {
         self.0.delete_l2_item(id)
     }
+    fn write_l2_conflict(
+        &self,
+        conflict: &cordelia_storage::L2ConflictRow,
+        max_siblings: usize,
+    ) -> cordelia_storage::Result<bool> {
+        self.0.write_l2_conflict(conflict, max_siblings)
+    }
+    fn list_l2_conflicts(
+        &self,
+        item_id: Option<&str>,
+    ) -> cordelia_storage::Result<Vec<cordelia_storage::L2ConflictRow>> {
+        self.0.list_l2_conflicts(item_id)
+    }
+    fn delete_l2_conflicts(
+        &self,
+        item_id: &str,
+        checksums: &[String],
+    ) -> cordelia_storage::Result<u32> {
+        self.0.delete_l2_conflicts(item_id, checksums)
+    }
     fn read_l2_item_meta(
         &self,
         id: &str,
@@ -74,6 +94,16 @@ impl cordelia_storage::Storage for StorageClone {
         self.0
             .write_group_signature(id, owner_id, owner_pubkey, signature)
     }
+    fn write_group_authority(
+        &self,
+        id: &str,
+        authority: &cordelia_storage::GroupAuthority,
+    ) -> cordelia_storage::Result<()> {
+        self.0.write_group_authority(id, authority)
+    }
+    fn set_group_hlc(&self, id: &str, hlc: Option<&str>) -> cordelia_storage::Result<()> {
+        self.0.set_group_hlc(id, hlc)
+    }
     fn read_group(&self, id: &str) -> cordelia_storage::Result<Option<cordelia_storage::GroupRow>> {
         self.0.read_group(id)
     }
@@ -123,9 +153,60 @@ impl cordelia_storage::Storage for StorageClone {
         self.0
             .purge_deleted_groups(tombstone_culture, retention_days)
     }
+    fn purge_expired_items(&self) -> cordelia_storage::Result<u32> {
+        self.0.purge_expired_items()
+    }
+    fn purge_group_items_older_than(
+        &self,
+        group_id: &str,
+        ttl_secs: u64,
+    ) -> cordelia_storage::Result<u32> {
+        self.0.purge_group_items_older_than(group_id, ttl_secs)
+    }
+    fn count_group_items(&self, group_id: &str) -> cordelia_storage::Result<u32> {
+        self.0.count_group_items(group_id)
+    }
+    fn purge_group_items(&self, group_id: &str) -> cordelia_storage::Result<u32> {
+        self.0.purge_group_items(group_id)
+    }
+    fn archive_group_items(&self, group_id: &str) -> cordelia_storage::Result<(u32, String)> {
+        self.0.archive_group_items(group_id)
+    }
+    fn record_departure(
+        &self,
+        departure: &cordelia_storage::GroupDepartureRow,
+    ) -> cordelia_storage::Result<()> {
+        self.0.record_departure(departure)
+    }
+    fn list_departures(
+        &self,
+    ) -> cordelia_storage::Result<Vec<cordelia_storage::GroupDepartureRow>> {
+        self.0.list_departures()
+    }
+    fn append_membership_entry(
+        &self,
+        entry: &cordelia_storage::MembershipLogRow,
+    ) -> cordelia_storage::Result<bool> {
+        self.0.append_membership_entry(entry)
+    }
+    fn list_membership_log(
+        &self,
+        group_id: &str,
+    ) -> cordelia_storage::Result<Vec<cordelia_storage::MembershipLogRow>> {
+        self.0.list_membership_log(group_id)
+    }
     fn log_access(&self, entry: &cordelia_storage::AccessLogEntry) -> cordelia_storage::Result<()> {
         self.0.log_access(entry)
     }
+    fn save_peer_book(
+        &self,
+        peers: &[cordelia_storage::PeerBookRow],
+    ) -> cordelia_storage::Result<()> {
+        self.0.save_peer_book(peers)
+    }
+    fn load_peer_book(&self) -> cordelia_storage::Result<Vec<cordelia_storage::PeerBookRow>> {
+        self.0.load_peer_book()
+    }
     fn read_l2_index(&self) -> cordelia_storage::Result<Option<Vec<u8>>> {
         self.0.read_l2_index()
     }
@@ -162,6 +243,9 @@ impl cordelia_storage::Storage for StorageClone {
     ) -> cordelia_storage::Result<Option<cordelia_storage::DeviceRow>> {
         self.0.get_device_by_token_hash(token_hash)
     }
+    fn touch_device(&self, device_id: &str) -> cordelia_storage::Result<()> {
+        self.0.touch_device(device_id)
+    }
 }
 
 pub fn expand_tilde(path: &str) -> PathBuf {
@@ -4,6 +4,7 @@
 //!   cordelia-node                      # Run with default config
 //!   cordelia-node --config path.toml   # Run with custom config
 //!   cordelia-node identity             # Show node identity
+//!   cordelia-node peers ban <peer_id> --reason "..."   # Manage peers
 
 use cordelia_node::config::{self, RelayPosture};
 use cordelia_node::governor_task;
@@ -17,8 +18,9 @@ use std::sync::Arc;
 
 use cordelia_api::AppState;
 use cordelia_crypto::NodeIdentity;
-use cordelia_governor::{DialPolicy, Governor, GovernorTargets};
-use cordelia_replication::{ReplicationConfig, ReplicationEngine};
+use cordelia_governor::{Clock, DialPolicy, Governor, GovernorTargets, SystemClock};
+use cordelia_replication::hlc::HlcClock;
+use cordelia_replication::{GroupCulture, ReplicationConfig, ReplicationEngine};
 use cordelia_storage::SqliteStorage;
 use libp2p::PeerId;
 
@@ -44,8 +46,11 @@ enum Commands {
     Run,
     /// Show node status (queries local API)
     Status,
-    /// List connected peers
-    Peers,
+    /// List connected peers, or manage them
+    Peers {
+        #[command(subcommand)]
+        action: Option<PeersAction>,
+    },
     /// List groups
     Groups,
     /// Show replication diagnostics
@@ -57,6 +62,34 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum PeersAction {
+    /// List connected peers (default)
+    List,
+    /// Dial a multiaddr
+    Connect {
+        /// e.g. /ip4/203.0.113.7/tcp/9474
+        addr: String,
+    },
+    /// Drop the connection to a peer
+    Disconnect { peer_id: String },
+    /// Ban and disconnect a peer
+    Ban {
+        peer_id: String,
+        #[arg(long)]
+        reason: String,
+        /// Ban length in seconds (default: escalating base duration)
+        #[arg(long)]
+        duration_secs: Option<u64>,
+    },
+    /// Lift a ban
+    Unban { peer_id: String },
+    /// Exempt a peer from churn and demotion
+    Pin { peer_id: String },
+    /// Undo a pin
+    Unpin { peer_id: String },
+}
+
 #[derive(Subcommand)]
 enum IdentityAction {
     /// Generate a new identity keypair
@@ -105,8 +138,42 @@ async fn main() -> anyhow::Result<()> {
         Some(Commands::Status) => {
             cli_api_call(&cfg, "/api/v1/status", "{}").await?;
         }
-        Some(Commands::Peers) => {
-            cli_api_call(&cfg, "/api/v1/peers", "{}").await?;
+        Some(Commands::Peers { action }) => {
+            let (path, body) = match action {
+                Some(PeersAction::List) | None => ("/api/v1/peers", serde_json::json!({})),
+                Some(PeersAction::Connect { addr }) => {
+                    ("/api/v1/peers/connect", serde_json::json!({ "addr": addr }))
+                }
+                Some(PeersAction::Disconnect { peer_id }) => (
+                    "/api/v1/peers/disconnect",
+                    serde_json::json!({ "peer_id": peer_id }),
+                ),
+                Some(PeersAction::Ban {
+                    peer_id,
+                    reason,
+                    duration_secs,
+                }) => (
+                    "/api/v1/peers/ban",
+                    serde_json::json!({
+                        "peer_id": peer_id,
+                        "reason": reason,
+                        "duration_secs": duration_secs,
+                    }),
+                ),
+                Some(PeersAction::Unban { peer_id }) => (
+                    "/api/v1/peers/unban",
+                    serde_json::json!({ "peer_id": peer_id }),
+                ),
+                Some(PeersAction::Pin { peer_id }) => (
+                    "/api/v1/peers/pin",
+                    serde_json::json!({ "peer_id": peer_id, "pinned": true }),
+                ),
+                Some(PeersAction::Unpin { peer_id }) => (
+                    "/api/v1/peers/pin",
+                    serde_json::json!({ "peer_id": peer_id, "pinned": false }),
+                ),
+            };
+            cli_api_call(&cfg, path, &body.to_string()).await?;
         }
         Some(Commands::Groups) => {
             cli_api_call(&cfg, "/api/v1/groups/list", "{}").await?;
@@ -201,8 +268,8 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
     for group_id in &cfg.node.groups {
         let exists = storage.read_group(group_id).unwrap_or(None).is_some();
         if !exists {
-            let default_culture = r#"{"broadcast_eagerness":"chatty"}"#;
-            if let Err(e) = storage.write_group(group_id, group_id, default_culture, "{}") {
+            let default_culture = GroupCulture::default().to_canonical_json();
+            if let Err(e) = storage.write_group(group_id, group_id, &default_culture, "{}") {
                 tracing::warn!(group = group_id, error = %e, "failed to seed group from config");
             } else {
                 tracing::info!(group = group_id, "seeded group from config");
@@ -210,6 +277,12 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
         }
     }
 
+    // Rewrite loose (pre-schema) cultures in canonical form
+    let node_pubkey = hex::encode(identity.public_key());
+    if let Err(e) = cordelia_replication::culture::migrate_stored(storage.as_ref(), &node_pubkey) {
+        tracing::warn!(error = %e, "failed to migrate group cultures");
+    }
+
     // Determine our groups from storage
     let initial_groups: Vec<String> = storage
         .list_groups()
@@ -235,7 +308,13 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
 
     // Build libp2p swarm
     let listen_addr: libp2p::Multiaddr = parse_listen_addr(&cfg.network.listen_addr).await?;
-    let mut swarm = swarm_task::build_swarm(keypair, listen_addr)
+    let swarm_opts = swarm_task::SwarmOptions {
+        relay_server: our_role == config::NodeRole::Relay,
+        autonat_only_global: cfg.network.nat.autonat_only_global,
+        mdns: cfg.network.mdns.enabled,
+        dht: swarm_task::dht_mode(our_role),
+    };
+    let mut swarm = swarm_task::build_swarm(keypair, listen_addr, swarm_opts)
         .map_err(|e| anyhow::anyhow!("swarm build failed: {e}"))?;
 
     // Add external address so identify announces our public IP (critical for Docker/NAT)
@@ -296,34 +375,18 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
         peer_pool::PeerPool::new(shared_groups.clone())
     };
 
-    // Build API state
-    let pool_for_count = pool.clone();
-    let pool_for_list = pool.clone();
-    let state = Arc::new(AppState {
-        storage: Box::new(StorageClone(storage.clone())),
-        node_id: identity.node_id_hex(),
-        entity_id: cfg.node.entity_id.clone(),
-        bearer_token,
-        start_time: std::time::Instant::now(),
-        write_notify: Some(write_tx),
-        shared_groups: Some(shared_groups.clone()),
-        peer_count_fn: Some(Box::new(move || {
-            let pool = pool_for_count.clone();
-            Box::pin(async move { pool.peer_count_by_state().await })
-        })),
-        peer_list_fn: Some(Box::new(move || {
-            let pool = pool_for_list.clone();
-            Box::pin(async move { pool.peer_details().await })
-        })),
-        replication_stats: Some(repl_stats.clone()),
-        bootstrap_sync: Some(bootstrap_tx),
-    });
+    // Live change feed: API, swarm and replication publish; SSE subscribers read
+    let change_feed = Arc::new(cordelia_api::events::ChangeFeed::default());
 
     // Build governor with role-based targets and dial policy
     let effective_gov = cfg.effective_governor_targets();
     let governor_targets = GovernorTargets {
         hot_min: effective_gov.hot_min,
         hot_max: effective_gov.hot_max,
+        hot_per_group: effective_gov.hot_per_group,
+        max_per_subnet: effective_gov.max_per_subnet,
+        max_per_share_source: effective_gov.max_per_share_source,
+        protected_peers: effective_gov.protected_peers,
         warm_min: effective_gov.warm_min,
         warm_max: effective_gov.warm_max,
         cold_max: effective_gov.cold_max,
@@ -359,23 +422,104 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
         warm_max = governor_targets.warm_max,
         "governor targets (role-adjusted)"
     );
-    let governor = Arc::new(tokio::sync::Mutex::new(Governor::with_dial_policy(
+    // One clock for the governor and replication timers
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+    let mut governor = Governor::with_clock(
         governor_targets,
         shared_groups.read().await.clone(),
         dial_policy,
-    )));
+        clock.clone(),
+    );
+    governor.set_dial_lan_peers(cfg.network.mdns.enabled && cfg.network.mdns.dial_lan_peers);
+    let governor = Arc::new(tokio::sync::Mutex::new(governor));
+
+    // Create swarm command/event channels
+    let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel::<swarm_task::SwarmCommand>(256);
+    let (event_tx, _) = tokio::sync::broadcast::channel::<swarm_task::SwarmEvent2>(256);
+
+    // Build API state
+    let pool_for_count = pool.clone();
+    let pool_for_list = pool.clone();
+    let governor_for_counts = governor.clone();
+    let governor_for_coverage = governor.clone();
+    let governor_for_admin = governor.clone();
+    let pool_for_admin = pool.clone();
+    let cmd_tx_for_admin = cmd_tx.clone();
+    let protocol_metrics = Arc::new(cordelia_api::metrics::ProtocolMetrics::new());
+    // Hybrid logical clock: versions local writes, advanced by received ones
+    let hlc = Arc::new(HlcClock::new(
+        identity.node_id_hex(),
+        cfg.replication.max_clock_skew_secs,
+    ));
+    let state = Arc::new(AppState {
+        storage: Box::new(StorageClone(storage.clone())),
+        node_id: identity.node_id_hex(),
+        entity_id: cfg.node.entity_id.clone(),
+        bearer_token,
+        start_time: std::time::Instant::now(),
+        write_notify: Some(write_tx),
+        shared_groups: Some(shared_groups.clone()),
+        peer_count_fn: Some(Box::new(move || {
+            let pool = pool_for_count.clone();
+            Box::pin(async move { pool.peer_count_by_state().await })
+        })),
+        peer_list_fn: Some(Box::new(move || {
+            let pool = pool_for_list.clone();
+            Box::pin(async move { pool.peer_details().await })
+        })),
+        group_coverage_fn: Some(Box::new(move || {
+            let governor = governor_for_coverage.clone();
+            Box::pin(async move { governor_task::group_coverage(&*governor.lock().await) })
+        })),
+        peer_admin_fn: Some(Box::new(move |action| {
+            let governor = governor_for_admin.clone();
+            let pool = pool_for_admin.clone();
+            let cmd_tx = cmd_tx_for_admin.clone();
+            Box::pin(
+                async move { governor_task::peer_admin(&governor, &pool, &cmd_tx, action).await },
+            )
+        })),
+        replication_stats: Some(repl_stats.clone()),
+        bootstrap_sync: Some(bootstrap_tx),
+        change_feed: Some(change_feed.clone()),
+        governor_counts_fn: Some(Box::new(move || {
+            let governor = governor_for_counts.clone();
+            Box::pin(async move {
+                let gov = governor.lock().await;
+                let (hot, warm, cold, banned) = gov.counts();
+                let diversity = gov.diversity_stats();
+                cordelia_api::metrics::GovernorCounts {
+                    hot,
+                    warm,
+                    cold,
+                    banned,
+                    protected: gov.protected_peers().len(),
+                    subnet_rejections: diversity.subnet_rejections,
+                    share_source_rejections: diversity.share_source_rejections,
+                }
+            })
+        })),
+        protocol_metrics: Some(protocol_metrics.clone()),
+        device_revocations: tokio::sync::broadcast::channel(16).0,
+        signer: Some(cordelia_api::NodeSigner {
+            pubkey_hex: hex::encode(identity.public_key()),
+            sign: {
+                let identity = identity.clone();
+                Box::new(move |payload| identity.sign(payload))
+            },
+        }),
+        hlc: hlc.clone(),
+    });
 
     // Build replication engine
     let repl_config = ReplicationConfig {
         sync_interval_taciturn_secs: cfg.replication.sync_interval_taciturn_secs,
         tombstone_retention_days: cfg.replication.tombstone_retention_days,
         max_batch_size: cfg.replication.max_batch_size,
+        max_clock_skew_secs: cfg.replication.max_clock_skew_secs,
     };
-    let repl_engine = ReplicationEngine::new(repl_config, cfg.node.entity_id.clone());
-
-    // Create swarm command/event channels
-    let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel::<swarm_task::SwarmCommand>(256);
-    let (event_tx, _) = tokio::sync::broadcast::channel::<swarm_task::SwarmEvent2>(256);
+    let repl_engine = ReplicationEngine::new(repl_config.clone(), cfg.node.entity_id.clone())
+        .with_clock(hlc.clone());
 
     // Spawn swarm task
     let swarm_handle = {
@@ -388,6 +532,15 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
         let relay_blocked = relay_blocked.clone();
         let node_identity = identity.clone();
         let entity_id = cfg.node.entity_id.clone();
+        let change_feed = change_feed.clone();
+        let protocol_metrics = protocol_metrics.clone();
+        let hlc = hlc.clone();
+        // Personal nodes sit behind NAT: hold circuits with relays
+        let relay_reservations = if our_role == config::NodeRole::Personal {
+            cfg.network.nat.relay_reservations
+        } else {
+            0
+        };
         tokio::spawn(async move {
             swarm_task::run_swarm_loop(
                 swarm,
@@ -402,6 +555,11 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
                 relay_blocked,
                 node_identity,
                 entity_id,
+                change_feed,
+                protocol_metrics,
+                repl_config,
+                hlc,
+                relay_reservations,
                 shutdown,
             )
             .await;
@@ -418,6 +576,7 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
         let shared_groups = shared_groups.clone();
         let shutdown = shutdown_tx.subscribe();
         let relay_learned = relay_learned_groups.clone();
+        let storage = storage.clone();
         tokio::spawn(async move {
             governor_task::run_governor_loop(
                 governor,
@@ -428,6 +587,7 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
                 shared_groups,
                 relay_learned,
                 our_peer_id,
+                storage,
                 shutdown,
             )
             .await;
@@ -445,6 +605,8 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
         let is_relay = our_role == config::NodeRole::Relay;
         let relay_learned = relay_learned_groups.clone();
         let relay_blocked = relay_blocked.clone();
+        let change_feed = change_feed.clone();
+        let clock = clock.clone();
         tokio::spawn(async move {
             replication_task::run_replication_loop(
                 repl_engine,
@@ -459,11 +621,36 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
                 relay_learned,
                 relay_blocked,
                 bootstrap_rx,
+                change_feed,
+                clock,
             )
             .await;
         })
     };
 
+    // Optional standalone metrics listener (for Prometheus scrapers)
+    let metrics_handle = if let Some(addr) = cfg.metrics.listen_addr.as_deref() {
+        let router = cordelia_api::metrics_router(state.clone(), cfg.metrics.require_token);
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        tracing::info!(
+            addr,
+            require_token = cfg.metrics.require_token,
+            "metrics listening (HTTP)"
+        );
+        let shutdown = shutdown_tx.subscribe();
+        Some(tokio::spawn(async move {
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    let mut shutdown = shutdown;
+                    let _ = shutdown.recv().await;
+                })
+                .await
+                .ok();
+        }))
+    } else {
+        None
+    };
+
     // Start API server
     let router = cordelia_api::router(state);
 
@@ -551,6 +738,9 @@ async fn run_node(cfg: config::NodeConfig) -> anyhow::Result<()> {
 
     // Wait for all tasks
     let _ = tokio::join!(swarm_handle, governor_handle, repl_handle, api_handle);
+    if let Some(handle) = metrics_handle {
+        let _ = handle.await;
+    }
 
     tracing::info!("shutdown complete");
     Ok(())
@@ -3,11 +3,13 @@
 //! Maps NodeId → PeerHandle (metadata only, no connection handles).
 //! The Swarm task owns all connections; this pool tracks peer state.
 
-use cordelia_governor::{GovernorActions, PeerState};
+use chrono::{DateTime, Utc};
+use cordelia_governor::{GovernorActions, PeerContribution, PeerState};
 use cordelia_protocol::{GroupId, NodeId};
 use libp2p::Multiaddr;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
 
 type SharedGroups = Arc<RwLock<Vec<GroupId>>>;
@@ -27,8 +29,13 @@ pub struct PeerHandle {
     pub protocol_version: u16,
     /// Whether this peer advertises itself as a relay.
     pub is_relay: bool,
-    /// Cumulative items delivered to this peer via push/retry.
+    /// Cumulative items delivered to this peer via push/retry (not scored).
     pub items_delivered: u64,
+    /// What this peer has delivered to us since it connected.
+    pub contribution: PeerContribution,
+    pub connected_at: Instant,
+    /// Estimated clock offset (peer minus us, ms) from GroupExchange timestamps.
+    pub clock_offset_ms: Option<i64>,
 }
 
 /// Thread-safe pool of active peer connections.
@@ -107,6 +114,9 @@ impl PeerPool {
             protocol_version,
             is_relay,
             items_delivered: 0,
+            contribution: PeerContribution::default(),
+            connected_at: Instant::now(),
+            clock_offset_ms: None,
         };
 
         let pool_size = {
@@ -293,6 +303,24 @@ impl PeerPool {
         }
     }
 
+    /// Add to what a peer has delivered to us (items we stored or
+    /// rejected, requests it answered or failed).
+    pub async fn record_contribution(&self, node_id: &NodeId, delta: PeerContribution) {
+        if let Some(handle) = self.inner.write().await.get_mut(node_id) {
+            handle.contribution += delta;
+        }
+    }
+
+    /// Contribution of every connected peer (synced into the governor).
+    pub async fn contributions(&self) -> Vec<(NodeId, PeerContribution)> {
+        self.inner
+            .read()
+            .await
+            .values()
+            .map(|h| (h.node_id, h.contribution))
+            .collect()
+    }
+
     /// Update a peer's RTT (from ping events).
     pub async fn update_rtt(&self, node_id: &NodeId, rtt_ms: f64) {
         if let Some(handle) = self.inner.write().await.get_mut(node_id) {
@@ -300,6 +328,40 @@ impl PeerPool {
         }
     }
 
+    /// Record a clock sample: the peer's clock read `remote` when it sent a
+    /// message we received at `received`. Half the ping RTT is credited for
+    /// transit. Returns the estimated offset (peer minus us) in milliseconds.
+    pub async fn record_clock_sample(
+        &self,
+        node_id: &NodeId,
+        remote: DateTime<Utc>,
+        received: DateTime<Utc>,
+    ) -> Option<i64> {
+        let mut pool = self.inner.write().await;
+        let handle = pool.get_mut(node_id)?;
+        let transit_ms = handle.rtt_ms.map_or(0, |rtt| (rtt / 2.0) as i64);
+        let offset = remote.signed_duration_since(received).num_milliseconds() + transit_ms;
+        handle.clock_offset_ms = Some(offset);
+        Some(offset)
+    }
+
+    /// Median clock offset across peers with a sample (ms), with the number
+    /// of samples. If most peers agree we are off, our clock is the suspect.
+    pub async fn median_clock_offset(&self) -> Option<(i64, usize)> {
+        let mut offsets: Vec<i64> = self
+            .inner
+            .read()
+            .await
+            .values()
+            .filter_map(|h| h.clock_offset_ms)
+            .collect();
+        if offsets.is_empty() {
+            return None;
+        }
+        offsets.sort_unstable();
+        Some((offsets[offsets.len() / 2], offsets.len()))
+    }
+
     #[allow(dead_code)]
     /// Set a peer's relay flag.
     pub async fn set_relay(&self, node_id: &NodeId, is_relay: bool) {
@@ -384,10 +446,19 @@ impl PeerPool {
                 state: h.state.name().to_string(),
                 rtt_ms: h.rtt_ms,
                 items_delivered: h.items_delivered,
+                score: cordelia_api::PeerScore {
+                    score: h.contribution.score(h.connected_at.elapsed(), h.rtt_ms),
+                    items_stored: h.contribution.items_stored,
+                    first_deliveries: h.contribution.first_deliveries,
+                    items_rejected: h.contribution.items_rejected,
+                    requests_served: h.contribution.requests_served,
+                    requests_failed: h.contribution.requests_failed,
+                },
                 groups: h.groups.clone(),
                 group_intersection: h.group_intersection.clone(),
                 is_relay: h.is_relay,
                 protocol_version: h.protocol_version,
+                clock_offset_ms: h.clock_offset_ms,
             })
             .collect()
     }
@@ -475,4 +546,91 @@ mod tests {
         let handle = pool.get(&peer).await.unwrap();
         assert_eq!(handle.group_intersection, vec!["g-new".to_string()]);
     }
+
+    #[tokio::test]
+    async fn test_clock_offset_samples() {
+        let pool = PeerPool::new(Arc::new(RwLock::new(vec![])));
+        let now = chrono::Utc::now();
+        let secs = |n: i64| chrono::Duration::try_seconds(n).unwrap();
+
+        // Unknown peers are ignored
+        assert_eq!(
+            pool.record_clock_sample(&test_peer_id(), now, now).await,
+            None
+        );
+        assert_eq!(pool.median_clock_offset().await, None);
+
+        let mut peers = Vec::new();
+        for ahead in [3600, 3601, 2] {
+            let peer = test_peer_id();
+            pool.insert(peer, vec![], vec![], PeerState::Hot, 1, false)
+                .await;
+            peers.push(peer);
+            pool.record_clock_sample(&peer, now + secs(ahead), now)
+                .await
+                .unwrap();
+        }
+        // Half the RTT is credited for transit
+        pool.update_rtt(&peers[2], 400.0).await;
+        assert_eq!(
+            pool.record_clock_sample(&peers[2], now + secs(2), now)
+                .await,
+            Some(2200)
+        );
+        let details = pool.peer_details().await;
+        assert!(details.iter().all(|d| d.clock_offset_ms.is_some()));
+
+        // Two of three peers an hour ahead: we are probably an hour behind
+        assert_eq!(pool.median_clock_offset().await, Some((3_600_000, 3)));
+    }
+
+    #[tokio::test]
+    async fn test_contribution_scoring() {
+        let pool = PeerPool::new(Arc::new(RwLock::new(vec![])));
+        let (pushed_to, contributor) = (test_peer_id(), test_peer_id());
+        for peer in [pushed_to, contributor] {
+            pool.insert(peer, vec![], vec![], PeerState::Hot, 1, false)
+                .await;
+        }
+
+        // Pushing to a peer is not a contribution from it
+        pool.record_items_delivered(&pushed_to, 100).await;
+        pool.record_contribution(
+            &contributor,
+            PeerContribution {
+                items_stored: 3,
+                first_deliveries: 2,
+                requests_served: 1,
+                ..PeerContribution::default()
+            },
+        )
+        .await;
+        pool.record_contribution(
+            &contributor,
+            PeerContribution {
+                items_stored: 1,
+                items_rejected: 1,
+                ..PeerContribution::default()
+            },
+        )
+        .await;
+
+        let contributions: HashMap<_, _> = pool.contributions().await.into_iter().collect();
+        assert_eq!(contributions[&pushed_to], PeerContribution::default());
+        assert_eq!(contributions[&contributor].items_stored, 4);
+        assert_eq!(contributions[&contributor].items_rejected, 1);
+
+        let details = pool.peer_details().await;
+        let detail = |id: NodeId| {
+            details
+                .iter()
+                .find(|d| d.node_id == id.to_base58())
+                .unwrap()
+        };
+        assert_eq!(detail(pushed_to).items_delivered, 100);
+        assert_eq!(detail(pushed_to).score.score, 0.0);
+        assert_eq!(detail(contributor).score.first_deliveries, 2);
+        assert_eq!(detail(contributor).score.requests_served, 1);
+        assert!(detail(contributor).score.score > 0.0);
+    }
 }
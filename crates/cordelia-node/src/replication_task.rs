@@ -8,14 +8,17 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
 
+use cordelia_api::events::{Change, ChangeFeed};
 use cordelia_api::{ReplicationStats, WriteNotification};
+use cordelia_governor::{Clock, PeerContribution};
 use cordelia_protocol::era::CURRENT_ERA;
 use cordelia_protocol::messages::{FetchRequest, FetchedItem, MemoryPushRequest, SyncRequest};
-use cordelia_replication::{GroupCulture, ReceiveOutcome, ReplicationEngine};
+use cordelia_replication::hlc::Hlc;
+use cordelia_replication::{GroupCulture, Posture, ReceiveOutcome, ReplicationEngine};
 use cordelia_storage::Storage;
 use tokio::sync::{broadcast, mpsc, RwLock};
-use tokio::time::Instant;
 
 use crate::peer_pool::PeerPool;
 use crate::swarm_task::SwarmCommand;
@@ -35,6 +38,8 @@ struct PendingPush {
 ///   Used to expand anti-entropy group list beyond our own memberships.
 /// `relay_blocked_groups`: deny-list applied on top of any posture.  Groups in this
 ///   set are excluded from anti-entropy sync and the relay acceptance predicate.
+/// `clock`: time source for push-retry and per-group sync deadlines (shared with
+///   the governor, so tests can advance both together).
 #[allow(clippy::too_many_arguments)]
 pub async fn run_replication_loop(
     engine: ReplicationEngine,
@@ -49,6 +54,8 @@ pub async fn run_replication_loop(
     relay_learned_groups: Option<Arc<RwLock<HashSet<String>>>>,
     relay_blocked_groups: Arc<HashSet<String>>,
     mut bootstrap_rx: mpsc::Receiver<String>,
+    change_feed: Arc<ChangeFeed>,
+    clock: Arc<dyn Clock>,
 ) {
     // Base tick for per-culture sync scheduling (fastest culture interval = 60s for chatty)
     let mut sync_base_tick = tokio::time::interval(std::time::Duration::from_secs(
@@ -76,8 +83,9 @@ pub async fn run_replication_loop(
     flush_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
     flush_tick.tick().await;
 
-    // Group tombstone GC: purge deleted groups past retention (daily check)
-    let mut gc_tick = tokio::time::interval(std::time::Duration::from_secs(24 * 3600));
+    // GC: expired items (hourly) and deleted groups past retention
+    const GC_INTERVAL_SECS: u64 = 3600;
+    let mut gc_tick = tokio::time::interval(std::time::Duration::from_secs(GC_INTERVAL_SECS));
     gc_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
     gc_tick.tick().await;
 
@@ -97,13 +105,23 @@ pub async fn run_replication_loop(
                 match write_result {
                     Ok(notif) => {
                         if let Some(group_id) = &notif.group_id {
+                            let posture = Posture::of(storage.as_ref(), group_id, engine.entity_id());
+                            if !posture.pushes() {
+                                tracing::debug!(
+                                    item_id = &notif.item_id,
+                                    group = group_id.as_str(),
+                                    posture = posture.as_str(),
+                                    "repl: posture suppresses eager push"
+                                );
+                                continue;
+                            }
                             let culture = load_group_culture(&storage, group_id)
                                 .unwrap_or_default();
 
                             tracing::debug!(
                                 item_id = &notif.item_id,
                                 group = group_id.as_str(),
-                                eagerness = culture.broadcast_eagerness.as_str(),
+                                eagerness = ?culture.broadcast_eagerness,
                                 is_copy = notif.is_copy,
                                 parent_id = notif.parent_id.as_deref().unwrap_or("-"),
                                 "repl: local write received"
@@ -118,10 +136,19 @@ pub async fn run_replication_loop(
                                 notif.key_version,
                                 notif.parent_id.clone(),
                                 notif.is_copy,
+                                notif.expires_at.clone(),
                             );
 
                             match action {
-                                cordelia_replication::engine::OutboundAction::BroadcastItem { group_id, item } => {
+                                cordelia_replication::engine::OutboundAction::BroadcastItem { group_id, mut item } => {
+                                    item.author_pubkey = notif.author_pubkey.clone();
+                                    item.author_signature = notif.author_signature.clone();
+                                    item.ancestry = notif.ancestry.clone();
+                                    // Push the version the write was stored with
+                                    if let Some(version) = notif.hlc.as_deref().and_then(Hlc::parse) {
+                                        item.updated_at = cordelia_replication::format_timestamp(version.wall());
+                                        item.hlc = notif.hlc.clone();
+                                    }
                                     tracing::debug!(
                                         item_id = item.item_id,
                                         group = group_id.as_str(),
@@ -173,7 +200,7 @@ pub async fn run_replication_loop(
                             items,
                             group_id,
                             attempt: 0,
-                            next_at: Instant::now()
+                            next_at: clock.now()
                                 + std::time::Duration::from_secs(CURRENT_ERA.push_retry_backoffs[0]),
                         });
                         continue;
@@ -223,7 +250,7 @@ pub async fn run_replication_loop(
                         items,
                         group_id,
                         attempt: 0,
-                        next_at: Instant::now()
+                        next_at: clock.now()
                             + std::time::Duration::from_secs(CURRENT_ERA.push_retry_backoffs[0]),
                     });
                 }
@@ -234,13 +261,22 @@ pub async fn run_replication_loop(
                 if pending_pushes.is_empty() {
                     continue;
                 }
-                let now = Instant::now();
+                let now = clock.now();
                 let mut keep = Vec::new();
                 for mut pending in pending_pushes.drain(..) {
                     if now < pending.next_at {
                         keep.push(pending);
                         continue;
                     }
+                    // Posture may have changed since the write (silent/emcon drop queued pushes)
+                    if !Posture::of(storage.as_ref(), &pending.group_id, engine.entity_id()).pushes() {
+                        tracing::debug!(
+                            group = pending.group_id.as_str(),
+                            items = pending.items.len(),
+                            "repl: posture suppresses push retry"
+                        );
+                        continue;
+                    }
                     let peers = pool.active_peers_for_group_or_relays(&pending.group_id).await;
                     if !peers.is_empty() {
                         tracing::info!(
@@ -299,7 +335,7 @@ pub async fn run_replication_loop(
 
             // Per-culture anti-entropy sync (base tick fires at fastest interval)
             _ = sync_base_tick.tick() => {
-                let now = Instant::now();
+                let now = clock.now();
                 let mut current_groups = shared_groups.read().await.clone();
 
                 // Relays: also sync groups we've learned from peers and stored locally
@@ -332,6 +368,16 @@ pub async fn run_replication_loop(
                     if deadline.is_some_and(|d| now < d) {
                         continue; // not yet due
                     }
+                    // A SyncRequest names the group on the wire
+                    let posture = Posture::of(storage.as_ref(), group_id, engine.entity_id());
+                    if !posture.pulls() {
+                        tracing::trace!(
+                            group = group_id.as_str(),
+                            posture = posture.as_str(),
+                            "repl: posture suppresses anti-entropy sync"
+                        );
+                        continue;
+                    }
 
                     // Compute culture-specific interval for next deadline
                     let culture = load_group_culture(&storage, group_id)
@@ -376,6 +422,7 @@ pub async fn run_replication_loop(
                         &stats,
                         is_relay,
                         relay_accept_set.as_ref(),
+                        &change_feed,
                     ).await {
                         Ok(latest_ts) => {
                             stats.sync_rounds.fetch_add(1, Ordering::Relaxed);
@@ -397,6 +444,15 @@ pub async fn run_replication_loop(
 
             // Fast bootstrap: immediate full sync for a newly-added group
             Some(group_id) = bootstrap_rx.recv() => {
+                let posture = Posture::of(storage.as_ref(), &group_id, engine.entity_id());
+                if !posture.pulls() {
+                    tracing::debug!(
+                        group = group_id.as_str(),
+                        posture = posture.as_str(),
+                        "repl: posture suppresses bootstrap sync"
+                    );
+                    continue;
+                }
                 tracing::info!(group = group_id.as_str(), "repl: bootstrap sync triggered");
 
                 // Reset tracking state so this is a full sync from scratch
@@ -422,6 +478,7 @@ pub async fn run_replication_loop(
                     &stats,
                     is_relay,
                     relay_accept_set.as_ref(),
+                    &change_feed,
                 ).await {
                     Ok(latest_ts) => {
                         stats.sync_rounds.fetch_add(1, Ordering::Relaxed);
@@ -441,8 +498,33 @@ pub async fn run_replication_loop(
                 }
             }
 
-            // Group tombstone garbage collection (daily)
+            // Expired item and group tombstone garbage collection
             _ = gc_tick.tick() => {
+                let mut expired = match storage.purge_expired_items() {
+                    Ok(n) => n as u64,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "repl: item expiry GC failed");
+                        0
+                    }
+                };
+                // ttl_default fallback for items written without an explicit expiry
+                for group in storage.list_groups().unwrap_or_default() {
+                    if group.culture == cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE {
+                        continue;
+                    }
+                    let Some(ttl) = GroupCulture::load(&group.culture).ttl_default else {
+                        continue;
+                    };
+                    match storage.purge_group_items_older_than(&group.id, ttl) {
+                        Ok(n) => expired += n as u64,
+                        Err(e) => tracing::warn!(group = group.id, error = %e, "repl: ttl_default GC failed"),
+                    }
+                }
+                if expired > 0 {
+                    stats.items_expired.fetch_add(expired, Ordering::Relaxed);
+                    tracing::info!(purged = expired, "repl: purged expired items");
+                }
+
                 let retention = engine.tombstone_retention_days();
                 match storage.purge_deleted_groups(
                     cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE,
@@ -508,6 +590,7 @@ async fn run_anti_entropy(
     stats: &Arc<ReplicationStats>,
     is_relay: bool,
     relay_accept_set: Option<&HashSet<String>>,
+    change_feed: &ChangeFeed,
 ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
     let peer = match if is_relay {
         pool.random_hot_peer_for_group_or_relays(group_id).await
@@ -516,7 +599,11 @@ async fn run_anti_entropy(
     } {
         Some(p) => p,
         None => {
-            tracing::debug!(group = group_id, "repl: no peers available");
+            // Governor coverage (hot_per_group) should prevent this
+            tracing::info!(
+                group = group_id,
+                "repl: no peer carries group, sync skipped"
+            );
             return Ok(None);
         }
     };
@@ -536,10 +623,9 @@ async fn run_anti_entropy(
         .await
         .map_err(|e| format!("send sync request failed: {e}"))?;
 
-    let remote_headers = resp_rx
-        .await
-        .map_err(|_| "sync response channel closed")?
-        .map_err(|e| format!("sync request failed: {e}"))?;
+    let remote_headers = resp_rx.await.map_err(|_| "sync response channel closed")?;
+    record_request(pool, &peer.node_id, remote_headers.is_ok()).await;
+    let remote_headers = remote_headers.map_err(|e| format!("sync request failed: {e}"))?;
 
     // Track latest remote timestamp for incremental sync
     let latest_ts = remote_headers
@@ -563,10 +649,25 @@ async fn run_anti_entropy(
             updated_at: h.updated_at,
             author_id: h.author_id,
             is_deletion: h.is_deletion,
+            expires_at: h.expires_at,
         })
         .collect();
 
-    let needed_ids = cordelia_replication::diff_headers(&local_proto, &remote_headers.items);
+    // Never fetch items that are already past expiry (explicit or ttl_default)
+    let ttl_default = storage
+        .read_group(group_id)
+        .ok()
+        .flatten()
+        .and_then(|g| GroupCulture::load(&g.culture).ttl_default);
+    let now = chrono::Utc::now();
+    let live_remote: Vec<cordelia_protocol::messages::ItemHeader> = remote_headers
+        .items
+        .iter()
+        .filter(|h| !cordelia_replication::header_expired(h, ttl_default, now))
+        .cloned()
+        .collect();
+
+    let needed_ids = cordelia_replication::diff_headers(&local_proto, &live_remote);
 
     if needed_ids.is_empty() {
         tracing::debug!(
@@ -596,6 +697,7 @@ async fn run_anti_entropy(
     let mut stored = 0u64;
     let mut rejected = 0u64;
     let mut duplicate = 0u64;
+    let mut contribution = PeerContribution::default();
 
     for chunk in needed_ids.chunks(engine.max_batch_size() as usize) {
         let (resp_tx, resp_rx) = tokio::sync::oneshot::channel();
@@ -610,21 +712,33 @@ async fn run_anti_entropy(
             .await
             .map_err(|e| format!("send fetch request failed: {e}"))?;
 
-        let fetch_resp = resp_rx
-            .await
-            .map_err(|_| "fetch response channel closed")?
-            .map_err(|e| format!("fetch request failed: {e}"))?;
+        let fetch_resp = resp_rx.await.map_err(|_| "fetch response channel closed")?;
+        record_request(pool, &peer.node_id, fetch_resp.is_ok()).await;
+        let fetch_resp = fetch_resp.map_err(|e| format!("fetch request failed: {e}"))?;
 
         for item in &fetch_resp.items {
             let relay_fn =
                 relay_accept_set.map(|set| move |gid: &str| -> bool { set.contains(gid) });
             let relay_ref: Option<&dyn Fn(&str) -> bool> =
                 relay_fn.as_ref().map(|f| f as &dyn Fn(&str) -> bool);
+            let known = storage
+                .read_l2_item_meta(&item.item_id)
+                .ok()
+                .flatten()
+                .is_some();
             let outcome = engine.on_receive(storage.as_ref(), item, our_groups, relay_ref);
             match &outcome {
                 ReceiveOutcome::Stored => {
                     stored += 1;
+                    if !known {
+                        contribution.first_deliveries += 1;
+                    }
                     tracing::debug!(item_id = &item.item_id, group = group_id, "repl: stored");
+                    change_feed.publish(storage.as_ref(), Change::replicated(item));
+                }
+                ReceiveOutcome::Conflict => {
+                    stored += 1;
+                    change_feed.publish(storage.as_ref(), Change::conflict(item));
                 }
                 ReceiveOutcome::Duplicate => {
                     duplicate += 1;
@@ -642,6 +756,10 @@ async fn run_anti_entropy(
         }
     }
 
+    contribution.items_stored = stored;
+    contribution.items_rejected = rejected;
+    pool.record_contribution(&peer.node_id, contribution).await;
+
     stats.items_synced.fetch_add(stored, Ordering::Relaxed);
     stats.items_rejected.fetch_add(rejected, Ordering::Relaxed);
     stats
@@ -660,6 +778,23 @@ async fn run_anti_entropy(
     Ok(latest_ts)
 }
 
+/// Credit (or penalise) a peer for answering one of our sync or fetch
+/// requests.
+async fn record_request(pool: &PeerPool, node_id: &cordelia_protocol::NodeId, served: bool) {
+    let contribution = if served {
+        PeerContribution {
+            requests_served: 1,
+            ..PeerContribution::default()
+        }
+    } else {
+        PeerContribution {
+            requests_failed: 1,
+            ..PeerContribution::default()
+        }
+    };
+    pool.record_contribution(node_id, contribution).await;
+}
+
 /// Load group culture from storage.
 fn load_group_culture(storage: &Arc<dyn Storage>, group_id: &str) -> Option<GroupCulture> {
     let group = match storage.read_group(group_id) {
@@ -676,18 +811,5 @@ fn load_group_culture(storage: &Arc<dyn Storage>, group_id: &str) -> Option<Grou
             return None;
         }
     };
-    match serde_json::from_str::<GroupCulture>(&group.culture) {
-        Ok(culture) => Some(culture),
-        Err(_) => {
-            tracing::debug!(
-                group = group_id,
-                raw_culture = group.culture,
-                "repl: culture JSON parse failed, treating as bare eagerness string"
-            );
-            Some(GroupCulture {
-                broadcast_eagerness: group.culture.clone(),
-                ..Default::default()
-            })
-        }
-    }
+    Some(GroupCulture::load(&group.culture))
 }
@@ -3,25 +3,45 @@
 //! All network I/O flows through this task. Governor and replication tasks
 //! communicate via SwarmCommand/SwarmEvent channels.
 
+use cordelia_api::events::{Change, ChangeFeed, ChangeKind};
+use cordelia_api::metrics::ProtocolMetrics;
 use cordelia_crypto::identity::NodeIdentity;
+use cordelia_crypto::verify_ed25519_hex;
+use cordelia_governor::{is_circuit_addr, PeerContribution};
 use cordelia_protocol::messages::*;
-use cordelia_replication::{ReceiveOutcome, ReplicationEngine};
-use cordelia_storage::Storage;
+use cordelia_replication::hlc::{Hlc, HlcClock};
+use cordelia_replication::{
+    membership, Eagerness, GroupCulture, Posture, ReceiveOutcome, ReplicationEngine, SecurityPolicy,
+};
+use cordelia_storage::{GroupAuthority, GroupRow, Storage};
+use libp2p::core::transport::ListenerId;
+use libp2p::core::ConnectedPoint;
 use libp2p::futures::StreamExt;
+use libp2p::multiaddr::Protocol;
 use libp2p::request_response::{self, ProtocolSupport};
-use libp2p::swarm::SwarmEvent;
-use libp2p::{identity, Multiaddr, PeerId, StreamProtocol, Swarm};
+use libp2p::swarm::behaviour::toggle::Toggle;
+use libp2p::swarm::dial_opts::{DialOpts, PeerCondition};
+use libp2p::swarm::{DialError, SwarmEvent};
+use libp2p::{
+    autonat, dcutr, identity, kad, mdns, relay, Multiaddr, PeerId, StreamProtocol, Swarm,
+};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc, oneshot, RwLock};
 
+/// Addresses of one peer dialled concurrently.
+const DIAL_CONCURRENCY: std::num::NonZeroU8 = std::num::NonZeroU8::new(4).unwrap();
+
 // ============================================================================
 // Group descriptor helpers (R4-030)
 // ============================================================================
 
-/// Build GroupDescriptors from local storage for all our groups.
-/// Lazy-signs owned groups that don't have a signature yet.
+/// Build GroupDescriptors from local storage for all our groups, except
+/// those we are in under `emcon` posture or whose security policy keeps
+/// them out of GroupExchange. Signs descriptors we have
+/// authority over (as owner, or as a listed admin) whose stored signature
+/// is missing or no longer matches their content.
 fn build_descriptors(
     storage: &dyn Storage,
     node_identity: &NodeIdentity,
@@ -32,32 +52,70 @@ fn build_descriptors(
             let pubkey_hex = hex::encode(node_identity.public_key());
             let descs: Vec<GroupDescriptor> = groups
                 .into_iter()
+                .filter(|g| advertised(storage, &g.id, our_entity_id))
                 .map(|g| {
                     let checksum = GroupDescriptor::compute_checksum(&g.id, &g.culture);
+                    let transfers = membership::parse_transfers(g.owner_transfers.as_deref());
                     let mut desc = GroupDescriptor {
                         id: g.id.clone(),
-                        culture: g.culture,
-                        updated_at: g.updated_at,
+                        culture: g.culture.clone(),
+                        updated_at: g.updated_at.clone(),
                         checksum,
                         owner_id: g.owner_id.clone(),
                         owner_pubkey: g.owner_pubkey.clone(),
                         signature: g.signature.clone(),
+                        admins: (!g.admins.is_empty()).then(|| g.admins.clone()),
+                        signer_pubkey: g.signer_pubkey.clone(),
+                        transfers: (!transfers.is_empty()).then_some(transfers),
+                        security_policy: SecurityPolicy::for_descriptor(&g.security_policy),
+                        hlc: g.hlc.clone(),
                     };
 
-                    // Lazy-sign: if we own this group and it's unsigned, sign it now
-                    let we_own_it = g.owner_id.as_deref() == Some(our_entity_id)
-                        || (g.owner_id.is_none() && is_owner(storage, &g.id, our_entity_id));
+                    let we_own_it = (g.owner_id.as_deref() == Some(our_entity_id)
+                        || (g.owner_id.is_none() && is_owner(storage, &g.id, our_entity_id)))
+                        && g.owner_pubkey.as_deref().is_none_or(|k| k == pubkey_hex);
+                    let we_admin = g.owner_id.is_some()
+                        && g.owner_pubkey.is_some()
+                        && g.admins.contains(&pubkey_hex);
+                    let signature_valid = match (&desc.signature, desc.signing_key()) {
+                        (Some(sig), Some(key)) => verify_descriptor_signature(&desc, key, sig),
+                        _ => false,
+                    };
+                    // The owner also replaces a handover signature by the previous owner
+                    let foreign_signer = desc
+                        .signer_pubkey
+                        .as_ref()
+                        .is_some_and(|k| !g.admins.contains(k));
+                    let needs_signing = if we_own_it {
+                        !signature_valid || foreign_signer
+                    } else {
+                        // Only the owner may sign a deletion
+                        we_admin && !signature_valid && !desc.is_tombstone()
+                    };
 
-                    if we_own_it && g.signature.is_none() {
+                    if needs_signing {
+                        if we_own_it {
+                            desc.owner_id = Some(our_entity_id.to_string());
+                            desc.owner_pubkey = Some(pubkey_hex.clone());
+                            desc.signer_pubkey = None;
+                        } else {
+                            desc.signer_pubkey = Some(pubkey_hex.clone());
+                        }
                         let sig = hex::encode(node_identity.sign(&desc.signing_payload()));
-                        desc.owner_id = Some(our_entity_id.to_string());
-                        desc.owner_pubkey = Some(pubkey_hex.clone());
                         desc.signature = Some(sig.clone());
 
                         // Persist so we don't re-sign every exchange
-                        if let Err(e) =
-                            storage.write_group_signature(&g.id, our_entity_id, &pubkey_hex, &sig)
-                        {
+                        let authority = GroupAuthority {
+                            updated_at: g.updated_at.clone(),
+                            owner_id: desc.owner_id.clone().unwrap_or_default(),
+                            owner_pubkey: desc.owner_pubkey.clone().unwrap_or_default(),
+                            signature: sig,
+                            signer_pubkey: desc.signer_pubkey.clone(),
+                            admins: g.admins.clone(),
+                            owner_transfers: g.owner_transfers.clone(),
+                            hlc: g.hlc.clone(),
+                        };
+                        if let Err(e) = storage.write_group_authority(&g.id, &authority) {
                             tracing::warn!(
                                 group_id = %g.id,
                                 "failed to persist group signature: {e}"
@@ -65,7 +123,8 @@ fn build_descriptors(
                         } else {
                             tracing::info!(
                                 group_id = %g.id,
-                                "net: lazy-signed group descriptor"
+                                as_admin = !we_own_it,
+                                "net: signed group descriptor"
                             );
                         }
                     }
@@ -83,6 +142,95 @@ fn build_descriptors(
     }
 }
 
+/// Whether a group may appear in our GroupExchange messages: not under our
+/// `emcon` posture, not hidden by its security policy, and not one we left
+/// as a member.
+fn advertised(storage: &dyn Storage, group_id: &str, our_entity_id: &str) -> bool {
+    Posture::of(storage, group_id, our_entity_id).advertises()
+        && SecurityPolicy::of(storage, group_id).exchange_visible
+        && !cordelia_replication::departure::left(storage, group_id)
+}
+
+/// Most membership log entries sent in one GroupExchange message; the rest
+/// follow in later exchanges.
+const MAX_MEMBERSHIP_PER_EXCHANGE: usize = 256;
+
+/// Membership log entries for the given (advertised) descriptors' groups
+/// that the peer lacks according to the heads it last reported (whole logs
+/// for groups it has not reported), oldest first, capped at
+/// `MAX_MEMBERSHIP_PER_EXCHANGE`. Also returns our own heads.
+fn build_membership(
+    storage: &dyn Storage,
+    descriptors: Option<&[GroupDescriptor]>,
+    peer_heads: Option<&HashMap<String, MembershipHead>>,
+) -> (Option<Vec<MembershipEntry>>, Option<Vec<MembershipHead>>) {
+    let mut entries = Vec::new();
+    let mut heads = Vec::new();
+    for desc in descriptors.unwrap_or_default() {
+        let log = match storage.list_membership_log(&desc.id) {
+            Ok(log) => log,
+            Err(e) => {
+                tracing::warn!(group_id = %desc.id, "failed to list membership log: {e}");
+                continue;
+            }
+        };
+        let Some(last) = log.last() else { continue };
+        heads.push(MembershipHead {
+            group_id: desc.id.clone(),
+            entries: log.len() as u32,
+            latest: last.created_at.clone(),
+        });
+        let missing = match peer_heads.and_then(|h| h.get(&desc.id)) {
+            Some(head) => missing_entries(&log, head),
+            None => &log[..],
+        };
+        let room = MAX_MEMBERSHIP_PER_EXCHANGE - entries.len();
+        entries.extend(missing.iter().take(room).map(membership::to_entry));
+    }
+    (
+        (!entries.is_empty()).then_some(entries),
+        (!heads.is_empty()).then_some(heads),
+    )
+}
+
+/// The part of `log` (replay order) a peer with `head` lacks: entries after
+/// its latest, or the whole log if it holds fewer entries up to that point
+/// than we do.
+fn missing_entries<'a>(
+    log: &'a [cordelia_storage::MembershipLogRow],
+    head: &MembershipHead,
+) -> &'a [cordelia_storage::MembershipLogRow] {
+    let known = log.partition_point(|e| e.created_at <= head.latest);
+    if known > head.entries as usize {
+        log
+    } else {
+        &log[known..]
+    }
+}
+
+/// Membership log heads each connected peer last reported.
+#[derive(Default)]
+struct PeerMembershipHeads(HashMap<PeerId, HashMap<String, MembershipHead>>);
+
+impl PeerMembershipHeads {
+    fn record(&mut self, peer: PeerId, heads: Option<&[MembershipHead]>) {
+        let heads = heads
+            .unwrap_or_default()
+            .iter()
+            .map(|h| (h.group_id.clone(), h.clone()))
+            .collect();
+        self.0.insert(peer, heads);
+    }
+
+    fn of(&self, peer: &PeerId) -> Option<&HashMap<String, MembershipHead>> {
+        self.0.get(peer)
+    }
+
+    fn forget(&mut self, peer: &PeerId) {
+        self.0.remove(peer);
+    }
+}
+
 /// Check if entity is owner of group via group_members table.
 fn is_owner(storage: &dyn Storage, group_id: &str, entity_id: &str) -> bool {
     storage
@@ -92,17 +240,90 @@ fn is_owner(storage: &dyn Storage, group_id: &str, entity_id: &str) -> bool {
         .is_some_and(|m| m.role == "owner")
 }
 
+/// Outbound request start times, keyed per mini-protocol (request IDs are
+/// only unique within one request-response behaviour).
+struct RequestTimer {
+    metrics: Arc<ProtocolMetrics>,
+    started: HashMap<(&'static str, request_response::OutboundRequestId), Instant>,
+}
+
+impl RequestTimer {
+    fn new(metrics: Arc<ProtocolMetrics>) -> Self {
+        Self {
+            metrics,
+            started: HashMap::new(),
+        }
+    }
+
+    fn start(&mut self, protocol: &'static str, id: request_response::OutboundRequestId) {
+        self.started.insert((protocol, id), Instant::now());
+    }
+
+    fn finish(
+        &mut self,
+        protocol: &'static str,
+        id: request_response::OutboundRequestId,
+        ok: bool,
+    ) {
+        let Some(started) = self.started.remove(&(protocol, id)) else {
+            return;
+        };
+        if ok {
+            self.metrics.observe(protocol, started.elapsed());
+        } else {
+            self.metrics.record_failure(protocol);
+        }
+    }
+}
+
 /// Result of merging group descriptors from a peer.
 struct MergeResult {
     /// Group IDs that were upserted (new or updated).
     upserted: Vec<String>,
-    /// Group IDs that were tombstoned (deletion propagated from owner).
-    tombstoned: Vec<String>,
+    /// Group IDs that were tombstoned (deletion propagated from owner), with
+    /// the culture they had before the tombstone (for notification_policy).
+    tombstoned: Vec<(String, Option<String>)>,
+    /// (group, entity) pairs whose membership changed via the membership log.
+    members: Vec<(String, String)>,
+}
+
+/// Enforce departure_policy for a tombstone received from a peer.
+///
+/// Only a tombstone signed by the group's owner (the one we know, or its
+/// successor through a verified handover) may trigger purge/archive; anything else retains items, so a peer cannot make us
+/// destroy data by gossiping a deletion.
+fn apply_remote_departure(storage: &dyn Storage, desc: &GroupDescriptor, prior: Option<&GroupRow>) {
+    let Some(local) = prior.filter(|g| g.culture != GROUP_TOMBSTONE_CULTURE) else {
+        return; // not a departure: unknown group or already tombstoned
+    };
+    // Same rule as the tombstone check in merge_descriptors, which has
+    // already verified the signature and any handover to a new owner
+    let policy = if desc.is_owner_signed() {
+        cordelia_replication::departure::departure_policy_for(&local.culture)
+    } else {
+        tracing::info!(
+            group_id = %desc.id,
+            "net: group tombstone not signed by owner -- retaining items regardless of departure_policy"
+        );
+        cordelia_replication::DeparturePolicy::Retain
+    };
+    if let Err(e) = cordelia_replication::departure::apply_departure_policy(
+        storage,
+        &desc.id,
+        policy,
+        cordelia_replication::departure::REASON_OWNER_REMOVAL,
+    ) {
+        tracing::warn!(group_id = %desc.id, error = %e, "net: departure policy failed");
+    }
 }
 
 /// Merge incoming descriptors into local storage (LWW by updated_at).
 /// Verifies checksum and signature before accepting.
-fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) -> MergeResult {
+fn merge_descriptors(
+    storage: &dyn Storage,
+    clock: &HlcClock,
+    descriptors: &[GroupDescriptor],
+) -> MergeResult {
     let mut upserted = Vec::new();
     let mut tombstoned = Vec::new();
     for desc in descriptors {
@@ -115,19 +336,42 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
             continue;
         }
 
-        // Culture size limit (4KB as per R4-030). Tombstones are always small.
-        if desc.culture.len() > 4096 && !desc.is_tombstone() {
+        // Culture must match the schema (size limit 4KB as per R4-030), in
+        // canonical form: it is stored as received, since the checksum and
+        // signature cover its bytes.
+        if !desc.is_tombstone() {
+            let reason = match GroupCulture::parse(&desc.culture) {
+                Ok(c) if c.to_canonical_json() == desc.culture => None,
+                Ok(_) => Some("culture is not in canonical form".to_string()),
+                Err(reason) => Some(reason),
+            };
+            if let Some(reason) = reason {
+                tracing::warn!(
+                    group_id = %desc.id,
+                    reason,
+                    "net: rejecting group descriptor with invalid culture"
+                );
+                continue;
+            }
+        }
+
+        // Security policy must be well-formed (it is enforced on receive)
+        if let Err(reason) = SecurityPolicy::parse(desc.security_policy.as_deref().unwrap_or("{}"))
+        {
             tracing::warn!(
                 group_id = %desc.id,
-                culture_len = desc.culture.len(),
-                "net: rejecting group descriptor with oversized culture"
+                reason,
+                "net: rejecting group descriptor with invalid security policy"
             );
             continue;
         }
 
-        // Verify signature if present
-        if let (Some(ref pubkey_hex), Some(ref sig_hex)) = (&desc.owner_pubkey, &desc.signature) {
-            if !verify_descriptor_signature(desc, pubkey_hex, sig_hex) {
+        // Verify signature if present (by the owner, an admin, or the outgoing owner)
+        if let Some(ref sig_hex) = desc.signature {
+            let valid = desc
+                .signing_key()
+                .is_some_and(|key| verify_descriptor_signature(desc, key, sig_hex));
+            if !valid {
                 tracing::warn!(
                     group_id = %desc.id,
                     "net: rejecting group descriptor with invalid signature"
@@ -136,9 +380,11 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
             }
         }
 
-        // If we already know this group and it has a signature, reject unsigned updates
-        if let Ok(Some(ref local)) = storage.read_group(&desc.id) {
-            if local.signature.is_some() && desc.signature.is_none() {
+        // If we already know this group and it has ever been signed, reject unsigned updates
+        let local = storage.read_group(&desc.id).ok().flatten();
+        if let Some(ref local) = local {
+            let ever_signed = local.signature.is_some() || local.owner_pubkey.is_some();
+            if ever_signed && desc.signature.is_none() {
                 tracing::warn!(
                     group_id = %desc.id,
                     "net: rejecting unsigned descriptor for signed group"
@@ -146,25 +392,83 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
                 continue;
             }
 
-            // If both signed, ensure same owner (prevent owner hijack)
+            // Only the owner may resurrect a deleted group
+            if local.culture == GROUP_TOMBSTONE_CULTURE
+                && !desc.is_tombstone()
+                && !desc.is_owner_signed()
+            {
+                tracing::warn!(
+                    group_id = %desc.id,
+                    "net: rejecting resurrection of deleted group not signed by owner"
+                );
+                continue;
+            }
+
+            // If both signed, a different owner needs a handover chain from
+            // the owner we know (prevent owner hijack)
             if let (Some(ref local_owner), Some(ref incoming_owner)) =
                 (&local.owner_pubkey, &desc.owner_pubkey)
             {
-                if local_owner != incoming_owner {
+                if local_owner != incoming_owner && !verify_transfer_chain(desc, local_owner) {
                     tracing::warn!(
                         group_id = %desc.id,
-                        "net: rejecting descriptor with different owner pubkey"
+                        "net: rejecting descriptor with different owner pubkey and no valid transfer"
                     );
                     continue;
                 }
             }
         }
 
-        // LWW: only upsert if incoming is newer than local
+        // Deletion needs the owner key (unsigned tombstones only for never-signed groups)
+        if desc.is_tombstone() && desc.signature.is_some() && !desc.is_owner_signed() {
+            tracing::warn!(
+                group_id = %desc.id,
+                "net: rejecting group tombstone not signed by owner"
+            );
+            continue;
+        }
+
+        if desc.signature.is_some() {
+            if let Err(reason) = check_descriptor_signer(desc, local.as_ref()) {
+                tracing::warn!(
+                    group_id = %desc.id,
+                    reason,
+                    "net: rejecting descriptor signed without authority"
+                );
+                continue;
+            }
+        }
+
+        // Version: the HLC, else derived from updated_at (older peers). A
+        // version beyond the allowed clock skew would win every later LWW.
+        let incoming = Hlc::of(desc.hlc.as_deref(), &desc.updated_at);
+        if let Some(ref version) = incoming {
+            if !clock.observe(version) {
+                tracing::warn!(
+                    group_id = %desc.id,
+                    updated_at = %desc.updated_at,
+                    "net: rejecting group descriptor dated in the future (clock skew)"
+                );
+                continue;
+            }
+        }
+
+        // A group we left stays left until the owner deletes it
+        if !desc.is_tombstone() && cordelia_replication::departure::left(storage, &desc.id) {
+            tracing::trace!(
+                group_id = %desc.id,
+                "net: skipping group descriptor (left this group)"
+            );
+            continue;
+        }
+
+        // LWW: only upsert if incoming is newer than local (total order on the HLC)
         // Also capture local name (name is not on wire -- portal distributes it out-of-band)
+        // and culture (departure_policy must be read before a tombstone overwrites it)
         let local_name;
+        let mut prior: Option<GroupRow> = None;
         match storage.read_group(&desc.id) {
-            Ok(Some(local)) if local.updated_at >= desc.updated_at => {
+            Ok(Some(local)) if Hlc::of(local.hlc.as_deref(), &local.updated_at) >= incoming => {
                 tracing::trace!(
                     group_id = %desc.id,
                     "net: skipping group descriptor (local is same or newer)"
@@ -173,6 +477,7 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
             }
             Ok(Some(local)) => {
                 local_name = local.name.clone();
+                prior = Some(local.clone());
                 // Check for eagerness increase (soft downgrade policy)
                 if eagerness_increased(&local.culture, &desc.culture) {
                     tracing::info!(
@@ -188,13 +493,29 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
             }
         }
 
-        match storage.write_group(&desc.id, &local_name, &desc.culture, "{}") {
+        let security_policy = desc.security_policy.as_deref().unwrap_or("{}");
+        match storage.write_group(&desc.id, &local_name, &desc.culture, security_policy) {
             Ok(_) => {
-                // Persist signature fields if present
+                // Persist signature fields if present (with the signed updated_at)
                 if let (Some(ref oid), Some(ref pk), Some(ref sig)) =
                     (&desc.owner_id, &desc.owner_pubkey, &desc.signature)
                 {
-                    let _ = storage.write_group_signature(&desc.id, oid, pk, sig);
+                    let authority = GroupAuthority {
+                        updated_at: desc.updated_at.clone(),
+                        owner_id: oid.clone(),
+                        owner_pubkey: pk.clone(),
+                        signature: sig.clone(),
+                        signer_pubkey: desc.signer_pubkey.clone(),
+                        admins: desc.admins.clone().unwrap_or_default(),
+                        owner_transfers: desc
+                            .transfers
+                            .as_ref()
+                            .and_then(|t| serde_json::to_string(t).ok()),
+                        hlc: desc.hlc.clone(),
+                    };
+                    let _ = storage.write_group_authority(&desc.id, &authority);
+                } else {
+                    let _ = storage.set_group_hlc(&desc.id, desc.hlc.as_deref());
                 }
 
                 if desc.is_tombstone() {
@@ -209,7 +530,8 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
                         group_id = %desc.id,
                         "net: group deletion tombstone received from peer"
                     );
-                    tombstoned.push(desc.id.clone());
+                    apply_remote_departure(storage, desc, prior.as_ref());
+                    tombstoned.push((desc.id.clone(), prior.map(|g| g.culture)));
                 } else {
                     tracing::debug!(
                         group_id = %desc.id,
@@ -230,37 +552,417 @@ fn merge_descriptors(storage: &dyn Storage, descriptors: &[GroupDescriptor]) ->
     MergeResult {
         upserted,
         tombstoned,
+        members: Vec::new(),
     }
 }
 
-/// Verify an Ed25519 signature on a group descriptor.
-fn verify_descriptor_signature(desc: &GroupDescriptor, pubkey_hex: &str, sig_hex: &str) -> bool {
-    let Ok(pubkey_bytes) = hex::decode(pubkey_hex) else {
+/// Merge incoming membership log entries, then replay the log of every
+/// group that gained entries or a new descriptor into `group_members`.
+/// Entries must carry a valid signature and belong to a live group whose
+/// owner key we know; authority is decided by `membership::ingest`.
+fn merge_membership(
+    storage: &dyn Storage,
+    entries: &[MembershipEntry],
+    upserted: &[String],
+) -> Vec<(String, String)> {
+    let mut by_group: HashMap<&str, Vec<cordelia_storage::MembershipLogRow>> = HashMap::new();
+    for entry in entries {
+        if !verify_ed25519_hex(
+            &entry.signing_payload(),
+            &entry.signer_pubkey,
+            &entry.signature,
+        ) {
+            tracing::warn!(
+                group_id = %entry.group_id,
+                signer = %entry.signer_id,
+                "net: rejecting membership entry with invalid signature"
+            );
+            continue;
+        }
+        by_group
+            .entry(entry.group_id.as_str())
+            .or_default()
+            .push(membership::to_row(entry));
+    }
+
+    let mut replay: HashSet<String> = upserted.iter().cloned().collect();
+    for (group_id, rows) in by_group {
+        match storage.read_group(group_id) {
+            Ok(Some(g)) if g.culture != GROUP_TOMBSTONE_CULTURE => {}
+            _ => continue, // unknown or deleted group
+        }
+        match membership::ingest(storage, group_id, &rows) {
+            Ok(0) => {}
+            Ok(appended) => {
+                tracing::debug!(group_id, appended, "net: merged membership log entries");
+                replay.insert(group_id.to_string());
+            }
+            Err(e) => tracing::warn!(group_id, "net: failed to merge membership log: {e}"),
+        }
+    }
+
+    let mut changed = Vec::new();
+    for group_id in replay {
+        match membership::apply(storage, &group_id) {
+            Ok(entities) => changed.extend(entities.into_iter().map(|e| (group_id.clone(), e))),
+            Err(e) => tracing::warn!(group_id, "net: failed to apply membership log: {e}"),
+        }
+    }
+    changed
+}
+
+/// Whether the descriptor's signer may sign it: the owner; the previous
+/// owner, on the descriptor carrying the handover to this owner only; or
+/// a listed admin, who cannot change the admin set. The set comes from our copy when the owner is
+/// unchanged, else from the descriptor (first sight).
+fn check_descriptor_signer(desc: &GroupDescriptor, local: Option<&GroupRow>) -> Result<(), String> {
+    let Some(signer) = desc.signer_pubkey.as_deref() else {
+        return Ok(()); // owner-signed
+    };
+    if desc.owner_pubkey.as_deref() == Some(signer) {
+        return Ok(());
+    }
+    let handover = desc
+        .transfers
+        .as_ref()
+        .and_then(|t| t.last())
+        .is_some_and(|t| {
+            t.from_pubkey == signer
+                && desc.owner_pubkey.as_deref() == Some(t.to_pubkey.as_str())
+                && carries_handover(desc, t)
+        });
+    if handover {
+        return Ok(());
+    }
+
+    let incoming: HashSet<&str> = desc.admins.iter().flatten().map(String::as_str).collect();
+    let known: HashSet<&str> = match local.filter(|l| l.owner_pubkey == desc.owner_pubkey) {
+        Some(l) => l.admins.iter().map(String::as_str).collect(),
+        None => incoming.clone(),
+    };
+    if !known.contains(signer) {
+        return Err(format!(
+            "signer {signer} is not the owner or a listed admin"
+        ));
+    }
+    if known != incoming {
+        return Err("admins cannot change the admin set".into());
+    }
+    Ok(())
+}
+
+/// Whether `desc` is the descriptor that carried handover `t`, the only
+/// one the outgoing owner key may sign.
+fn carries_handover(desc: &GroupDescriptor, t: &OwnershipTransfer) -> bool {
+    if desc.updated_at != t.transferred_at {
+        return false;
+    }
+    match &t.hlc {
+        Some(hlc) => desc.hlc.as_ref() == Some(hlc),
+        // Older transfers did not record the HLC: hold it to the same second
+        None => desc.hlc.as_deref().is_none_or(|h| {
+            Hlc::parse(h).is_some_and(|h| {
+                h.wall().format("%Y-%m-%d %H:%M:%S").to_string() == t.transferred_at
+            })
+        }),
+    }
+}
+
+/// Verify the descriptor's handover chain leads from `from_pubkey` (the
+/// owner we know) to its `owner_pubkey`, each link signed by its outgoing owner.
+fn verify_transfer_chain(desc: &GroupDescriptor, from_pubkey: &str) -> bool {
+    let Some(transfers) = desc.transfers.as_deref() else {
         return false;
     };
-    let Ok(sig_bytes) = hex::decode(sig_hex) else {
+    let Some(start) = transfers.iter().position(|t| t.from_pubkey == from_pubkey) else {
         return false;
     };
-    let payload = desc.signing_payload();
-    let public_key =
-        ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, &pubkey_bytes);
-    public_key.verify(&payload, &sig_bytes).is_ok()
+    let mut key = from_pubkey;
+    for t in &transfers[start..] {
+        if t.from_pubkey != key
+            || !verify_ed25519_hex(&t.signing_payload(&desc.id), &t.from_pubkey, &t.signature)
+        {
+            return false;
+        }
+        key = &t.to_pubkey;
+    }
+    let last = &transfers[transfers.len() - 1];
+    desc.owner_pubkey.as_deref() == Some(key)
+        && desc.owner_id.as_deref() == Some(last.to_owner_id.as_str())
+}
+
+/// Verify an Ed25519 signature on a group descriptor.
+fn verify_descriptor_signature(desc: &GroupDescriptor, pubkey_hex: &str, sig_hex: &str) -> bool {
+    verify_ed25519_hex(&desc.signing_payload(), pubkey_hex, sig_hex)
+}
+
+/// Forward a peer's GroupExchange `sent_at` for clock offset estimation.
+fn report_peer_clock(
+    event_tx: &broadcast::Sender<SwarmEvent2>,
+    peer_id: PeerId,
+    sent_at: Option<&str>,
+) {
+    let Some(remote_time) = sent_at.and_then(cordelia_replication::parse_timestamp) else {
+        return; // old peer, or garbled timestamp
+    };
+    let _ = event_tx.send(SwarmEvent2::PeerClock {
+        peer_id,
+        remote_time,
+        received_at: chrono::Utc::now(),
+    });
 }
 
 /// Check if broadcast_eagerness increased (informational only, soft policy).
-/// "moderate" is treated as chatty (deprecated, maps to eager).
 fn eagerness_increased(old_culture: &str, new_culture: &str) -> bool {
-    fn parse_eagerness(culture: &str) -> u8 {
-        if culture.contains("\"eager\"")
-            || culture.contains("\"chatty\"")
-            || culture.contains("\"moderate\"")
+    GroupCulture::load(old_culture).broadcast_eagerness == Eagerness::Taciturn
+        && GroupCulture::load(new_culture).broadcast_eagerness == Eagerness::Chatty
+}
+
+/// Circuit relay v2 reservations a personal node holds with relays, so
+/// peers behind other NATs can reach it through them and hole punch.
+struct RelayReservations {
+    max: usize,
+    /// Address we dialled each connected peer on (outbound, direct only).
+    dialled: HashMap<PeerId, Multiaddr>,
+    /// Circuit listeners, by the relay they are reserved with.
+    listeners: HashMap<ListenerId, PeerId>,
+}
+
+impl RelayReservations {
+    fn new(max: usize) -> Self {
+        Self {
+            max,
+            dialled: HashMap::new(),
+            listeners: HashMap::new(),
+        }
+    }
+
+    fn on_connected(&mut self, peer: PeerId, endpoint: &ConnectedPoint) {
+        if let ConnectedPoint::Dialer { address, .. } = endpoint {
+            if !endpoint.is_relayed() {
+                self.dialled.insert(peer, address.clone());
+            }
+        }
+    }
+
+    fn on_disconnected(&mut self, swarm: &mut Swarm<CordeliaBehaviour>, peer: PeerId) {
+        self.dialled.remove(&peer);
+        self.listeners.retain(|id, relay| {
+            let keep = *relay != peer;
+            if !keep {
+                swarm.remove_listener(*id);
+            }
+            keep
+        });
+    }
+
+    fn on_listener_closed(&mut self, id: ListenerId) {
+        if let Some(relay) = self.listeners.remove(&id) {
+            tracing::info!(%relay, "net: relay reservation closed");
+        }
+    }
+
+    /// Reserve a circuit with a peer that serves the relay hop protocol,
+    /// unless we already hold `max`. Only relays we dialled qualify: the
+    /// remote address of an inbound connection is not one we can reach.
+    fn on_identify(
+        &mut self,
+        swarm: &mut Swarm<CordeliaBehaviour>,
+        peer: PeerId,
+        protocols: &[StreamProtocol],
+    ) {
+        if self.listeners.len() >= self.max
+            || !protocols.contains(&relay::HOP_PROTOCOL_NAME)
+            || self.listeners.values().any(|r| *r == peer)
         {
-            2
-        } else {
-            0
-        } // passive/taciturn or unknown
+            return;
+        }
+        let Some(addr) = self.dialled.get(&peer) else {
+            return;
+        };
+        let addr = circuit_addr(addr, peer);
+        match swarm.listen_on(addr.clone()) {
+            Ok(id) => {
+                tracing::info!(relay = %peer, %addr, "net: requesting relay reservation");
+                self.listeners.insert(id, peer);
+            }
+            Err(e) => tracing::warn!(relay = %peer, %addr, "net: relay reservation failed: {e}"),
+        }
     }
-    parse_eagerness(new_culture) > parse_eagerness(old_culture)
+}
+
+/// `relay_addr/p2p/<relay>/p2p-circuit`: listen (or, with the target's
+/// `/p2p` appended, dial) through a relay.
+fn circuit_addr(relay_addr: &Multiaddr, relay: PeerId) -> Multiaddr {
+    let mut addr = relay_addr.clone();
+    if matches!(addr.iter().last(), Some(Protocol::P2p(_))) {
+        addr.pop();
+    }
+    addr.with(Protocol::P2p(relay)).with(Protocol::P2pCircuit)
+}
+
+/// Our own Kademlia DHT, separate from the public libp2p one.
+pub const KAD_PROTOCOL: StreamProtocol = StreamProtocol::new("/cordelia/kad/1");
+
+/// Kademlia mode for a role (see [`SwarmOptions::dht`]).
+pub fn dht_mode(role: crate::config::NodeRole) -> Option<kad::Mode> {
+    match role {
+        crate::config::NodeRole::Relay => Some(kad::Mode::Server),
+        crate::config::NodeRole::Personal => Some(kad::Mode::Client),
+        crate::config::NodeRole::Keeper => None,
+    }
+}
+
+/// DHT key of a group's provider records: the SHA-256 of the group ID, so
+/// relays carrying a group can be found without gossiping group lists.
+pub fn group_provider_key(group_id: &str) -> kad::RecordKey {
+    kad::RecordKey::new(&cordelia_crypto::sha256_hex(group_id.as_bytes()))
+}
+
+/// Kademlia queries in flight and the groups we are a provider for.
+#[derive(Default)]
+struct Dht {
+    /// Groups we hold a provider record for (relays).
+    providing: HashSet<String>,
+    /// Provider lookups, by group.
+    provider_lookups: HashMap<kad::QueryId, String>,
+    /// Lookups for the address of a provider missing from our routing table.
+    provider_addr_lookups: HashMap<kad::QueryId, (String, PeerId)>,
+}
+
+impl Dht {
+    /// Publish provider records for the groups we carry and withdraw those
+    /// we no longer do. Waits for a non-empty routing table, so the first
+    /// publication reaches someone.
+    fn provide(&mut self, kad: &mut kad::Behaviour<kad::store::MemoryStore>, groups: &[String]) {
+        if kad.kbuckets().next().is_none() {
+            return;
+        }
+        self.providing.retain(|g| {
+            let keep = groups.contains(g);
+            if !keep {
+                kad.stop_providing(&group_provider_key(g));
+            }
+            keep
+        });
+        for g in groups {
+            if self.providing.contains(g) {
+                continue;
+            }
+            match kad.start_providing(group_provider_key(g)) {
+                Ok(_) => {
+                    tracing::debug!(group = %g, "dht: providing group");
+                    self.providing.insert(g.clone());
+                }
+                Err(e) => tracing::warn!(group = %g, "dht: provider record not stored: {e}"),
+            }
+        }
+    }
+
+    fn find_providers(&mut self, kad: &mut kad::Behaviour<kad::store::MemoryStore>, group: String) {
+        let id = kad.get_providers(group_provider_key(&group));
+        self.provider_lookups.insert(id, group);
+    }
+
+    /// Turn a Kademlia event into governor events: peers found by a random
+    /// walk or routing update, and providers of a group with their addresses.
+    fn on_event(
+        &mut self,
+        kad: &mut kad::Behaviour<kad::store::MemoryStore>,
+        local_id: PeerId,
+        event: kad::Event,
+    ) -> Vec<SwarmEvent2> {
+        let mut out = Vec::new();
+        match event {
+            kad::Event::RoutingUpdated {
+                peer,
+                is_new_peer: true,
+                addresses,
+                ..
+            } => out.push(SwarmEvent2::DhtPeerDiscovered {
+                peer_id: peer,
+                addrs: addresses.into_vec(),
+            }),
+            kad::Event::OutboundQueryProgressed {
+                id, result, step, ..
+            } => match result {
+                kad::QueryResult::GetClosestPeers(res) => {
+                    let peers = match res {
+                        Ok(ok) => ok.peers,
+                        Err(kad::GetClosestPeersError::Timeout { peers, .. }) => peers,
+                    };
+                    if let Some((group_id, provider)) = self.provider_addr_lookups.remove(&id) {
+                        if let Some(p) = peers.into_iter().find(|p| p.peer_id == provider) {
+                            out.push(SwarmEvent2::GroupProvidersFound {
+                                group_id,
+                                providers: vec![(p.peer_id, p.addrs)],
+                            });
+                        }
+                    } else {
+                        out.extend(peers.into_iter().filter(|p| !p.addrs.is_empty()).map(|p| {
+                            SwarmEvent2::DhtPeerDiscovered {
+                                peer_id: p.peer_id,
+                                addrs: p.addrs,
+                            }
+                        }));
+                    }
+                }
+                kad::QueryResult::GetProviders(res) => {
+                    let group = if step.last {
+                        self.provider_lookups.remove(&id)
+                    } else {
+                        self.provider_lookups.get(&id).cloned()
+                    };
+                    let (Some(group_id), Ok(kad::GetProvidersOk::FoundProviders { providers, .. })) =
+                        (group, res)
+                    else {
+                        return out;
+                    };
+                    let mut found = Vec::new();
+                    for provider in providers.into_iter().filter(|p| *p != local_id) {
+                        let addrs = routing_addrs(kad, provider);
+                        if addrs.is_empty() {
+                            let q = kad.get_closest_peers(provider);
+                            self.provider_addr_lookups
+                                .insert(q, (group_id.clone(), provider));
+                        } else {
+                            found.push((provider, addrs));
+                        }
+                    }
+                    tracing::debug!(group = %group_id, found = found.len(), "dht: group providers found");
+                    if !found.is_empty() {
+                        out.push(SwarmEvent2::GroupProvidersFound {
+                            group_id,
+                            providers: found,
+                        });
+                    }
+                }
+                kad::QueryResult::StartProviding(Err(e)) => {
+                    // Publish again on the next round
+                    tracing::debug!("dht: provider announcement failed: {e}");
+                    self.providing.retain(|g| group_provider_key(g) != *e.key());
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        out
+    }
+}
+
+/// Addresses of a peer in the Kademlia routing table (empty if absent).
+fn routing_addrs(
+    kad: &mut kad::Behaviour<kad::store::MemoryStore>,
+    peer: PeerId,
+) -> Vec<Multiaddr> {
+    kad.kbucket(peer)
+        .and_then(|bucket| {
+            bucket
+                .iter()
+                .find(|e| *e.node.key.preimage() == peer)
+                .map(|e| e.node.value.iter().cloned().collect())
+        })
+        .unwrap_or_default()
 }
 
 // ============================================================================
@@ -271,6 +973,18 @@ fn eagerness_increased(old_culture: &str, new_culture: &str) -> bool {
 pub struct CordeliaBehaviour {
     pub ping: libp2p::ping::Behaviour,
     pub identify: libp2p::identify::Behaviour,
+    /// Circuit relay v2 server (relay nodes only).
+    pub relay: Toggle<relay::Behaviour>,
+    pub relay_client: relay::client::Behaviour,
+    /// Hole punching: upgrades relayed connections to direct ones.
+    pub dcutr: dcutr::Behaviour,
+    /// Reachability probes; confirmed addresses become external addresses.
+    pub autonat: autonat::Behaviour,
+    /// Local-link peer discovery (opt-in).
+    pub mdns: Toggle<mdns::tokio::Behaviour>,
+    /// Peer and group-provider discovery (server mode on relays only; off
+    /// on keepers).
+    pub kad: Toggle<kad::Behaviour<kad::store::MemoryStore>>,
     pub peer_share: request_response::json::Behaviour<PeerShareRequest, PeerShareResponse>,
     pub memory_sync: request_response::json::Behaviour<SyncRequest, SyncResponse>,
     pub memory_fetch: request_response::json::Behaviour<FetchRequest, FetchResponse>,
@@ -283,7 +997,14 @@ pub struct CordeliaBehaviour {
 // ============================================================================
 
 pub enum SwarmCommand {
+    /// Dial an address whose peer ID is unknown (bootnode, operator).
     Dial(Multiaddr),
+    /// Dial a known peer on all of `addrs` at once (best first); the first
+    /// to connect wins.
+    DialPeer {
+        peer: PeerId,
+        addrs: Vec<Multiaddr>,
+    },
     Disconnect(PeerId),
     SendPeerShareRequest {
         peer: PeerId,
@@ -309,6 +1030,14 @@ pub enum SwarmCommand {
         request: GroupExchange,
         response_tx: oneshot::Sender<Result<GroupExchangeResponse, String>>,
     },
+    /// Look up the peers closest to a random key (DHT random walk).
+    DhtRandomWalk,
+    /// Look up the relays holding a provider record for a group.
+    FindGroupProviders {
+        group_id: String,
+    },
+    /// Publish provider records for the groups we carry (relays only).
+    ProvideGroups,
 }
 
 // ============================================================================
@@ -320,6 +1049,8 @@ pub enum SwarmEvent2 {
     PeerConnected {
         peer_id: PeerId,
         addrs: Vec<Multiaddr>,
+        /// The address we dialled, for outbound connections.
+        dialed_addr: Option<Multiaddr>,
     },
     PeerDisconnected {
         peer_id: PeerId,
@@ -328,9 +1059,17 @@ pub enum SwarmEvent2 {
         peer_id: PeerId,
         rtt_ms: f64,
     },
+    /// A peer's clock reading (GroupExchange `sent_at`) and when we received it.
+    PeerClock {
+        peer_id: PeerId,
+        remote_time: chrono::DateTime<chrono::Utc>,
+        received_at: chrono::DateTime<chrono::Utc>,
+    },
     IdentifyReceived {
         peer_id: PeerId,
         listen_addrs: Vec<Multiaddr>,
+        /// Serves circuit relay reservations (a relay).
+        is_relay: bool,
         #[allow(dead_code)]
         observed_addr: Multiaddr,
         #[allow(dead_code)]
@@ -339,8 +1078,35 @@ pub enum SwarmEvent2 {
     ExternalAddrConfirmed {
         addr: Multiaddr,
     },
+    /// A further, direct outbound connection to a connected peer --
+    /// typically a hole punch upgrading a relayed connection.
+    DirectConnection {
+        peer_id: PeerId,
+        addr: Multiaddr,
+    },
+    /// Peer found on the local link by mDNS.
+    LanPeerDiscovered {
+        peer_id: PeerId,
+        addrs: Vec<Multiaddr>,
+    },
+    /// A LAN peer's mDNS record expired.
+    LanPeerExpired {
+        peer_id: PeerId,
+    },
+    /// Peer found in the DHT (random walk or routing table update).
+    DhtPeerDiscovered {
+        peer_id: PeerId,
+        addrs: Vec<Multiaddr>,
+    },
+    /// Relays holding a provider record for a group, with their addresses.
+    GroupProvidersFound {
+        group_id: String,
+        providers: Vec<(PeerId, Vec<Multiaddr>)>,
+    },
     DialFailure {
         peer_id: Option<PeerId>,
+        /// Addresses that were tried and failed.
+        failed_addrs: Vec<Multiaddr>,
     },
 }
 
@@ -348,20 +1114,69 @@ pub enum SwarmEvent2 {
 // Build swarm
 // ============================================================================
 
+/// Optional behaviours and NAT traversal settings for [`build_swarm`].
+#[derive(Debug, Clone, Copy)]
+pub struct SwarmOptions {
+    /// Serve circuit relay v2 reservations (relay nodes).
+    pub relay_server: bool,
+    /// Only use and serve AutoNAT probes over public IPs.
+    pub autonat_only_global: bool,
+    /// Discover peers on the local link via mDNS.
+    pub mdns: bool,
+    /// Kademlia mode: server on relays; client elsewhere, so the node is
+    /// not advertised. None runs no DHT (keepers dial trusted relays only).
+    pub dht: Option<kad::Mode>,
+}
+
 pub fn build_swarm(
     keypair: identity::Keypair,
     listen_addr: Multiaddr,
+    opts: SwarmOptions,
 ) -> Result<Swarm<CordeliaBehaviour>, Box<dyn std::error::Error + Send + Sync>> {
     let peer_id = PeerId::from(keypair.public());
+    let mdns = if opts.mdns {
+        Some(mdns::tokio::Behaviour::new(
+            mdns::Config::default(),
+            peer_id,
+        )?)
+    } else {
+        None
+    };
 
-    let behaviour = CordeliaBehaviour {
+    let behaviour = |key: &identity::Keypair, relay_client| CordeliaBehaviour {
         ping: libp2p::ping::Behaviour::new(
             libp2p::ping::Config::new().with_interval(Duration::from_secs(15)),
         ),
         identify: libp2p::identify::Behaviour::new(libp2p::identify::Config::new(
             "/cordelia/id/1".into(),
-            keypair.public(),
+            key.public(),
         )),
+        relay: opts
+            .relay_server
+            .then(|| relay::Behaviour::new(peer_id, relay::Config::default()))
+            .into(),
+        relay_client,
+        dcutr: dcutr::Behaviour::new(peer_id),
+        autonat: autonat::Behaviour::new(
+            peer_id,
+            autonat::Config {
+                only_global_ips: opts.autonat_only_global,
+                ..Default::default()
+            },
+        ),
+        mdns: mdns.into(),
+        kad: opts
+            .dht
+            .map(|mode| {
+                let mut kad = kad::Behaviour::with_config(
+                    peer_id,
+                    kad::store::MemoryStore::new(peer_id),
+                    kad::Config::new(KAD_PROTOCOL),
+                );
+                kad.set_mode(Some(mode));
+                kad
+            })
+            .into(),
         peer_share: request_response::json::Behaviour::new(
             [(
                 StreamProtocol::new("/cordelia/peer-share/1"),
@@ -407,8 +1222,12 @@ pub fn build_swarm(
             libp2p::yamux::Config::default,
         )?
         .with_dns()?
-        .with_behaviour(|_| behaviour)?
-        .with_swarm_config(|cfg| cfg.with_idle_connection_timeout(Duration::from_secs(120)))
+        .with_relay_client(libp2p::noise::Config::new, libp2p::yamux::Config::default)?
+        .with_behaviour(behaviour)?
+        .with_swarm_config(|cfg| {
+            cfg.with_idle_connection_timeout(Duration::from_secs(120))
+                .with_dial_concurrency_factor(DIAL_CONCURRENCY)
+        })
         .build();
 
     swarm.listen_on(listen_addr)?;
@@ -428,6 +1247,8 @@ pub fn build_swarm(
 ///   For transparent relays this is None (accept all). For dynamic/explicit, it's the
 ///   computed acceptance set. Non-relay nodes pass None.
 /// `relay_blocked_groups`: deny-list applied on top of any posture.
+/// `relay_reservations`: circuit reservations to hold with relays (personal
+///   nodes; 0 for others).
 #[allow(clippy::too_many_arguments)]
 pub async fn run_swarm_loop(
     mut swarm: Swarm<CordeliaBehaviour>,
@@ -442,8 +1263,22 @@ pub async fn run_swarm_loop(
     relay_blocked_groups: Arc<HashSet<String>>,
     node_identity: Arc<NodeIdentity>,
     our_entity_id: String,
+    change_feed: Arc<ChangeFeed>,
+    protocol_metrics: Arc<ProtocolMetrics>,
+    repl_config: cordelia_replication::ReplicationConfig,
+    hlc: Arc<HlcClock>,
+    relay_reservations: usize,
     mut shutdown: broadcast::Receiver<()>,
 ) {
+    let mut timer = RequestTimer::new(protocol_metrics);
+    let mut reservations = RelayReservations::new(relay_reservations);
+    // Relay nodes: peers holding a circuit reservation with us
+    let mut relay_clients: HashSet<PeerId> = HashSet::new();
+    let mut dht = Dht::default();
+    let mut membership_heads = PeerMembershipHeads::default();
+    // Engine for inbound pushes (same config and clock as the replication task's)
+    let push_engine =
+        ReplicationEngine::new(repl_config, our_entity_id.clone()).with_clock(hlc.clone());
     // Track pending outbound request-response channels
     type ReqId = request_response::OutboundRequestId;
     let mut pending_peer_share: HashMap<ReqId, oneshot::Sender<Result<PeerShareResponse, String>>> =
@@ -469,6 +1304,16 @@ pub async fn run_swarm_loop(
                             tracing::warn!(%addr, "net: dial failed: {e}");
                         }
                     }
+                    SwarmCommand::DialPeer { peer, addrs } => {
+                        tracing::debug!(%peer, addrs = addrs.len(), "net: dialling peer");
+                        let opts = DialOpts::peer_id(peer)
+                            .addresses(addrs)
+                            .condition(PeerCondition::DisconnectedAndNotDialing)
+                            .build();
+                        if let Err(e) = swarm.dial(opts) {
+                            tracing::debug!(%peer, "net: dial not started: {e}");
+                        }
+                    }
                     SwarmCommand::Disconnect(peer_id) => {
                         tracing::debug!(%peer_id, "net: disconnecting peer");
                         if let Err(e) = swarm.disconnect_peer_id(peer_id) {
@@ -478,29 +1323,71 @@ pub async fn run_swarm_loop(
                     SwarmCommand::SendPeerShareRequest { peer, request, response_tx } => {
                         tracing::debug!(%peer, max_peers = request.max_peers, "net: sending peer share request");
                         let req_id = swarm.behaviour_mut().peer_share.send_request(&peer, request);
+                        timer.start("peer_share", req_id);
                         pending_peer_share.insert(req_id, response_tx);
                     }
                     SwarmCommand::SendSyncRequest { peer, request, response_tx } => {
                         tracing::debug!(%peer, group = request.group_id, "net: sending sync request");
                         let req_id = swarm.behaviour_mut().memory_sync.send_request(&peer, request);
+                        timer.start("sync", req_id);
                         pending_sync.insert(req_id, response_tx);
                     }
                     SwarmCommand::SendFetchRequest { peer, request, response_tx } => {
                         tracing::debug!(%peer, items = request.item_ids.len(), "net: sending fetch request");
                         let req_id = swarm.behaviour_mut().memory_fetch.send_request(&peer, request);
+                        timer.start("fetch", req_id);
                         pending_fetch.insert(req_id, response_tx);
                     }
                     SwarmCommand::SendMemoryPush { peer, request } => {
                         tracing::debug!(%peer, items = request.items.len(), "net: sending push");
-                        swarm.behaviour_mut().memory_push.send_request(&peer, request);
+                        let req_id = swarm.behaviour_mut().memory_push.send_request(&peer, request);
+                        timer.start("push", req_id);
+                    }
+                    SwarmCommand::DhtRandomWalk => {
+                        if let Some(kad) = swarm.behaviour_mut().kad.as_mut() {
+                            kad.get_closest_peers(PeerId::random());
+                        }
+                    }
+                    SwarmCommand::FindGroupProviders { group_id } => {
+                        if let Some(kad) = swarm.behaviour_mut().kad.as_mut() {
+                            dht.find_providers(kad, group_id);
+                        }
                     }
+                    SwarmCommand::ProvideGroups if our_role == crate::config::NodeRole::Relay => {
+                        // The groups our group exchange advertises
+                        let mut groups = shared_groups.read().await.clone();
+                        if let Some(ref accepted) = relay_accepted_groups {
+                            for g in accepted.read().await.iter() {
+                                if !groups.contains(g) {
+                                    groups.push(g.clone());
+                                }
+                            }
+                        }
+                        // Never announce groups under emcon or hidden by policy
+                        groups.retain(|g| advertised(storage.as_ref(), g, &our_entity_id));
+                        if let Some(kad) = swarm.behaviour_mut().kad.as_mut() {
+                            dht.provide(kad, &groups);
+                        }
+                    }
+                    SwarmCommand::ProvideGroups => {}
                     SwarmCommand::SendGroupExchange { peer, mut request, response_tx } => {
                         // Enrich with descriptors from local storage (R4-030)
                         if request.descriptors.is_none() {
                             request.descriptors = build_descriptors(storage.as_ref(), &node_identity, &our_entity_id);
                         }
+                        if request.membership.is_none() {
+                            (request.membership, request.membership_heads) = build_membership(
+                                storage.as_ref(),
+                                request.descriptors.as_deref(),
+                                membership_heads.of(&peer),
+                            );
+                        }
+                        // Never advertise groups we are in under emcon, or hidden by policy
+                        request.groups.retain(|g| advertised(storage.as_ref(), g, &our_entity_id));
+                        request.sent_at = Some(cordelia_replication::format_timestamp(chrono::Utc::now()));
                         tracing::debug!(%peer, our_groups = request.groups.len(), descriptors = request.descriptors.as_ref().map_or(0, |d| d.len()), "net: sending group exchange");
                         let req_id = swarm.behaviour_mut().group_exchange.send_request(&peer, request);
+                        timer.start("group_exchange", req_id);
                         pending_group_exchange.insert(req_id, response_tx);
                     }
                 }
@@ -512,6 +1399,9 @@ pub async fn run_swarm_loop(
                     SwarmEvent::NewListenAddr { address, .. } => {
                         tracing::info!(%address, "listening");
                     }
+                    SwarmEvent::ListenerClosed { listener_id, .. } => {
+                        reservations.on_listener_closed(listener_id);
+                    }
                     SwarmEvent::ConnectionEstablished {
                         peer_id,
                         endpoint,
@@ -528,11 +1418,16 @@ pub async fn run_swarm_loop(
                             connections = conns,
                             "net: connection established"
                         );
+                        reservations.on_connected(peer_id, &endpoint);
                         // Only emit connect for the first connection to this peer
-                        if conns == 1 {
+                        if conns > 1 && endpoint.is_dialer() && !endpoint.is_relayed() {
+                            let _ = event_tx.send(SwarmEvent2::DirectConnection { peer_id, addr });
+                        } else if conns == 1 {
+                            let dialed_addr = endpoint.is_dialer().then(|| addr.clone());
                             if let Err(e) = event_tx.send(SwarmEvent2::PeerConnected {
                                 peer_id,
                                 addrs: vec![addr],
+                                dialed_addr,
                             }) {
                                 tracing::warn!(%peer_id, "net: failed to send PeerConnected event: {e}");
                             }
@@ -552,6 +1447,9 @@ pub async fn run_swarm_loop(
                         );
                         // Only emit disconnect when last connection closes
                         if num_established == 0 {
+                            reservations.on_disconnected(&mut swarm, peer_id);
+                            relay_clients.remove(&peer_id);
+                            membership_heads.forget(&peer_id);
                             if let Err(e) = event_tx.send(SwarmEvent2::PeerDisconnected { peer_id }) {
                                 tracing::warn!(%peer_id, "net: failed to send PeerDisconnected event: {e}");
                             }
@@ -563,7 +1461,16 @@ pub async fn run_swarm_loop(
                             error = %error,
                             "net: outgoing connection failed"
                         );
-                        let _ = event_tx.send(SwarmEvent2::DialFailure { peer_id });
+                        let failed_addrs = match &error {
+                            DialError::Transport(errors) => {
+                                errors.iter().map(|(addr, _)| addr.clone()).collect()
+                            }
+                            DialError::WrongPeerId { endpoint, .. } => {
+                                vec![endpoint.get_remote_address().clone()]
+                            }
+                            _ => vec![],
+                        };
+                        let _ = event_tx.send(SwarmEvent2::DialFailure { peer_id, failed_addrs });
                     }
                     SwarmEvent::ExternalAddrConfirmed { address } => {
                         tracing::info!(%address, "net: external address confirmed");
@@ -578,7 +1485,7 @@ pub async fn run_swarm_loop(
                         // Handle peer share request async (needs pool access)
                         let max = request.max_peers as usize;
                         let relay_peers = pool.relay_peers().await;
-                        let peers: Vec<PeerAddress> = relay_peers
+                        let mut peers: Vec<PeerAddress> = relay_peers
                             .iter()
                             .take(max)
                             .map(|h| PeerAddress {
@@ -589,6 +1496,32 @@ pub async fn run_swarm_loop(
                                 role: if h.is_relay { "relay".into() } else { our_role.as_str().into() },
                             })
                             .collect();
+                        // Group members holding a reservation with us, reachable
+                        // through our circuit on each of our external addresses
+                        let local_id = *swarm.local_peer_id();
+                        for client in &relay_clients {
+                            if peers.len() >= max {
+                                break;
+                            }
+                            let Some(h) = pool.get(client).await else { continue };
+                            if h.groups.is_empty() {
+                                continue; // nobody would dial it
+                            }
+                            let addrs: Vec<String> = swarm
+                                .external_addresses()
+                                .map(|a| circuit_addr(a, local_id).with(Protocol::P2p(*client)).to_string())
+                                .collect();
+                            if addrs.is_empty() {
+                                break;
+                            }
+                            peers.push(PeerAddress {
+                                peer_id: client.to_string(),
+                                addrs,
+                                last_seen: 0,
+                                groups: h.groups.clone(),
+                                role: crate::config::NodeRole::Personal.as_str().into(),
+                            });
+                        }
                         tracing::debug!(
                             requested = max,
                             shared = peers.len(),
@@ -640,8 +1573,9 @@ pub async fn run_swarm_loop(
                             relay_check.as_ref().map(|f| f as &dyn Fn(&str) -> bool);
 
                         let item_count = request.items.len();
-                        let ack =
-                            handle_push_request(&storage, &request, &groups_snap, relay_accepts_ref);
+                        let (ack, contribution) =
+                            handle_push_request(&push_engine, &storage, &request, &groups_snap, relay_accepts_ref, &change_feed);
+                        pool.record_contribution(&peer, contribution).await;
 
                         if ack.rejected > 0 {
                             tracing::warn!(
@@ -679,17 +1613,94 @@ pub async fn run_swarm_loop(
                                     "relay: re-pushing to connected peers"
                                 );
                                 for relay_peer in &forward_peers {
-                                    swarm.behaviour_mut().memory_push.send_request(
+                                    let req_id = swarm.behaviour_mut().memory_push.send_request(
                                         &relay_peer.node_id,
                                         MemoryPushRequest {
                                             items: request.items.clone(),
                                         },
                                     );
+                                    timer.start("push", req_id);
                                 }
                             }
                         }
                     }
+                    SwarmEvent::Behaviour(CordeliaBehaviourEvent::Relay(ev)) => match ev {
+                        relay::Event::ReservationReqAccepted { src_peer_id, renewed } => {
+                            if !renewed {
+                                tracing::info!(peer = %src_peer_id, "relay: reservation accepted");
+                            }
+                            relay_clients.insert(src_peer_id);
+                        }
+                        relay::Event::ReservationTimedOut { src_peer_id } => {
+                            tracing::debug!(peer = %src_peer_id, "relay: reservation expired");
+                            relay_clients.remove(&src_peer_id);
+                        }
+                        relay::Event::CircuitReqAccepted { src_peer_id, dst_peer_id } => {
+                            tracing::debug!(src = %src_peer_id, dst = %dst_peer_id, "relay: circuit opened");
+                        }
+                        _ => {}
+                    },
+                    SwarmEvent::Behaviour(CordeliaBehaviourEvent::RelayClient(
+                        relay::client::Event::ReservationReqAccepted { relay_peer_id, renewal: false, .. },
+                    )) => {
+                        tracing::info!(relay = %relay_peer_id, "net: relay reservation accepted");
+                    }
+                    SwarmEvent::Behaviour(CordeliaBehaviourEvent::Autonat(
+                        autonat::Event::StatusChanged { old, new },
+                    )) => {
+                        tracing::info!(?old, ?new, "net: reachability changed");
+                    }
+                    SwarmEvent::Behaviour(CordeliaBehaviourEvent::Dcutr(dcutr::Event {
+                        remote_peer_id,
+                        result,
+                    })) => match result {
+                        Ok(_) => tracing::info!(peer = %remote_peer_id, "net: hole punch succeeded"),
+                        Err(e) => tracing::debug!(peer = %remote_peer_id, "net: hole punch failed: {e}"),
+                    },
+                    SwarmEvent::Behaviour(CordeliaBehaviourEvent::Mdns(ev)) => match ev {
+                        mdns::Event::Discovered(found) => {
+                            let mut by_peer: HashMap<PeerId, Vec<Multiaddr>> = HashMap::new();
+                            for (peer_id, addr) in found {
+                                by_peer.entry(peer_id).or_default().push(addr);
+                            }
+                            for (peer_id, addrs) in by_peer {
+                                tracing::debug!(%peer_id, ?addrs, "net: mdns discovered peer");
+                                let _ = event_tx.send(SwarmEvent2::LanPeerDiscovered { peer_id, addrs });
+                            }
+                        }
+                        mdns::Event::Expired(gone) => {
+                            let gone: HashSet<PeerId> = gone.into_iter().map(|(p, _)| p).collect();
+                            for peer_id in gone {
+                                tracing::debug!(%peer_id, "net: mdns peer expired");
+                                let _ = event_tx.send(SwarmEvent2::LanPeerExpired { peer_id });
+                            }
+                        }
+                    },
+                    SwarmEvent::Behaviour(CordeliaBehaviourEvent::Kad(ev)) => {
+                        let local_id = *swarm.local_peer_id();
+                        if let Some(kad) = swarm.behaviour_mut().kad.as_mut() {
+                            for out in dht.on_event(kad, local_id, ev) {
+                                let _ = event_tx.send(out);
+                            }
+                        }
+                    }
                     SwarmEvent::Behaviour(ev) => {
+                        if let CordeliaBehaviourEvent::Identify(libp2p::identify::Event::Received {
+                            peer_id,
+                            info,
+                            ..
+                        }) = &ev
+                        {
+                            reservations.on_identify(&mut swarm, *peer_id, &info.protocols);
+                            // DHT servers (relays) join our routing table
+                            if let Some(kad) = swarm.behaviour_mut().kad.as_mut() {
+                                if info.protocols.contains(&KAD_PROTOCOL) {
+                                    for addr in info.listen_addrs.iter().filter(|a| !is_circuit_addr(a)) {
+                                        kad.add_address(peer_id, addr.clone());
+                                    }
+                                }
+                            }
+                        }
                         let mut groups_snap = shared_groups.read().await.clone();
                         // Relay nodes: include learned groups so exchange responses
                         // advertise all groups the relay handles (not just formal members).
@@ -714,6 +1725,9 @@ pub async fn run_swarm_loop(
                             &mut pending_group_exchange,
                             &node_identity,
                             &our_entity_id,
+                            &hlc,
+                            &mut timer,
+                            &mut membership_heads,
                         );
                         // Note: merged descriptors are written to storage for metadata
                         // tracking (cultures, signatures) but do NOT enter shared_groups.
@@ -721,10 +1735,20 @@ pub async fn run_swarm_loop(
                         // in shared_groups. Relay nodes use relay_learned_groups instead.
 
                         // Tombstoned groups: remove from shared_groups (stops replication)
+                        for gid in &merge_result.upserted {
+                            change_feed.publish(
+                                storage.as_ref(),
+                                Change::group(ChangeKind::GroupUpdated, gid),
+                            );
+                        }
                         if !merge_result.tombstoned.is_empty() {
                             let mut groups = shared_groups.write().await;
-                            for gid in &merge_result.tombstoned {
+                            for (gid, prior_culture) in &merge_result.tombstoned {
                                 groups.retain(|g| g != gid);
+                                change_feed.publish_with_culture(
+                                    prior_culture.as_deref(),
+                                    Change::group(ChangeKind::GroupDeleted, gid),
+                                );
                             }
                             tracing::info!(
                                 tombstoned = ?merge_result.tombstoned,
@@ -732,6 +1756,9 @@ pub async fn run_swarm_loop(
                                 "net: removed tombstoned groups from shared_groups"
                             );
                         }
+                        for (gid, entity_id) in &merge_result.members {
+                            change_feed.publish(storage.as_ref(), Change::member(gid, entity_id));
+                        }
                     }
                     _ => {}
                 }
@@ -780,9 +1807,13 @@ fn handle_behaviour_event(
     >,
     node_identity: &Arc<NodeIdentity>,
     our_entity_id: &str,
+    hlc: &HlcClock,
+    timer: &mut RequestTimer,
+    membership_heads: &mut PeerMembershipHeads,
 ) -> MergeResult {
     let mut merged_groups = Vec::new();
     let mut tombstoned_groups = Vec::new();
+    let mut member_changes = Vec::new();
     match event {
         // -- Ping --
         CordeliaBehaviourEvent::Ping(libp2p::ping::Event {
@@ -811,6 +1842,7 @@ fn handle_behaviour_event(
             );
             let _ = event_tx.send(SwarmEvent2::IdentifyReceived {
                 peer_id,
+                is_relay: info.protocols.contains(&relay::HOP_PROTOCOL_NAME),
                 listen_addrs: info.listen_addrs,
                 observed_addr: info.observed_addr,
                 agent_version: info.agent_version,
@@ -827,6 +1859,7 @@ fn handle_behaviour_event(
                 },
             ..
         }) => {
+            timer.finish("peer_share", request_id, true);
             if let Some(tx) = pending_peer_share.remove(&request_id) {
                 let _ = tx.send(Ok(response));
             }
@@ -837,6 +1870,7 @@ fn handle_behaviour_event(
             ..
         }) => {
             tracing::warn!(error = %error, "net: peer share request failed");
+            timer.finish("peer_share", request_id, false);
             if let Some(tx) = pending_peer_share.remove(&request_id) {
                 let _ = tx.send(Err(error.to_string()));
             }
@@ -850,7 +1884,7 @@ fn handle_behaviour_event(
                 },
             ..
         }) => {
-            let resp = handle_sync_request(storage, &request);
+            let resp = handle_sync_request(storage, &request, our_entity_id);
             tracing::debug!(
                 group = request.group_id,
                 since = request.since.as_deref().unwrap_or("(full)"),
@@ -871,6 +1905,7 @@ fn handle_behaviour_event(
                 },
             ..
         }) => {
+            timer.finish("sync", request_id, true);
             if let Some(tx) = pending_sync.remove(&request_id) {
                 let _ = tx.send(Ok(response));
             }
@@ -881,6 +1916,7 @@ fn handle_behaviour_event(
             ..
         }) => {
             tracing::warn!(error = %error, "net: sync request failed");
+            timer.finish("sync", request_id, false);
             if let Some(tx) = pending_sync.remove(&request_id) {
                 let _ = tx.send(Err(error.to_string()));
             }
@@ -895,7 +1931,7 @@ fn handle_behaviour_event(
             ..
         }) => {
             let requested = request.item_ids.len();
-            let resp = handle_fetch_request(storage, &request);
+            let resp = handle_fetch_request(storage, &request, our_entity_id);
             tracing::debug!(
                 requested,
                 returned = resp.items.len(),
@@ -914,6 +1950,7 @@ fn handle_behaviour_event(
                 },
             ..
         }) => {
+            timer.finish("fetch", request_id, true);
             if let Some(tx) = pending_fetch.remove(&request_id) {
                 let _ = tx.send(Ok(response));
             }
@@ -924,6 +1961,7 @@ fn handle_behaviour_event(
             ..
         }) => {
             tracing::warn!(error = %error, "net: fetch request failed");
+            timer.finish("fetch", request_id, false);
             if let Some(tx) = pending_fetch.remove(&request_id) {
                 let _ = tx.send(Err(error.to_string()));
             }
@@ -941,9 +1979,14 @@ fn handle_behaviour_event(
             tracing::warn!("net: unexpected push request in behaviour handler");
         }
         CordeliaBehaviourEvent::MemoryPush(request_response::Event::Message {
-            message: request_response::Message::Response { response, .. },
+            message:
+                request_response::Message::Response {
+                    request_id,
+                    response,
+                },
             ..
         }) => {
+            timer.finish("push", request_id, true);
             tracing::debug!(
                 stored = response.stored,
                 rejected = response.rejected,
@@ -967,10 +2010,11 @@ fn handle_behaviour_event(
                 our_groups = our_groups.len(),
                 "net: served group exchange request"
             );
+            report_peer_clock(event_tx, peer, request.sent_at.as_deref());
 
             // Merge incoming descriptors from peer (R4-030)
             if let Some(ref descs) = request.descriptors {
-                let result = merge_descriptors(storage.as_ref(), descs);
+                let result = merge_descriptors(storage.as_ref(), hlc, descs);
                 if !result.upserted.is_empty() {
                     tracing::info!(
                         %peer,
@@ -982,10 +2026,29 @@ fn handle_behaviour_event(
                 }
                 tombstoned_groups.extend(result.tombstoned);
             }
+            member_changes = merge_membership(
+                storage.as_ref(),
+                request.membership.as_deref().unwrap_or_default(),
+                &merged_groups,
+            );
+            membership_heads.record(peer, request.membership_heads.as_deref());
 
+            let descriptors = build_descriptors(storage.as_ref(), node_identity, our_entity_id);
+            let (membership, heads) = build_membership(
+                storage.as_ref(),
+                descriptors.as_deref(),
+                membership_heads.of(&peer),
+            );
             let resp = GroupExchangeResponse {
-                groups: our_groups.to_vec(),
-                descriptors: build_descriptors(storage.as_ref(), node_identity, our_entity_id),
+                groups: our_groups
+                    .iter()
+                    .filter(|g| advertised(storage.as_ref(), g, our_entity_id))
+                    .cloned()
+                    .collect(),
+                membership,
+                membership_heads: heads,
+                descriptors,
+                sent_at: Some(cordelia_replication::format_timestamp(chrono::Utc::now())),
             };
             let _ = swarm
                 .behaviour_mut()
@@ -998,11 +2061,14 @@ fn handle_behaviour_event(
                     request_id,
                     response,
                 },
+            peer,
             ..
         }) => {
+            timer.finish("group_exchange", request_id, true);
+            report_peer_clock(event_tx, peer, response.sent_at.as_deref());
             // Merge incoming descriptors from peer response (R4-030)
             if let Some(ref descs) = response.descriptors {
-                let result = merge_descriptors(storage.as_ref(), descs);
+                let result = merge_descriptors(storage.as_ref(), hlc, descs);
                 if !result.upserted.is_empty() {
                     tracing::info!(
                         merged = result.upserted.len(),
@@ -1013,6 +2079,12 @@ fn handle_behaviour_event(
                 }
                 tombstoned_groups.extend(result.tombstoned);
             }
+            member_changes = merge_membership(
+                storage.as_ref(),
+                response.membership.as_deref().unwrap_or_default(),
+                &merged_groups,
+            );
+            membership_heads.record(peer, response.membership_heads.as_deref());
 
             if let Some(tx) = pending_group_exchange.remove(&request_id) {
                 let _ = tx.send(Ok(response));
@@ -1024,6 +2096,7 @@ fn handle_behaviour_event(
             ..
         }) => {
             tracing::warn!(error = %error, "net: group exchange request failed");
+            timer.finish("group_exchange", request_id, false);
             if let Some(tx) = pending_group_exchange.remove(&request_id) {
                 let _ = tx.send(Err(error.to_string()));
             }
@@ -1031,10 +2104,12 @@ fn handle_behaviour_event(
 
         // Memory push outbound failure (fire-and-forget, no pending channel)
         CordeliaBehaviourEvent::MemoryPush(request_response::Event::OutboundFailure {
+            request_id,
             error,
             ..
         }) => {
             tracing::warn!(error = %error, "net: push outbound failure");
+            timer.finish("push", request_id, false);
         }
 
         // Log inbound failures (peer failed to respond to our request)
@@ -1059,6 +2134,7 @@ fn handle_behaviour_event(
     MergeResult {
         upserted: merged_groups,
         tombstoned: tombstoned_groups,
+        members: member_changes,
     }
 }
 
@@ -1066,7 +2142,20 @@ fn handle_behaviour_event(
 // Inbound request handlers
 // ============================================================================
 
-fn handle_sync_request(storage: &Arc<dyn Storage>, req: &SyncRequest) -> SyncResponse {
+fn handle_sync_request(
+    storage: &Arc<dyn Storage>,
+    req: &SyncRequest,
+    our_entity_id: &str,
+) -> SyncResponse {
+    // emcon: answer as if we held nothing for the group
+    if !Posture::of(storage.as_ref(), &req.group_id, our_entity_id).serves() {
+        tracing::debug!(group = req.group_id, "net: emcon, not serving sync");
+        return SyncResponse {
+            items: Vec::new(),
+            has_more: false,
+        };
+    }
+
     let items = storage
         .list_group_items(&req.group_id, req.since.as_deref(), req.limit)
         .unwrap_or_default();
@@ -1082,6 +2171,7 @@ fn handle_sync_request(storage: &Arc<dyn Storage>, req: &SyncRequest) -> SyncRes
             updated_at: h.updated_at,
             author_id: h.author_id,
             is_deletion: h.is_deletion,
+            expires_at: h.expires_at,
         })
         .collect();
 
@@ -1091,11 +2181,30 @@ fn handle_sync_request(storage: &Arc<dyn Storage>, req: &SyncRequest) -> SyncRes
     }
 }
 
-fn handle_fetch_request(storage: &Arc<dyn Storage>, req: &FetchRequest) -> FetchResponse {
+fn handle_fetch_request(
+    storage: &Arc<dyn Storage>,
+    req: &FetchRequest,
+    our_entity_id: &str,
+) -> FetchResponse {
     let mut items = Vec::new();
+    let now = chrono::Utc::now();
+    let mut serves: HashMap<String, bool> = HashMap::new();
 
     for id in &req.item_ids {
         if let Ok(Some(row)) = storage.read_l2_item(id) {
+            // Never serve expired items, even if GC has not run yet
+            if cordelia_replication::is_expired(row.expires_at.as_deref(), now) {
+                continue;
+            }
+            // Nor items of groups we are in under emcon
+            if let Some(gid) = &row.group_id {
+                let serving = *serves
+                    .entry(gid.clone())
+                    .or_insert_with(|| Posture::of(storage.as_ref(), gid, our_entity_id).serves());
+                if !serving {
+                    continue;
+                }
+            }
             items.push(FetchedItem {
                 item_id: row.id,
                 item_type: row.item_type,
@@ -1107,6 +2216,11 @@ fn handle_fetch_request(storage: &Arc<dyn Storage>, req: &FetchRequest) -> Fetch
                 parent_id: row.parent_id,
                 is_copy: row.is_copy,
                 updated_at: row.updated_at,
+                expires_at: row.expires_at,
+                author_pubkey: row.author_pubkey,
+                author_signature: row.author_signature,
+                hlc: row.hlc,
+                ancestry: row.ancestry,
             });
         }
     }
@@ -1115,38 +2229,52 @@ fn handle_fetch_request(storage: &Arc<dyn Storage>, req: &FetchRequest) -> Fetch
 }
 
 fn handle_push_request(
+    engine: &ReplicationEngine,
     storage: &Arc<dyn Storage>,
     req: &MemoryPushRequest,
     our_groups: &[String],
     relay_accepts: Option<&dyn Fn(&str) -> bool>,
-) -> PushAck {
-    let engine = ReplicationEngine::new(
-        cordelia_replication::ReplicationConfig::default(),
-        String::new(),
-    );
-
+    change_feed: &ChangeFeed,
+) -> (PushAck, PeerContribution) {
     let mut stored = 0u32;
     let mut rejected = 0u32;
+    let mut contribution = PeerContribution::default();
 
     for item in &req.items {
+        let known = storage
+            .read_l2_item_meta(&item.item_id)
+            .ok()
+            .flatten()
+            .is_some();
         match engine.on_receive(storage.as_ref(), item, our_groups, relay_accepts) {
             ReceiveOutcome::Stored => {
                 stored += 1;
+                contribution.items_stored += 1;
+                if !known {
+                    contribution.first_deliveries += 1;
+                }
                 tracing::debug!(
                     item_id = &item.item_id,
                     group = &item.group_id,
                     "push: stored replicated item"
                 );
+                change_feed.publish(storage.as_ref(), Change::replicated(item));
+            }
+            ReceiveOutcome::Conflict => {
+                stored += 1;
+                contribution.items_stored += 1;
+                change_feed.publish(storage.as_ref(), Change::conflict(item));
             }
             ReceiveOutcome::Duplicate => {
                 tracing::debug!(item_id = &item.item_id, "push: duplicate, skipped");
             }
             ReceiveOutcome::Rejected(reason) => {
                 rejected += 1;
+                contribution.items_rejected += 1;
                 tracing::warn!(item_id = &item.item_id, reason, "push: rejected");
             }
         }
     }
 
-    PushAck { stored, rejected }
+    (PushAck { stored, rejected }, contribution)
 }
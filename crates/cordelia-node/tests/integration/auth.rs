@@ -0,0 +1,359 @@
+//! Device token auth tests -- scopes, group limits and revocation.
+
+use std::time::Duration;
+
+use crate::harness::{TestMesh, TestNode};
+
+async fn post_as(
+    node: &TestNode,
+    token: &str,
+    path: &str,
+    body: serde_json::Value,
+) -> (u16, serde_json::Value) {
+    let resp = reqwest::Client::new()
+        .post(format!("http://{}{}", node.api_addr, path))
+        .header("Authorization", format!("Bearer {token}"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    let status = resp.status().as_u16();
+    let text = resp.text().await.unwrap();
+    let val = serde_json::from_str(&text).unwrap_or(serde_json::json!({ "_raw": text }));
+    (status, val)
+}
+
+async fn register(node: &TestNode, device_id: &str, token: &str, scopes: &str) {
+    let (status, resp) = node
+        .api_post_raw(
+            "/api/v1/devices/register",
+            serde_json::json!({
+                "device_id": device_id,
+                "entity_id": "russell",
+                "auth_token_hash": hex::encode(ring::digest::digest(&ring::digest::SHA256, token.as_bytes())),
+                "scopes": scopes,
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "register {device_id}: {resp}");
+}
+
+/// Device tokens authenticate with their scopes; revocation is immediate.
+#[tokio::test]
+async fn test_device_token_scopes() {
+    let mesh = TestMesh::new(1, vec![]).await.unwrap();
+    let node = &mesh.nodes[0];
+
+    for g in ["team-a", "team-b"] {
+        node.api_create_group(g, g, r#"{"broadcast_eagerness":"chatty"}"#)
+            .await
+            .unwrap();
+    }
+    node.api_write_item("a-1", "entity", b"{\"v\":1}", "team-a")
+        .await
+        .unwrap();
+    node.api_write_item("b-1", "entity", b"{\"v\":1}", "team-b")
+        .await
+        .unwrap();
+
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/devices/register",
+            serde_json::json!({
+                "device_id": "bad", "entity_id": "russell",
+                "auth_token_hash": "x", "scopes": "read root",
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 400, "unknown scope must be rejected");
+
+    register(node, "reader-a", "tok-reader-a", "read group:team-a").await;
+    register(node, "writer", "tok-writer", "write").await;
+
+    // Unknown token
+    let (status, _) = post_as(node, "tok-nope", "/api/v1/status", serde_json::json!({})).await;
+    assert_eq!(status, 401);
+
+    // Group-limited reader: sees only team-a
+    let (status, resp) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/groups/list",
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, 200);
+    let ids: Vec<&str> = resp["groups"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|g| g["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["team-a"]);
+    let (status, _) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/l2/read",
+        serde_json::json!({ "item_id": "a-1" }),
+    )
+    .await;
+    assert_eq!(status, 200);
+    let (status, _) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/l2/read",
+        serde_json::json!({ "item_id": "b-1" }),
+    )
+    .await;
+    assert_eq!(status, 403);
+    let (status, _) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/l2/write",
+        serde_json::json!({ "item_id": "a-2", "type": "entity", "data": {}, "meta": { "group_id": "team-a" } }),
+    )
+    .await;
+    assert_eq!(status, 403, "read scope cannot write");
+    let (status, _) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/diagnostics",
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, 403, "group-limited token has no node-wide access");
+    let (status, resp) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/status",
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(resp["groups"], serde_json::json!(["team-a"]));
+
+    // Writer: can write items, cannot manage groups or devices
+    let (status, _) = post_as(
+        node,
+        "tok-writer",
+        "/api/v1/l2/write",
+        serde_json::json!({ "item_id": "b-2", "type": "entity", "data": {}, "meta": { "group_id": "team-b" } }),
+    )
+    .await;
+    assert_eq!(status, 200);
+    let (status, _) = post_as(
+        node,
+        "tok-writer",
+        "/api/v1/groups/delete",
+        serde_json::json!({ "group_id": "team-b" }),
+    )
+    .await;
+    assert_eq!(status, 403);
+
+    // last_seen_at recorded
+    let (_, resp) = node
+        .api_post_raw(
+            "/api/v1/devices/list",
+            serde_json::json!({ "entity_id": "russell" }),
+        )
+        .await
+        .unwrap();
+    let writer = resp["devices"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["device_id"] == "writer")
+        .unwrap()
+        .clone();
+    assert!(writer["last_seen_at"].is_string());
+    assert_eq!(writer["scopes"], "write");
+
+    // Open an event stream as the reader, then revoke: stream ends, token rejected
+    let mut stream = reqwest::Client::new()
+        .get(format!("http://{}/api/v1/events", node.api_addr))
+        .header("Authorization", "Bearer tok-reader-a")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(stream.status().as_u16(), 200);
+
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/devices/revoke",
+            serde_json::json!({ "entity_id": "russell", "device_id": "reader-a" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+
+    let ended = tokio::time::timeout(Duration::from_secs(10), async {
+        while let Ok(Some(_)) = stream.chunk().await {}
+    })
+    .await;
+    assert!(ended.is_ok(), "event stream should close on revocation");
+
+    let (status, _) = post_as(
+        node,
+        "tok-reader-a",
+        "/api/v1/groups/list",
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, 401);
+
+    mesh.shutdown_all().await;
+}
+
+/// Group roles bound what a token can do within a group; denials are 403.
+#[tokio::test]
+async fn test_group_roles_enforced() {
+    let mesh = TestMesh::new(1, vec!["roles-g".into()]).await.unwrap();
+    let node = &mesh.nodes[0];
+
+    // Node's entity is the owner; add a viewer and a member with their own devices
+    for (entity, role) in [("vera", "viewer"), ("mia", "member")] {
+        let (status, resp) = node
+            .api_post_raw(
+                "/api/v1/groups/add_member",
+                serde_json::json!({ "group_id": "roles-g", "entity_id": entity, "role": role }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, 200, "add {entity}: {resp}");
+    }
+    for (device, entity) in [("vera-dev", "vera"), ("mia-dev", "mia")] {
+        let (status, _) = node
+            .api_post_raw(
+                "/api/v1/devices/register",
+                serde_json::json!({
+                    "device_id": device,
+                    "entity_id": entity,
+                    "auth_token_hash": hex::encode(ring::digest::digest(&ring::digest::SHA256, format!("tok-{device}").as_bytes())),
+                    "scopes": "admin",
+                }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, 200);
+    }
+
+    let write = |id: &str| {
+        serde_json::json!({
+            "item_id": id, "type": "entity", "data": {},
+            "meta": { "visibility": "group", "group_id": "roles-g" },
+        })
+    };
+
+    // Viewer cannot write, even with an admin-scoped token
+    let (status, _) = post_as(node, "tok-vera-dev", "/api/v1/l2/write", write("v-1")).await;
+    assert_eq!(status, 403);
+
+    // Member can write, but not administer or delete the group
+    let (status, _) = post_as(node, "tok-mia-dev", "/api/v1/l2/write", write("m-1")).await;
+    assert_eq!(status, 200);
+    let (status, _) = post_as(
+        node,
+        "tok-mia-dev",
+        "/api/v1/groups/update_posture",
+        serde_json::json!({ "group_id": "roles-g", "entity_id": "vera", "posture": "silent" }),
+    )
+    .await;
+    assert_eq!(status, 403);
+    let (status, _) = post_as(
+        node,
+        "tok-mia-dev",
+        "/api/v1/groups/update_posture",
+        serde_json::json!({ "group_id": "roles-g", "entity_id": "mia", "posture": "silent" }),
+    )
+    .await;
+    assert_eq!(status, 200, "member can set its own posture");
+    let (status, _) = post_as(
+        node,
+        "tok-mia-dev",
+        "/api/v1/groups/create",
+        serde_json::json!({ "group_id": "roles-g", "name": "x", "culture": "{}" }),
+    )
+    .await;
+    assert_eq!(status, 403, "member cannot change culture");
+    let (status, _) = post_as(
+        node,
+        "tok-mia-dev",
+        "/api/v1/groups/delete",
+        serde_json::json!({ "group_id": "roles-g" }),
+    )
+    .await;
+    assert_eq!(status, 403);
+
+    // Owner (node entity) can do all of it
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/groups/add_member",
+            serde_json::json!({ "group_id": "roles-g", "entity_id": "vera", "role": "admin" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    let (status, _) = post_as(node, "tok-vera-dev", "/api/v1/l2/write", write("v-2")).await;
+    assert_eq!(status, 200, "promoted viewer can write");
+    let (status, _) = post_as(
+        node,
+        "tok-vera-dev",
+        "/api/v1/groups/delete",
+        serde_json::json!({ "group_id": "roles-g" }),
+    )
+    .await;
+    assert_eq!(status, 403, "only owners delete groups");
+
+    // Admins cannot grant owner or admin, or demote the owner; refused
+    // changes leave the local rows untouched
+    let owner = node
+        .storage
+        .list_members("roles-g")
+        .unwrap()
+        .into_iter()
+        .find(|m| m.role == "owner")
+        .unwrap()
+        .entity_id;
+    for (entity, role) in [
+        ("mia", "owner"),
+        ("mia", "admin"),
+        (owner.as_str(), "viewer"),
+    ] {
+        let (status, _) = post_as(
+            node,
+            "tok-vera-dev",
+            "/api/v1/groups/add_member",
+            serde_json::json!({ "group_id": "roles-g", "entity_id": entity, "role": role }),
+        )
+        .await;
+        assert_eq!(status, 403, "admin sets {entity} to {role}");
+    }
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/groups/add_member",
+            serde_json::json!({ "group_id": "roles-g", "entity_id": "mia", "role": "owner" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 403, "owner is only transferred");
+    let role_of = |entity: &str| {
+        node.storage
+            .get_membership("roles-g", entity)
+            .unwrap()
+            .map(|m| m.role)
+    };
+    assert_eq!(role_of("mia").as_deref(), Some("member"));
+    assert_eq!(role_of(&owner).as_deref(), Some("owner"));
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/groups/delete",
+            serde_json::json!({ "group_id": "roles-g" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+
+    mesh.shutdown_all().await;
+}
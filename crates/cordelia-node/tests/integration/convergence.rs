@@ -37,6 +37,10 @@ async fn test_staggered_startup() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -76,6 +80,93 @@ async fn test_staggered_startup() {
     node_b.shutdown().await;
 }
 
+/// A restarted node with no bootnodes rejoins through its peer book, and
+/// saves the peers it knows on shutdown.
+#[tokio::test]
+async fn test_rejoin_from_peer_book() {
+    let node_a = TestNodeBuilder::new("node-a")
+        .groups(vec!["g1".into()])
+        .build()
+        .await
+        .unwrap();
+
+    let node_b = TestNodeBuilder::new("node-b")
+        .groups(vec!["g1".into()])
+        .peer_book(vec![cordelia_storage::PeerBookRow {
+            peer_id: node_a.peer_id.to_string(),
+            addrs: vec![node_a.listen_addr.to_string()],
+            groups: vec!["g1".into()],
+            is_relay: true,
+            rtt_ms: None,
+            disconnect_count: 0,
+            ban_escalation: 0,
+            banned_until: None,
+            ban_reason: None,
+            last_seen: chrono::Utc::now().to_rfc3339(),
+        }])
+        .build()
+        .await
+        .unwrap();
+
+    node_b
+        .wait_hot_peers(1, Duration::from_secs(60))
+        .await
+        .unwrap();
+    node_a
+        .wait_connected_peers(1, Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    let storage_b = node_b.storage.clone();
+    node_b.shutdown().await;
+    let book = storage_b.load_peer_book().unwrap();
+    assert!(
+        book.iter().any(|p| p.peer_id == node_a.peer_id.to_string()),
+        "peer book after shutdown: {book:?}"
+    );
+
+    node_a.shutdown().await;
+}
+
+/// A peer whose first known address is dead is still reached on its
+/// second (all addresses are dialled, not just the first).
+#[tokio::test]
+async fn test_dial_falls_back_to_second_addr() {
+    let node_a = TestNodeBuilder::new("node-a")
+        .groups(vec!["g1".into()])
+        .build()
+        .await
+        .unwrap();
+
+    // Nothing listens on port 1
+    let dead = "/ip4/127.0.0.1/tcp/1".to_string();
+    let node_b = TestNodeBuilder::new("node-b")
+        .groups(vec!["g1".into()])
+        .peer_book(vec![cordelia_storage::PeerBookRow {
+            peer_id: node_a.peer_id.to_string(),
+            addrs: vec![dead, node_a.listen_addr.to_string()],
+            groups: vec!["g1".into()],
+            is_relay: true,
+            rtt_ms: None,
+            disconnect_count: 0,
+            ban_escalation: 0,
+            banned_until: None,
+            ban_reason: None,
+            last_seen: chrono::Utc::now().to_rfc3339(),
+        }])
+        .build()
+        .await
+        .unwrap();
+
+    node_b
+        .wait_hot_peers(1, Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    node_b.shutdown().await;
+    node_a.shutdown().await;
+}
+
 /// Stable N-node mesh, then add one more node. All connected.
 /// Base mesh size from TEST_NODE_COUNT (default 2).
 #[test]
@@ -95,6 +186,10 @@ fn test_late_joiner() {
             .governor_targets(GovernorTargets {
                 hot_min: 1,
                 hot_max: total.max(2),
+                hot_per_group: 1,
+                max_per_subnet: 2,
+                max_per_share_source: 10,
+                protected_peers: 2,
                 warm_min: 1,
                 warm_max: total.max(2),
                 cold_max: total * 2,
@@ -148,6 +243,10 @@ fn test_rolling_restart() {
             .governor_targets(GovernorTargets {
                 hot_min: 1,
                 hot_max: n.max(2),
+                hot_per_group: 1,
+                max_per_subnet: 2,
+                max_per_share_source: 10,
+                protected_peers: 2,
                 warm_min: 1,
                 warm_max: n.max(2),
                 cold_max: n * 2,
@@ -0,0 +1,70 @@
+//! Group culture tests -- strict schema on create, canonical form on the wire.
+
+use std::time::{Duration, Instant};
+
+use crate::harness::{scaled_timeout, TestNodeBuilder};
+
+/// Typos are rejected; accepted cultures are stored canonically and reach
+/// peers byte-for-byte, alongside the harness's migrated legacy culture.
+#[tokio::test]
+async fn test_culture_validated_and_canonical() {
+    let node = TestNodeBuilder::new("culture-a")
+        .groups(vec!["legacy".into()])
+        .build()
+        .await
+        .unwrap();
+    let create = |group: &'static str, culture: &'static str| {
+        node.api_post_raw(
+            "/api/v1/groups/create",
+            serde_json::json!({ "group_id": group, "name": group, "culture": culture }),
+        )
+    };
+
+    for bad in [
+        r#"{"broadcast_eagerness":"chatyy"}"#,
+        r#"{"broadcast_eagerness":"chatty","colour":"red"}"#,
+        r#"{"ttl_default":0}"#,
+        r#"{"version":2}"#,
+    ] {
+        let (status, _) = create("bad", bad).await.unwrap();
+        assert_eq!(status, 400, "{bad}");
+    }
+    let (status, resp) = create(
+        "typed",
+        r#"{ "ttl_default": 3600, "broadcast_eagerness": "moderate" }"#,
+    )
+    .await
+    .unwrap();
+    assert_eq!(status, 200, "{resp}");
+    let canonical = r#"{"broadcast_eagerness":"chatty","ttl_default":3600,"version":1}"#;
+    let group = node.api_read_group("typed").await.unwrap();
+    assert_eq!(group["group"]["culture"], canonical);
+    // Harness seeds a bare "chatty" culture, migrated on load
+    let group = node.api_read_group("legacy").await.unwrap();
+    assert_eq!(
+        group["group"]["culture"],
+        r#"{"broadcast_eagerness":"chatty","version":1}"#
+    );
+
+    let peer = TestNodeBuilder::new("culture-b")
+        .bootnode(node.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+    let deadline = Instant::now() + scaled_timeout(2, 30);
+    let culture = loop {
+        let culture = peer
+            .api_read_group("typed")
+            .await
+            .ok()
+            .and_then(|g| g["group"]["culture"].as_str().map(String::from));
+        if culture.is_some() || Instant::now() > deadline {
+            break culture;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    };
+    assert_eq!(culture.as_deref(), Some(canonical));
+
+    node.shutdown().await;
+    peer.shutdown().await;
+}
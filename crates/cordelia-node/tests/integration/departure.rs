@@ -0,0 +1,202 @@
+//! Departure policy tests -- what happens to items when a group is left.
+
+use std::time::{Duration, Instant};
+
+use crate::harness::{scaled_timeout, TestMesh, TestNodeBuilder};
+
+/// groups/delete enforces each group's departure_policy and reports it.
+#[tokio::test]
+async fn test_departure_policy_on_local_leave() {
+    let mesh = TestMesh::new(1, vec![]).await.unwrap();
+    let node = &mesh.nodes[0];
+
+    node.api_create_group(
+        "g-purge",
+        "Purge",
+        r#"{"broadcast_eagerness":"chatty","departure_policy":"purge"}"#,
+    )
+    .await
+    .unwrap();
+    node.api_create_group("g-retain", "Retain", r#"{"broadcast_eagerness":"chatty"}"#)
+        .await
+        .unwrap();
+
+    node.api_write_item("purge-1", "entity", b"{\"v\":1}", "g-purge")
+        .await
+        .unwrap();
+    node.api_write_item("retain-1", "entity", b"{\"v\":1}", "g-retain")
+        .await
+        .unwrap();
+
+    for group in ["g-purge", "g-retain"] {
+        let (status, resp) = node
+            .api_post_raw(
+                "/api/v1/groups/delete",
+                serde_json::json!({ "group_id": group }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, 200, "delete {group} failed: {resp}");
+        assert_eq!(resp["departure"]["items_affected"], 1);
+    }
+
+    // purge: item is gone
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/l2/read",
+            serde_json::json!({ "item_id": "purge-1" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 404, "purged item should be gone");
+
+    // retain (default): item readable but read-only
+    let read = node.api_read_item("retain-1").await.unwrap();
+    assert_eq!(read["meta"]["group_id"], "g-retain");
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/l2/write",
+            serde_json::json!({
+                "item_id": "retain-1",
+                "type": "entity",
+                "data": { "v": 2 },
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 409, "retained item should be read-only");
+    let (status, _) = node
+        .api_post_raw(
+            "/api/v1/l2/delete",
+            serde_json::json!({ "item_id": "retain-1" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 409, "retained item should not be deletable");
+
+    let (status, resp) = node
+        .api_post_raw("/api/v1/groups/departures", serde_json::json!({}))
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    let departures = resp["departures"].as_array().unwrap();
+    assert_eq!(departures.len(), 2);
+    assert_eq!(departures[0]["group_id"], "g-retain");
+    assert_eq!(departures[0]["policy"], "retain");
+    assert_eq!(departures[1]["policy"], "purge");
+    assert_eq!(departures[1]["reason"], "local_leave");
+
+    mesh.shutdown_all().await;
+}
+
+/// A member leaves a group it does not own: the departure stays on its
+/// node, and neither the owner's copy nor later owner updates undo it.
+#[tokio::test]
+async fn test_member_leave() {
+    let owner = TestNodeBuilder::new("leave-a")
+        .groups(vec!["crew".into()])
+        .build()
+        .await
+        .unwrap();
+    let member = TestNodeBuilder::new("leave-b")
+        .bootnode(owner.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+
+    // The member learns the owner-signed descriptor
+    let deadline = Instant::now() + scaled_timeout(2, 30);
+    loop {
+        let group = member.api_read_group("crew").await.ok();
+        if group.is_some_and(|g| g["group"]["signature"].is_string()) {
+            break;
+        }
+        assert!(Instant::now() < deadline, "descriptor did not replicate");
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    // Deleting needs the owner key; leaving does not
+    let (status, _) = member
+        .api_post_raw(
+            "/api/v1/groups/delete",
+            serde_json::json!({ "group_id": "crew" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 403);
+    let (status, resp) = member
+        .api_post_raw(
+            "/api/v1/groups/leave",
+            serde_json::json!({ "group_id": "crew" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "leave failed: {resp}");
+    assert_eq!(resp["departure"]["reason"], "member_leave");
+    let (status, _) = owner
+        .api_post_raw(
+            "/api/v1/groups/leave",
+            serde_json::json!({ "group_id": "crew" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 409, "the owner cannot leave");
+
+    // A newer owner descriptor neither resurrects the group on the member
+    // nor is the member's unsigned tombstone taken up by the owner
+    let admins = serde_json::json!(["ab".repeat(32)]);
+    let (status, resp) = owner
+        .api_post_raw(
+            "/api/v1/groups/set_admins",
+            serde_json::json!({ "group_id": "crew", "admins": admins }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "set_admins failed: {resp}");
+    // A fresh node picks it up and hands it to the member on connect
+    let courier = TestNodeBuilder::new("leave-c")
+        .bootnode(owner.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+    let deadline = Instant::now() + scaled_timeout(2, 30);
+    loop {
+        let group = courier.api_read_group("crew").await.ok();
+        if group.is_some_and(|g| g["group"]["admins"] == admins) {
+            break;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "updated descriptor did not replicate"
+        );
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    let (status, _) = member
+        .api_post_raw(
+            "/api/v1/peers/connect",
+            serde_json::json!({ "addr": courier.listen_addr.to_string() }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    member
+        .wait_connected_peers(2, scaled_timeout(2, 30))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    let (status, _) = member
+        .api_post_raw(
+            "/api/v1/groups/read",
+            serde_json::json!({ "group_id": "crew" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 404, "left group must stay left");
+    let group = owner.api_read_group("crew").await.unwrap();
+    assert_eq!(group["group"]["admins"], admins);
+
+    owner.shutdown().await;
+    member.shutdown().await;
+    courier.shutdown().await;
+}
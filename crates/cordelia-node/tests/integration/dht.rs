@@ -0,0 +1,76 @@
+//! Kademlia DHT -- finding the relays that carry a group.
+
+use std::time::Duration;
+
+use cordelia_governor::GovernorTargets;
+use cordelia_node::config::{NodeRole, RelayPosture};
+
+use crate::harness::{TestNode, TestNodeBuilder};
+
+/// Whether `node` is connected to `peer`.
+async fn is_connected(node: &TestNode, peer: &TestNode) -> bool {
+    let peers = node.api_peers().await.unwrap();
+    let peer_id = peer.peer_id.to_string();
+    peers["peers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|p| p["node_id"] == peer_id.as_str())
+}
+
+/// A personal node bootstrapped from a relay that does not carry its group
+/// finds a second relay that does through the DHT, without waiting for
+/// peer-share.
+#[tokio::test]
+async fn test_personal_node_finds_group_relay_via_dht() {
+    let targets = GovernorTargets {
+        hot_min: 1,
+        hot_max: 5,
+        warm_min: 2,
+        warm_max: 5,
+        cold_max: 10,
+        churn_fraction: 0.0,
+        ..Default::default()
+    };
+    let entry = TestNodeBuilder::new("entry-relay")
+        .role(NodeRole::Relay)
+        .relay_posture(RelayPosture::Transparent)
+        .governor_targets(targets.clone())
+        .build()
+        .await
+        .unwrap();
+    let carrier = TestNodeBuilder::new("group-relay")
+        .role(NodeRole::Relay)
+        .relay_posture(RelayPosture::Transparent)
+        .groups(vec!["dht-group".into()])
+        .governor_targets(targets.clone())
+        .bootnode(entry.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+    let personal = TestNodeBuilder::new("personal")
+        .role(NodeRole::Personal)
+        .groups(vec!["dht-group".into()])
+        .governor_targets(targets)
+        .bootnode(entry.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+
+    // Found before the first peer-share round could have introduced it
+    let first_peer_share =
+        cordelia_governor::TICK_INTERVAL * cordelia_protocol::PEER_DISCOVERY_TICKS as u32;
+    let deadline = tokio::time::Instant::now() + first_peer_share - Duration::from_secs(5);
+    while !is_connected(&personal, &carrier).await {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "group relay not found: {}",
+            personal.api_peers().await.unwrap()
+        );
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    personal.shutdown().await;
+    carrier.shutdown().await;
+    entry.shutdown().await;
+}
@@ -0,0 +1,80 @@
+//! Change feed tests -- local and replicated changes reach subscribers.
+
+use std::time::Duration;
+
+use cordelia_api::events::ChangeKind;
+
+use crate::harness::{scaled_timeout, TestMesh};
+
+/// A write on node 0 shows up as item_replicated on node 1's feed.
+#[tokio::test]
+async fn test_change_feed_replicated_item() {
+    let groups = vec!["feed-group".into()];
+    let mesh = TestMesh::new(2, groups).await.unwrap();
+    mesh.wait_full_mesh(scaled_timeout(2, 60)).await.unwrap();
+
+    let mut sub = mesh.nodes[1].change_feed.subscribe(None);
+
+    mesh.nodes[0]
+        .api_write_item("feed-item-001", "entity", b"feed-blob", "feed-group")
+        .await
+        .unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(30), async {
+        loop {
+            let e = sub.live.recv().await.unwrap();
+            if e.item_id.as_deref() == Some("feed-item-001") {
+                return e;
+            }
+        }
+    })
+    .await
+    .expect("node-1 did not publish replicated item");
+    assert_eq!(event.kind, ChangeKind::ItemReplicated);
+    assert_eq!(event.group_id.as_deref(), Some("feed-group"));
+
+    mesh.shutdown_all().await;
+}
+
+/// GET /api/v1/events streams SSE and requires the bearer token.
+#[tokio::test]
+async fn test_events_sse_stream() {
+    let mesh = TestMesh::new(1, vec![]).await.unwrap();
+    let node = &mesh.nodes[0];
+    let url = format!("http://{}/api/v1/events?item_type=entity", node.api_addr);
+    let client = reqwest::Client::new();
+
+    let unauth = client.get(&url).send().await.unwrap();
+    assert_eq!(unauth.status().as_u16(), 401);
+
+    node.api_create_group("sse-group", "SSE", r#"{"broadcast_eagerness":"chatty"}"#)
+        .await
+        .unwrap();
+
+    let mut resp = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", node.bearer_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+
+    node.api_write_item("sse-item-001", "entity", b"{\"v\":1}", "sse-group")
+        .await
+        .unwrap();
+
+    let mut body = String::new();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !body.contains("sse-item-001") {
+            let chunk = resp.chunk().await.unwrap().expect("stream ended");
+            body.push_str(&String::from_utf8_lossy(&chunk));
+        }
+    })
+    .await
+    .expect("no SSE event received");
+    assert!(body.contains("event: item_written"), "got: {body}");
+    // group_updated for sse-group was filtered out by item_type
+    assert!(!body.contains("group_updated"), "got: {body}");
+
+    mesh.shutdown_all().await;
+}
@@ -9,9 +9,10 @@ use std::time::Duration;
 
 use cordelia_api::{AppState, ReplicationStats};
 use cordelia_crypto::NodeIdentity;
-use cordelia_governor::{DialPolicy, Governor, GovernorTargets};
+use cordelia_governor::{Clock, DialPolicy, Governor, GovernorTargets, SystemClock};
 use cordelia_node::config::{BootnodeEntry, NodeRole, RelayPosture};
 use cordelia_node::{governor_task, peer_pool, replication_task, swarm_task, StorageClone};
+use cordelia_replication::hlc::HlcClock;
 use cordelia_replication::{ReplicationConfig, ReplicationEngine};
 use cordelia_storage::SqliteStorage;
 use libp2p::{Multiaddr, PeerId};
@@ -59,7 +60,7 @@ pub struct TestNode {
     pub listen_addr: Multiaddr,
     pub bearer_token: String,
     pub storage: Arc<dyn cordelia_storage::Storage>,
-    pub shared_groups: Arc<RwLock<Vec<String>>>,
+    pub change_feed: Arc<cordelia_api::events::ChangeFeed>,
     cmd_tx: tokio::sync::mpsc::Sender<swarm_task::SwarmCommand>,
     shutdown_tx: broadcast::Sender<()>,
     _tempdir: tempfile::TempDir,
@@ -210,7 +211,7 @@ impl TestNode {
             "group_id": id,
             "name": name,
             "culture": culture,
-            "security_policy": "standard",
+            "security_policy": "{}",
         });
         self.api_post("/api/v1/groups/create", body).await
     }
@@ -263,6 +264,9 @@ pub struct TestNodeBuilder {
     replication_config: ReplicationConfig,
     relay_posture: Option<RelayPosture>,
     relay_blocked_groups: HashSet<String>,
+    peer_book: Vec<cordelia_storage::PeerBookRow>,
+    clock: Arc<dyn Clock>,
+    dht: bool,
 }
 
 #[allow(dead_code)]
@@ -276,6 +280,10 @@ impl TestNodeBuilder {
             governor_targets: GovernorTargets {
                 hot_min: 1,
                 hot_max: 10,
+                hot_per_group: 1,
+                max_per_subnet: 2,
+                max_per_share_source: 10,
+                protected_peers: 2,
                 warm_min: 1,
                 warm_max: 10,
                 cold_max: 20,
@@ -286,9 +294,13 @@ impl TestNodeBuilder {
                 sync_interval_taciturn_secs: 15,
                 tombstone_retention_days: 7,
                 max_batch_size: 100,
+                max_clock_skew_secs: 300,
             },
             relay_posture: None,
             relay_blocked_groups: HashSet::new(),
+            peer_book: vec![],
+            clock: Arc::new(SystemClock),
+            dht: true,
         }
     }
 
@@ -332,6 +344,24 @@ impl TestNodeBuilder {
         self
     }
 
+    /// Peers saved by a previous run (as if restarting with this database).
+    pub fn peer_book(mut self, peers: Vec<cordelia_storage::PeerBookRow>) -> Self {
+        self.peer_book = peers;
+        self
+    }
+
+    /// Time source for the governor and replication timers.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Run no DHT, so the node only meets peers via bootnodes and peer-share.
+    pub fn without_dht(mut self) -> Self {
+        self.dht = false;
+        self
+    }
+
     pub async fn build(self) -> anyhow::Result<TestNode> {
         // Generate identity
         let identity = NodeIdentity::generate()?;
@@ -347,6 +377,8 @@ impl TestNodeBuilder {
         let storage: Arc<dyn cordelia_storage::Storage> =
             Arc::new(SqliteStorage::create_new(&db_path)?);
 
+        storage.save_peer_book(&self.peer_book)?;
+
         // Seed L1 entry for this node's entity (needed for group_members FK)
         storage.write_l1(&self.name, b"{}")?;
 
@@ -355,6 +387,8 @@ impl TestNodeBuilder {
             storage.write_group(group_id, group_id, "chatty", "standard")?;
             storage.add_member(group_id, &self.name, "owner")?;
         }
+        let node_pubkey = hex::encode(identity.public_key());
+        cordelia_replication::culture::migrate_stored(storage.as_ref(), &node_pubkey)?;
         let shared_groups = Arc::new(RwLock::new(self.groups.clone()));
 
         // Bearer token
@@ -371,11 +405,19 @@ impl TestNodeBuilder {
 
         // Build swarm on ephemeral port
         let listen_addr: Multiaddr = "/ip4/127.0.0.1/tcp/0".parse()?;
-        let mut swarm = swarm_task::build_swarm(keypair, listen_addr)
+        // All on loopback: AutoNAT must accept non-global addresses
+        let swarm_opts = swarm_task::SwarmOptions {
+            relay_server: self.role == NodeRole::Relay,
+            autonat_only_global: false,
+            mdns: false,
+            dht: swarm_task::dht_mode(self.role).filter(|_| self.dht),
+        };
+        let mut swarm = swarm_task::build_swarm(keypair, listen_addr, swarm_opts)
             .map_err(|e| anyhow::anyhow!("swarm build failed: {e}"))?;
 
         // Wait for NewListenAddr to get actual port
         use libp2p::futures::StreamExt;
+        #[allow(clippy::collapsible_match)]
         let actual_addr = loop {
             if let Some(event) = swarm.next().await {
                 if let libp2p::swarm::SwarmEvent::NewListenAddr { address, .. } = event {
@@ -391,9 +433,36 @@ impl TestNodeBuilder {
         let api_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
         let api_addr = api_listener.local_addr()?.to_string();
 
+        let change_feed = Arc::new(cordelia_api::events::ChangeFeed::default());
+        let protocol_metrics = Arc::new(cordelia_api::metrics::ProtocolMetrics::new());
+        let hlc = Arc::new(HlcClock::new(
+            identity.node_id_hex(),
+            self.replication_config.max_clock_skew_secs,
+        ));
+
+        // Governor (personal nodes dial as in production)
+        let dial_policy = if self.role == NodeRole::Personal {
+            DialPolicy::RelaysOnly
+        } else {
+            DialPolicy::All
+        };
+        let governor = Arc::new(tokio::sync::Mutex::new(Governor::with_clock(
+            self.governor_targets.clone(),
+            self.groups.clone(),
+            dial_policy,
+            self.clock.clone(),
+        )));
+
+        // Channels
+        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel::<swarm_task::SwarmCommand>(256);
+        let (event_tx, _) = broadcast::channel::<swarm_task::SwarmEvent2>(256);
+
         // Build API state
         let pool_for_count = pool.clone();
         let pool_for_list = pool.clone();
+        let governor_for_admin = governor.clone();
+        let pool_for_admin = pool.clone();
+        let cmd_tx_for_admin = cmd_tx.clone();
         let state = Arc::new(AppState {
             storage: Box::new(StorageClone(storage.clone())),
             node_id: identity.node_id_hex(),
@@ -410,20 +479,35 @@ impl TestNodeBuilder {
                 let pool = pool_for_list.clone();
                 Box::pin(async move { pool.peer_details().await })
             })),
+            group_coverage_fn: None,
+            peer_admin_fn: Some(Box::new(move |action| {
+                let governor = governor_for_admin.clone();
+                let pool = pool_for_admin.clone();
+                let cmd_tx = cmd_tx_for_admin.clone();
+                Box::pin(async move {
+                    governor_task::peer_admin(&governor, &pool, &cmd_tx, action).await
+                })
+            })),
             replication_stats: Some(repl_stats.clone()),
             bootstrap_sync: None,
+            change_feed: Some(change_feed.clone()),
+            governor_counts_fn: None,
+            protocol_metrics: Some(protocol_metrics.clone()),
+            device_revocations: tokio::sync::broadcast::channel(16).0,
+            signer: Some(cordelia_api::NodeSigner {
+                pubkey_hex: hex::encode(identity.public_key()),
+                sign: {
+                    let identity = identity.clone();
+                    Box::new(move |payload| identity.sign(payload))
+                },
+            }),
+            hlc: hlc.clone(),
         });
 
-        // Governor
-        let governor = Arc::new(tokio::sync::Mutex::new(Governor::with_dial_policy(
-            self.governor_targets.clone(),
-            self.groups.clone(),
-            DialPolicy::All,
-        )));
-
         // Replication engine
         let repl_engine =
-            ReplicationEngine::new(self.replication_config.clone(), self.name.clone());
+            ReplicationEngine::new(self.replication_config.clone(), self.name.clone())
+                .with_clock(hlc.clone());
 
         // Relay state
         let is_relay = self.role == NodeRole::Relay && self.relay_posture.is_some();
@@ -451,10 +535,6 @@ impl TestNodeBuilder {
                 None
             };
 
-        // Channels
-        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel::<swarm_task::SwarmCommand>(256);
-        let (event_tx, _) = broadcast::channel::<swarm_task::SwarmEvent2>(256);
-
         let mut handles = Vec::new();
 
         // Spawn swarm task
@@ -469,6 +549,11 @@ impl TestNodeBuilder {
             let relay_blocked = relay_blocked.clone();
             let node_identity = identity.clone();
             let entity_id = self.name.clone();
+            let change_feed = change_feed.clone();
+            let protocol_metrics = protocol_metrics.clone();
+            let repl_config = self.replication_config.clone();
+            let hlc = hlc.clone();
+            let relay_reservations = if role == NodeRole::Personal { 2 } else { 0 };
             handles.push(tokio::spawn(async move {
                 swarm_task::run_swarm_loop(
                     swarm,
@@ -483,6 +568,11 @@ impl TestNodeBuilder {
                     relay_blocked,
                     node_identity,
                     entity_id,
+                    change_feed,
+                    protocol_metrics,
+                    repl_config,
+                    hlc,
+                    relay_reservations,
                     shutdown,
                 )
                 .await;
@@ -505,6 +595,7 @@ impl TestNodeBuilder {
             let shared_groups = shared_groups.clone();
             let shutdown = shutdown_tx.subscribe();
             let relay_learned = relay_learned_groups.clone();
+            let storage = storage.clone();
             handles.push(tokio::spawn(async move {
                 governor_task::run_governor_loop(
                     governor,
@@ -515,6 +606,7 @@ impl TestNodeBuilder {
                     shared_groups,
                     relay_learned,
                     peer_id,
+                    storage,
                     shutdown,
                 )
                 .await;
@@ -532,6 +624,8 @@ impl TestNodeBuilder {
             let relay_learned = relay_learned_groups.clone();
             let relay_blocked = relay_blocked.clone();
             let (_, bootstrap_rx) = tokio::sync::mpsc::channel::<String>(32);
+            let change_feed = change_feed.clone();
+            let clock = self.clock.clone();
             handles.push(tokio::spawn(async move {
                 replication_task::run_replication_loop(
                     repl_engine,
@@ -546,6 +640,8 @@ impl TestNodeBuilder {
                     relay_learned,
                     relay_blocked,
                     bootstrap_rx,
+                    change_feed,
+                    clock,
                 )
                 .await;
             }));
@@ -572,7 +668,7 @@ impl TestNodeBuilder {
             listen_addr: actual_addr,
             bearer_token,
             storage,
-            shared_groups,
+            change_feed,
             cmd_tx,
             shutdown_tx,
             _tempdir: tempdir,
@@ -599,6 +695,10 @@ impl TestMesh {
                 .governor_targets(GovernorTargets {
                     hot_min: 1,
                     hot_max: n.max(2),
+                    hot_per_group: 1,
+                    max_per_subnet: 2,
+                    max_per_share_source: 10,
+                    protected_peers: 2,
                     warm_min: 1,
                     warm_max: n.max(2),
                     cold_max: n * 2,
@@ -634,6 +734,10 @@ impl TestMesh {
                 .governor_targets(GovernorTargets {
                     hot_min: 1,
                     hot_max: n.max(2),
+                    hot_per_group: 1,
+                    max_per_subnet: 2,
+                    max_per_share_source: 10,
+                    protected_peers: 2,
                     warm_min: 1,
                     warm_max: n.max(2),
                     cold_max: n * 2,
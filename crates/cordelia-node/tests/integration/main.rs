@@ -2,7 +2,18 @@
 //!
 //! Run with: cargo test --test integration -- --test-threads=1
 
+mod auth;
 mod backpressure;
 mod convergence;
+mod culture;
+mod departure;
+mod dht;
+mod events;
 mod harness;
+mod membership;
+mod metrics;
+mod nat;
+mod peers;
+mod posture;
 mod replication;
+mod security;
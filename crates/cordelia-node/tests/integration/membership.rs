@@ -0,0 +1,148 @@
+//! Membership log tests -- owner-signed membership ops replicate via GroupExchange.
+
+use std::time::{Duration, Instant};
+
+use crate::harness::{scaled_timeout, TestNodeBuilder};
+
+/// Members added, re-roled and removed on the owner's node reach a peer
+/// that only learns the group from its descriptor.
+#[tokio::test]
+async fn test_membership_log_replicates() {
+    let owner = TestNodeBuilder::new("roster-a")
+        .groups(vec!["roster".into()])
+        .build()
+        .await
+        .unwrap();
+
+    let post = |path: &'static str, body: serde_json::Value| owner.api_post_raw(path, body);
+    for body in [
+        serde_json::json!({ "group_id": "roster", "entity_id": "alice", "role": "admin" }),
+        serde_json::json!({ "group_id": "roster", "entity_id": "bob" }),
+        serde_json::json!({ "group_id": "roster", "entity_id": "carol" }),
+        // Existing member: recorded as a role change
+        serde_json::json!({ "group_id": "roster", "entity_id": "alice", "role": "viewer" }),
+    ] {
+        let (status, resp) = post("/api/v1/groups/add_member", body).await.unwrap();
+        assert_eq!(status, 200, "add_member failed: {resp}");
+        assert_eq!(resp["logged"], true);
+    }
+    let (status, resp) = post(
+        "/api/v1/groups/remove_member",
+        serde_json::json!({ "group_id": "roster", "entity_id": "carol" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(status, 200);
+    assert_eq!(resp["logged"], true);
+
+    // Peer holds no groups of its own: everything arrives via GroupExchange
+    let peer = TestNodeBuilder::new("roster-b")
+        .bootnode(owner.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let deadline = Instant::now() + scaled_timeout(2, 30);
+    let members = loop {
+        let group = peer.api_read_group("roster").await.unwrap();
+        let members = group["members"].as_array().cloned().unwrap_or_default();
+        if members.len() == 3 || Instant::now() > deadline {
+            break members;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    };
+    let role_of = |entity: &str| {
+        members
+            .iter()
+            .find(|m| m["entity_id"] == entity)
+            .map(|m| m["role"].as_str().unwrap().to_string())
+    };
+    assert_eq!(role_of("roster-a").as_deref(), Some("owner"), "{members:?}");
+    assert_eq!(role_of("alice").as_deref(), Some("viewer"));
+    assert_eq!(role_of("bob").as_deref(), Some("member"));
+    assert_eq!(role_of("carol"), None, "removed member must not replicate");
+
+    owner.shutdown().await;
+    peer.shutdown().await;
+}
+
+/// An owner hands the group to another node's key: peers accept the new
+/// owner through the signed handover chain, and the old owner becomes admin.
+#[tokio::test]
+async fn test_ownership_transfer_replicates() {
+    let owner = TestNodeBuilder::new("handover-a")
+        .groups(vec!["handover".into()])
+        .build()
+        .await
+        .unwrap();
+    let successor = TestNodeBuilder::new("handover-b").build().await.unwrap();
+    let status = successor.api_status().await.unwrap();
+    let successor_id = status["entity_id"].as_str().unwrap().to_string();
+    let successor_key = status["node_pubkey"].as_str().unwrap().to_string();
+    let admin_key = "ab".repeat(32);
+
+    let (status, resp) = owner
+        .api_post_raw(
+            "/api/v1/groups/set_admins",
+            serde_json::json!({ "group_id": "handover", "admins": [admin_key] }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "set_admins failed: {resp}");
+    let (status, resp) = owner
+        .api_post_raw(
+            "/api/v1/groups/transfer_owner",
+            serde_json::json!({
+                "group_id": "handover",
+                "new_owner_id": successor_id,
+                "new_owner_pubkey": successor_key,
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "transfer_owner failed: {resp}");
+
+    // The old owner no longer holds the owner key
+    let (status, _) = owner
+        .api_post_raw(
+            "/api/v1/groups/set_admins",
+            serde_json::json!({ "group_id": "handover", "admins": [] }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 403);
+    let group = owner.api_read_group("handover").await.unwrap();
+    let old_role = group["members"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|m| m["entity_id"] == "handover-a")
+        .map(|m| m["role"].clone());
+    assert_eq!(old_role, Some(serde_json::json!("admin")));
+
+    // A fresh peer only accepts the new owner via the signed handover chain
+    let observer = TestNodeBuilder::new("handover-c")
+        .bootnode(owner.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let deadline = Instant::now() + scaled_timeout(2, 30);
+    let group = loop {
+        let group = observer.api_read_group("handover").await.ok();
+        let done = group
+            .as_ref()
+            .is_some_and(|g| g["group"]["owner_pubkey"] == successor_key.as_str());
+        if done || Instant::now() > deadline {
+            break group.unwrap_or_default();
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    };
+    assert_eq!(group["group"]["owner_id"], successor_id.as_str(), "{group}");
+    assert_eq!(group["group"]["owner_pubkey"], successor_key.as_str());
+    assert_eq!(group["group"]["admins"], serde_json::json!([admin_key]));
+
+    owner.shutdown().await;
+    successor.shutdown().await;
+    observer.shutdown().await;
+}
@@ -0,0 +1,56 @@
+//! Prometheus /metrics endpoint tests.
+
+use crate::harness::{scaled_timeout, TestMesh};
+
+/// /metrics is authenticated on the main API and reflects replication.
+#[tokio::test]
+async fn test_metrics_endpoint() {
+    let groups = vec!["metrics-group".into()];
+    let mesh = TestMesh::new(2, groups).await.unwrap();
+    mesh.wait_full_mesh(scaled_timeout(2, 60)).await.unwrap();
+
+    mesh.nodes[0]
+        .api_write_item("metrics-item-001", "entity", b"blob", "metrics-group")
+        .await
+        .unwrap();
+    mesh.nodes[1]
+        .wait_item("metrics-item-001", scaled_timeout(2, 30))
+        .await
+        .unwrap();
+
+    let node = &mesh.nodes[0];
+    let url = format!("http://{}/metrics", node.api_addr);
+    let client = reqwest::Client::new();
+
+    let unauth = client.get(&url).send().await.unwrap();
+    assert_eq!(unauth.status().as_u16(), 401);
+
+    let resp = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", node.bearer_token))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+    assert!(resp.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain; version=0.0.4"));
+    let body = resp.text().await.unwrap();
+
+    assert!(body.contains("# TYPE cordelia_replication_items_pushed_total counter"));
+    assert!(body.contains("cordelia_peers{state=\"hot\"}"));
+    assert!(body.contains("cordelia_group_items{group_id=\"metrics-group\"} 1"));
+    assert!(
+        body.contains("cordelia_request_duration_seconds_bucket{protocol=\"push\",le=\"+Inf\"}")
+    );
+    let pushed: u64 = body
+        .lines()
+        .find_map(|l| l.strip_prefix("cordelia_request_duration_seconds_count{protocol=\"push\"} "))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(pushed >= 1, "push latency not recorded:\n{body}");
+
+    mesh.shutdown_all().await;
+}
@@ -0,0 +1,74 @@
+//! NAT traversal -- relay circuits and hole punching between personal nodes.
+
+use std::time::Duration;
+
+use cordelia_governor::GovernorTargets;
+use cordelia_node::config::{NodeRole, RelayPosture};
+
+use crate::harness::{TestNode, TestNodeBuilder};
+
+/// Whether `node` holds a direct (non-relayed) address for `peer`.
+async fn has_direct_addr(node: &TestNode, peer: &TestNode) -> bool {
+    let peers = node.api_peers().await.unwrap();
+    let peer_id = peer.peer_id.to_string();
+    peers["peers"].as_array().into_iter().flatten().any(|p| {
+        p["node_id"] == peer_id.as_str()
+            && p["addrs"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|a| a.as_str().is_some_and(|a| !a.contains("p2p-circuit")))
+    })
+}
+
+/// Two personal nodes only ever dial relays. Each holds a reservation with
+/// the relay, which shares the other's circuit address; they connect
+/// through it and hole punch a direct connection.
+#[tokio::test]
+async fn test_personal_nodes_hole_punch_via_relay() {
+    let targets = GovernorTargets {
+        hot_min: 1,
+        hot_max: 5,
+        warm_min: 2,
+        warm_max: 5,
+        cold_max: 10,
+        churn_fraction: 0.0,
+        ..Default::default()
+    };
+    let relay = TestNodeBuilder::new("relay")
+        .role(NodeRole::Relay)
+        .relay_posture(RelayPosture::Transparent)
+        .governor_targets(targets.clone())
+        .build()
+        .await
+        .unwrap();
+    let personal = |name: &str| {
+        TestNodeBuilder::new(name)
+            .role(NodeRole::Personal)
+            .groups(vec!["nat-group".into()])
+            .governor_targets(targets.clone())
+            .bootnode(relay.listen_addr.clone())
+            .build()
+    };
+    let a = personal("personal-a").await.unwrap();
+    let b = personal("personal-b").await.unwrap();
+
+    // AutoNAT boot delay + a discovery round + the punch itself
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(150);
+    loop {
+        if has_direct_addr(&a, &b).await || has_direct_addr(&b, &a).await {
+            break;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "no direct connection: a={} b={}",
+            a.api_peers().await.unwrap(),
+            b.api_peers().await.unwrap()
+        );
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    a.shutdown().await;
+    b.shutdown().await;
+    relay.shutdown().await;
+}
@@ -0,0 +1,153 @@
+//! Operator peer management -- connect, disconnect, ban, unban, pin.
+
+use std::time::Duration;
+
+use libp2p::PeerId;
+
+use crate::harness::{TestMesh, TestNode};
+
+async fn total_peers(node: &TestNode) -> u64 {
+    node.api_peers().await.unwrap()["total"]
+        .as_u64()
+        .unwrap_or(0)
+}
+
+/// Poll until the node has no connected peers.
+async fn wait_no_peers(node: &TestNode, timeout: Duration) {
+    let deadline = tokio::time::Instant::now() + timeout;
+    while total_peers(node).await > 0 {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "peer still connected: {}",
+            node.api_peers().await.unwrap()
+        );
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+}
+
+/// A banned peer is cut off at once and refused on redial until unbanned.
+#[tokio::test]
+async fn test_peer_admin_ban_and_reconnect() {
+    let mesh = TestMesh::new(2, vec!["g1".into()]).await.unwrap();
+    mesh.wait_full_mesh(Duration::from_secs(90)).await.unwrap();
+    let (a, b) = (&mesh.nodes[0], &mesh.nodes[1]);
+    let b_id = b.peer_id.to_string();
+
+    // Auth comes before validation; overlong bans are refused
+    let unauth = reqwest::Client::new()
+        .post(format!("http://{}/api/v1/peers/ban", a.api_addr))
+        .json(&serde_json::json!({ "peer_id": b_id, "reason": "" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unauth.status().as_u16(), 401);
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/ban",
+            serde_json::json!({ "peer_id": b_id, "reason": "x", "duration_secs": u64::MAX }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 400);
+
+    // Bad input and unknown peers
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/ban",
+            serde_json::json!({ "peer_id": "nope", "reason": "x" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 400);
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/ban",
+            serde_json::json!({ "peer_id": PeerId::random().to_string(), "reason": "x" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 404);
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/connect",
+            serde_json::json!({ "addr": "not-a-multiaddr" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 400);
+
+    let (status, resp) = a
+        .api_post_raw("/api/v1/peers/pin", serde_json::json!({ "peer_id": b_id }))
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "pin: {resp}");
+
+    // Ban: dropped immediately, and B's redials are refused
+    let (status, resp) = a
+        .api_post_raw(
+            "/api/v1/peers/ban",
+            serde_json::json!({ "peer_id": b_id, "reason": "misbehaving relay" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "ban: {resp}");
+    wait_no_peers(a, Duration::from_secs(10)).await;
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/disconnect",
+            serde_json::json!({ "peer_id": b_id }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 404, "banned peer is not connected");
+    b.api_post_raw(
+        "/api/v1/peers/connect",
+        serde_json::json!({ "addr": a.listen_addr.to_string() }),
+    )
+    .await
+    .unwrap();
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert_eq!(total_peers(a).await, 0, "banned peer got back in");
+
+    // Unban, then reconnect on request
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/unban",
+            serde_json::json!({ "peer_id": b_id }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/unban",
+            serde_json::json!({ "peer_id": b_id }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 404, "already unbanned");
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/connect",
+            serde_json::json!({ "addr": b.listen_addr.to_string() }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    a.wait_connected_peers(1, Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    // Operator disconnect
+    let (status, _) = a
+        .api_post_raw(
+            "/api/v1/peers/disconnect",
+            serde_json::json!({ "peer_id": b_id }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    wait_no_peers(a, Duration::from_secs(10)).await;
+
+    mesh.shutdown_all().await;
+}
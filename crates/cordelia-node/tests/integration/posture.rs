@@ -0,0 +1,63 @@
+//! Member posture tests -- silent/emcon suppress what a node emits.
+
+use std::time::Duration;
+
+use crate::harness::{scaled_timeout, TestMesh};
+
+/// A silent node stops pushing its writes; returning to active resumes.
+#[tokio::test]
+async fn test_silent_posture_suppresses_push() {
+    let groups = vec!["posture-group".into()];
+    let mesh = TestMesh::new(2, groups).await.unwrap();
+    mesh.wait_full_mesh(scaled_timeout(2, 60)).await.unwrap();
+    let (n0, n1) = (&mesh.nodes[0], &mesh.nodes[1]);
+
+    let set_posture = |posture: &'static str| {
+        n0.api_post_raw(
+            "/api/v1/groups/update_posture",
+            serde_json::json!({
+                "group_id": "posture-group",
+                "entity_id": "node-0",
+                "posture": posture,
+            }),
+        )
+    };
+
+    let (status, _) = set_posture("silent").await.unwrap();
+    assert_eq!(status, 200);
+    let group = n0.api_read_group("posture-group").await.unwrap();
+    assert_eq!(group["local_posture"]["posture"], "silent");
+    assert_eq!(group["local_posture"]["eager_push"], false);
+    assert_eq!(group["local_posture"]["serves_sync"], true);
+    assert_eq!(group["local_posture"]["pulls_sync"], true);
+    assert_eq!(group["local_posture"]["advertised"], true);
+
+    n0.api_write_item("silent-1", "entity", b"{\"v\":1}", "posture-group")
+        .await
+        .unwrap();
+    // Well inside the 60s anti-entropy interval: only a push could deliver it
+    assert!(
+        n1.wait_item("silent-1", Duration::from_secs(3))
+            .await
+            .is_err(),
+        "silent node must not push"
+    );
+
+    let (status, _) = set_posture("emcon").await.unwrap();
+    assert_eq!(status, 200);
+    let group = n0.api_read_group("posture-group").await.unwrap();
+    assert_eq!(group["local_posture"]["serves_sync"], false);
+    assert_eq!(group["local_posture"]["pulls_sync"], false);
+    assert_eq!(group["local_posture"]["advertised"], false);
+
+    let (status, _) = set_posture("active").await.unwrap();
+    assert_eq!(status, 200);
+    n0.api_write_item("active-1", "entity", b"{\"v\":1}", "posture-group")
+        .await
+        .unwrap();
+    n1.wait_item("active-1", scaled_timeout(2, 30))
+        .await
+        .expect("active node pushes again");
+
+    mesh.shutdown_all().await;
+}
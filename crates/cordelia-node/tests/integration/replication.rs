@@ -3,6 +3,7 @@
 use std::collections::HashSet;
 use std::time::Duration;
 
+use cordelia_api::events::ChangeKind;
 use cordelia_governor::GovernorTargets;
 use cordelia_node::config::{NodeRole, RelayPosture};
 
@@ -58,6 +59,10 @@ async fn test_item_replication_moderate_maps_to_chatty() {
         .governor_targets(GovernorTargets {
             hot_min: 1,
             hot_max: 5,
+            hot_per_group: 1,
+            max_per_subnet: 2,
+            max_per_share_source: 10,
+            protected_peers: 2,
             warm_min: 1,
             warm_max: 5,
             cold_max: 10,
@@ -74,6 +79,10 @@ async fn test_item_replication_moderate_maps_to_chatty() {
         .governor_targets(GovernorTargets {
             hot_min: 1,
             hot_max: 5,
+            hot_per_group: 1,
+            max_per_subnet: 2,
+            max_per_share_source: 10,
+            protected_peers: 2,
             warm_min: 1,
             warm_max: 5,
             cold_max: 10,
@@ -102,6 +111,37 @@ async fn test_item_replication_moderate_maps_to_chatty() {
         .await
         .unwrap();
 
+    // The pushed copy still verifies: the signature covers the version
+    // and timestamp the item travelled with
+    let row = node_b
+        .storage
+        .read_l2_item("item-mod-001")
+        .unwrap()
+        .unwrap();
+    let received = cordelia_protocol::messages::FetchedItem {
+        item_id: row.id,
+        item_type: row.item_type,
+        encrypted_blob: row.data,
+        checksum: row.checksum.unwrap_or_default(),
+        author_id: row.author_id.unwrap_or_default(),
+        group_id: row.group_id.unwrap_or_default(),
+        key_version: row.key_version as u32,
+        parent_id: row.parent_id,
+        is_copy: row.is_copy,
+        updated_at: row.updated_at,
+        expires_at: row.expires_at,
+        author_pubkey: row.author_pubkey,
+        author_signature: row.author_signature,
+        hlc: row.hlc,
+        ancestry: row.ancestry,
+    };
+    assert!(received.hlc.is_some());
+    assert!(cordelia_crypto::verify_ed25519_hex(
+        &received.signing_payload(),
+        received.author_pubkey.as_deref().unwrap(),
+        received.author_signature.as_deref().unwrap(),
+    ));
+
     node_a.shutdown().await;
     node_b.shutdown().await;
 }
@@ -112,6 +152,10 @@ async fn test_group_scoped_replication() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -189,6 +233,10 @@ async fn test_blind_relay_forwarding_transparent() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -278,6 +326,10 @@ async fn test_dynamic_relay_scoping() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -381,6 +433,10 @@ async fn test_blocked_groups() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -399,9 +455,11 @@ async fn test_blocked_groups() {
         .await
         .unwrap();
 
+    // No DHT: A and B must only meet through the relay
     let node_a = TestNodeBuilder::new("blk-a")
         .role(NodeRole::Relay)
         .groups(vec!["g1".into()])
+        .without_dht()
         .governor_targets(targets.clone())
         .bootnode(relay.listen_addr.clone())
         .build()
@@ -411,6 +469,7 @@ async fn test_blocked_groups() {
     let node_b = TestNodeBuilder::new("blk-b")
         .role(NodeRole::Relay)
         .groups(vec!["g1".into()])
+        .without_dht()
         .governor_targets(targets)
         .bootnode(relay.listen_addr.clone())
         .build()
@@ -736,6 +795,10 @@ async fn test_group_descriptor_replication() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -835,6 +898,10 @@ async fn test_group_descriptor_via_relay() {
     let targets = GovernorTargets {
         hot_min: 1,
         hot_max: 5,
+        hot_per_group: 1,
+        max_per_subnet: 2,
+        max_per_share_source: 10,
+        protected_peers: 2,
         warm_min: 1,
         warm_max: 5,
         cold_max: 10,
@@ -914,3 +981,142 @@ async fn test_group_descriptor_via_relay() {
     node_a.shutdown().await;
     node_b.shutdown().await;
 }
+
+/// A concurrent edit is kept as a conflict sibling, listed by l2/conflicts,
+/// and cleared everywhere by a merge write that resolves it.
+#[tokio::test]
+async fn test_concurrent_edit_conflict_and_merge() {
+    let groups = vec!["conflict-group".into()];
+    let mesh = TestMesh::new(2, groups).await.unwrap();
+    mesh.wait_full_mesh(scaled_timeout(2, 60)).await.unwrap();
+    let (node_a, node_b) = (&mesh.nodes[0], &mesh.nodes[1]);
+    let mut sub = node_b.change_feed.subscribe(None);
+
+    // A records B's key, so B's signature vouches for the merge's ancestry
+    let key = node_b.api_status().await.unwrap()["node_pubkey"].clone();
+    let (status, resp) = node_a
+        .api_post_raw(
+            "/api/v1/groups/add_member",
+            serde_json::json!({
+                "group_id": "conflict-group",
+                "entity_id": "node-1",
+                "pubkey": key,
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200, "add_member failed: {resp}");
+
+    // B's own version, never seen by A
+    node_b
+        .storage
+        .write_l2_item(&cordelia_storage::L2ItemWrite {
+            id: "conflict-item-001".into(),
+            item_type: "entity".into(),
+            data: br#"{"v":"b"}"#.to_vec(),
+            owner_id: None,
+            visibility: "group".into(),
+            group_id: Some("conflict-group".into()),
+            author_id: Some("test".into()),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: Some("2026-01-01T00:00:00.000Z".into()),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: Some(vec![]),
+        })
+        .unwrap();
+
+    // A writes the same item without knowing B's version
+    node_a
+        .api_write_item(
+            "conflict-item-001",
+            "entity",
+            br#"{"v":"a"}"#,
+            "conflict-group",
+        )
+        .await
+        .unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(30), async {
+        loop {
+            let e = sub.live.recv().await.unwrap();
+            if e.item_id.as_deref() == Some("conflict-item-001") {
+                return e;
+            }
+        }
+    })
+    .await
+    .expect("node-b did not publish the conflict");
+    assert_eq!(event.kind, ChangeKind::ItemConflict);
+
+    let (status, listed) = node_b
+        .api_post_raw(
+            "/api/v1/l2/conflicts",
+            serde_json::json!({ "item_id": "conflict-item-001" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    let conflicts = listed["conflicts"].as_array().unwrap();
+    assert_eq!(conflicts.len(), 1, "{listed}");
+    assert_eq!(conflicts[0]["current"]["data"]["v"], "a");
+    let siblings = conflicts[0]["siblings"].as_array().unwrap();
+    assert_eq!(siblings.len(), 1);
+    assert_eq!(siblings[0]["data"]["v"], "b");
+    let sibling = siblings[0]["checksum"].as_str().unwrap().to_string();
+
+    // Resolving something that is not a sibling is refused
+    let merge = |resolves: Vec<String>| {
+        serde_json::json!({
+            "item_id": "conflict-item-001",
+            "type": "entity",
+            "data": {"v": "ab"},
+            "meta": {
+                "visibility": "group",
+                "group_id": "conflict-group",
+                "resolves": resolves,
+            }
+        })
+    };
+    let (status, _) = node_b
+        .api_post_raw("/api/v1/l2/write", merge(vec!["not-a-sibling".into()]))
+        .await
+        .unwrap();
+    assert_eq!(status, 400);
+
+    // Merge on B: the sibling is cleared and A fast-forwards to the merge
+    let (status, _) = node_b
+        .api_post_raw("/api/v1/l2/write", merge(vec![sibling]))
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    let (_, listed) = node_b
+        .api_post_raw("/api/v1/l2/conflicts", serde_json::json!({}))
+        .await
+        .unwrap();
+    assert_eq!(listed["conflicts"].as_array().unwrap().len(), 0);
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(30);
+    loop {
+        let item = node_a.api_read_item("conflict-item-001").await.unwrap();
+        if item["data"]["v"] == "ab" {
+            break;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "node-a did not receive the merge: {item}"
+        );
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    let (_, listed) = node_a
+        .api_post_raw("/api/v1/l2/conflicts", serde_json::json!({}))
+        .await
+        .unwrap();
+    assert_eq!(listed["conflicts"].as_array().unwrap().len(), 0);
+
+    mesh.shutdown_all().await;
+}
@@ -0,0 +1,95 @@
+//! Group security policy tests -- validated on create, enforced on write,
+//! carried in descriptors, and able to keep a group out of GroupExchange.
+
+use std::time::{Duration, Instant};
+
+use crate::harness::{scaled_timeout, TestNodeBuilder};
+
+/// A policy narrows local writes and replicates with the descriptor; a
+/// group with `exchange_visible: false` never reaches peers.
+#[tokio::test]
+async fn test_security_policy_enforced_and_replicated() {
+    let node = TestNodeBuilder::new("policy-a").build().await.unwrap();
+    let create = |group: &'static str, policy: &'static str| {
+        node.api_post_raw(
+            "/api/v1/groups/create",
+            serde_json::json!({
+                "group_id": group,
+                "name": group,
+                "culture": r#"{"broadcast_eagerness":"chatty"}"#,
+                "security_policy": policy,
+            }),
+        )
+    };
+
+    let (status, _) = create("bad", r#"{"max_item_bytes":999999999}"#)
+        .await
+        .unwrap();
+    assert_eq!(status, 400, "max_item_bytes above the era limit");
+    let (status, _) = create("bad", r#"{"relay_storabel":false}"#).await.unwrap();
+    assert_eq!(status, 400, "unknown field");
+
+    let policy = r#"{"allowed_item_types":["entity"],"max_item_bytes":64,"relay_storable":false}"#;
+    let (status, resp) = create("policed", policy).await.unwrap();
+    assert_eq!(status, 200, "{resp}");
+    let (status, _) = create("hidden", r#"{"exchange_visible":false}"#)
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+
+    let write = |item_id: &str, item_type: &str, data: serde_json::Value| {
+        node.api_post_raw(
+            "/api/v1/l2/write",
+            serde_json::json!({
+                "item_id": item_id,
+                "type": item_type,
+                "data": data,
+                "meta": { "group_id": "policed", "visibility": "group" },
+            }),
+        )
+    };
+    let (status, _) = write("ok-1", "entity", serde_json::json!({ "v": 1 }))
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+    let (status, _) = write("type-1", "session", serde_json::json!({ "v": 1 }))
+        .await
+        .unwrap();
+    assert_eq!(status, 400, "type not allowed by policy");
+    let (status, _) = write(
+        "big-1",
+        "entity",
+        serde_json::json!({ "v": "x".repeat(100) }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(status, 413, "larger than the group limit");
+
+    // Peer holds no groups: it learns both only via GroupExchange
+    let peer = TestNodeBuilder::new("policy-b")
+        .bootnode(node.listen_addr.clone())
+        .build()
+        .await
+        .unwrap();
+    let deadline = Instant::now() + scaled_timeout(2, 30);
+    let group = loop {
+        let group = peer.api_read_group("policed").await.unwrap();
+        if group["group"].is_object() || Instant::now() > deadline {
+            break group;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    };
+    assert_eq!(group["group"]["security_policy"], policy, "{group}");
+
+    let (status, _) = peer
+        .api_post_raw(
+            "/api/v1/groups/read",
+            serde_json::json!({ "group_id": "hidden" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, 404, "hidden group must not be exchanged");
+
+    node.shutdown().await;
+    peer.shutdown().await;
+}
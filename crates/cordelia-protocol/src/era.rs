@@ -71,6 +71,15 @@ pub struct ProtocolEra {
     pub sync_interval_taciturn_secs: u64,
     /// Days to retain tombstones before garbage collection.
     pub tombstone_retention_days: u32,
+    /// How far into the future an item's `updated_at` may be (seconds)
+    /// before it is rejected. Bounds the LWW advantage of a fast clock.
+    pub max_clock_skew_secs: u64,
+    /// Checksums of replaced versions an item carries (newest first), used
+    /// to tell a successor version from a concurrent edit.
+    pub max_item_ancestry: usize,
+    /// Concurrent versions kept per item until a merge resolves them; the
+    /// oldest (by HLC) are evicted past this.
+    pub max_item_siblings: usize,
     /// Push retry backoff schedule in seconds (up to 4 steps).
     /// Items are re-pushed with increasing delay; no explicit ack.
     pub push_retry_backoffs: [u64; 4],
@@ -136,6 +145,9 @@ pub const ERA_0: ProtocolEra = ProtocolEra {
     sync_interval_moderate_secs: 300,
     sync_interval_taciturn_secs: 900,
     tombstone_retention_days: 7,
+    max_clock_skew_secs: 300,
+    max_item_ancestry: 16,
+    max_item_siblings: 8,
     push_retry_backoffs: [5, 15, 60, 300], // aggressive early, converges to anti-entropy
     push_retry_count: 4,
 
@@ -51,6 +51,15 @@ pub const SYNC_INTERVAL_TACITURN_SECS: u64 = ERA_0.sync_interval_taciturn_secs;
 /// Tombstone retention in days (sourced from current era).
 pub const TOMBSTONE_RETENTION_DAYS: u32 = ERA_0.tombstone_retention_days;
 
+/// Maximum future clock skew for item timestamps (sourced from current era).
+pub const MAX_CLOCK_SKEW_SECS: u64 = ERA_0.max_clock_skew_secs;
+
+/// Maximum item ancestry length (sourced from current era).
+pub const MAX_ITEM_ANCESTRY: usize = ERA_0.max_item_ancestry;
+
+/// Maximum conflict siblings kept per item (sourced from current era).
+pub const MAX_ITEM_SIBLINGS: usize = ERA_0.max_item_siblings;
+
 /// Group exchange interval in governor ticks (sourced from current era).
 pub const GROUP_EXCHANGE_TICKS: u64 = ERA_0.group_exchange_ticks;
 
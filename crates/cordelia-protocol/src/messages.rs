@@ -59,6 +59,9 @@ pub struct ItemHeader {
     pub updated_at: String,
     pub author_id: String,
     pub is_deletion: bool,
+    /// RFC 3339 expiry. Expired items are garbage-collected and never re-fetched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
 }
 
 // ============================================================================
@@ -89,6 +92,84 @@ pub struct FetchedItem {
     pub parent_id: Option<String>,
     pub is_copy: bool,
     pub updated_at: String,
+    /// RFC 3339 expiry, bound into `checksum` and the author signature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// Hex-encoded Ed25519 key of the author's node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_pubkey: Option<String>,
+    /// Hex-encoded Ed25519 signature by `author_pubkey` over `signing_payload()`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_signature: Option<String>,
+    /// Hybrid logical clock version (`<physical ms>-<counter>-<node>`). LWW
+    /// orders on it; absent (older peers) = derived from `updated_at`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hlc: Option<String>,
+    /// Checksums of the versions this one replaced, newest first (at most
+    /// `MAX_ITEM_ANCESTRY`). Empty for a new item; absent from older peers,
+    /// whose versions fall back to plain LWW.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ancestry: Option<Vec<String>>,
+}
+
+impl FetchedItem {
+    /// Canonical payload for the author signature (see `ItemSigningFields`).
+    pub fn signing_payload(&self) -> Vec<u8> {
+        ItemSigningFields {
+            item_id: &self.item_id,
+            group_id: &self.group_id,
+            item_type: &self.item_type,
+            checksum: &self.checksum,
+            updated_at: &self.updated_at,
+            expires_at: self.expires_at.as_deref(),
+            hlc: self.hlc.as_deref(),
+            ancestry: self.ancestry.as_deref(),
+        }
+        .payload()
+    }
+}
+
+/// The fields of an item its author signature covers.
+#[derive(Debug, Clone, Copy)]
+pub struct ItemSigningFields<'a> {
+    pub item_id: &'a str,
+    pub group_id: &'a str,
+    pub item_type: &'a str,
+    pub checksum: &'a str,
+    pub updated_at: &'a str,
+    pub expires_at: Option<&'a str>,
+    pub hlc: Option<&'a str>,
+    pub ancestry: Option<&'a [String]>,
+}
+
+impl ItemSigningFields<'_> {
+    /// Canonical payload: id, group, type, checksum and updated_at, plus
+    /// the expiry, the HLC and the ancestry when set. The checksum is
+    /// unkeyed, so the expiry is signed in its own right: a relay that
+    /// extends it and recomputes the checksum still breaks the signature.
+    /// Signing the version stops a relay deciding LWW outcomes, and signing
+    /// the ancestry stops it claiming a version supersedes ours. The author
+    /// is bound by the key, which receivers look up in the group's
+    /// membership log.
+    pub fn payload(&self) -> Vec<u8> {
+        let mut payload = format!(
+            "{}\n{}\n{}\n{}\n{}",
+            self.item_id, self.group_id, self.item_type, self.checksum, self.updated_at
+        );
+        if let Some(expires_at) = self.expires_at {
+            payload.push_str("\nexpires_at=");
+            payload.push_str(expires_at);
+        }
+        if let Some(hlc) = self.hlc {
+            payload.push_str("\nhlc=");
+            payload.push_str(hlc);
+        }
+        if let Some(ancestry) = self.ancestry {
+            payload.push_str("\nancestry=");
+            payload.push_str(&ancestry.join(","));
+        }
+        payload.into_bytes()
+    }
 }
 
 // ============================================================================
@@ -129,9 +210,68 @@ pub struct GroupDescriptor {
     /// Hex-encoded Ed25519 public key of the owner.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner_pubkey: Option<String>,
-    /// Hex-encoded Ed25519 signature over canonical(id + culture + updated_at).
+    /// Hex-encoded Ed25519 signature over canonical(id + culture + updated_at [+ admins]).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signature: Option<String>,
+    /// Hex-encoded public keys allowed to sign culture updates besides the
+    /// owner. Only an owner-signed descriptor may change the set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admins: Option<Vec<String>>,
+    /// Key that made `signature`, when not the owner (an admin, or the
+    /// outgoing owner on a handover).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_pubkey: Option<String>,
+    /// Ownership handovers, oldest first. Lets peers holding an earlier
+    /// owner key verify the chain to `owner_pubkey`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfers: Option<Vec<OwnershipTransfer>>,
+    /// Security policy JSON. Absent = `{}` (no restrictions).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_policy: Option<String>,
+    /// Hybrid logical clock version; LWW orders on it. Absent (older peers,
+    /// groups last changed before HLCs) = derived from `updated_at`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hlc: Option<String>,
+}
+
+/// Owner-signed handover of a group to a new owner key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnershipTransfer {
+    /// Hex-encoded public key of the outgoing owner (the signer).
+    pub from_pubkey: String,
+    pub to_owner_id: String,
+    /// Hex-encoded public key of the new owner.
+    pub to_pubkey: String,
+    pub transferred_at: String,
+    /// Hex-encoded Ed25519 signature by `from_pubkey` over `signing_payload()`.
+    pub signature: String,
+    /// HLC of the descriptor carrying the handover, the only descriptor the
+    /// outgoing key may sign. Absent from older peers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hlc: Option<String>,
+    /// Membership log heads at the handover: the outgoing key's entries
+    /// count only if they are these or precede them. Meaningful only when
+    /// `hlc` is set (older transfers bound entries by `transferred_at`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub log_heads: Vec<String>,
+}
+
+impl OwnershipTransfer {
+    /// Canonical payload for signing: group + every field except the
+    /// signature, `hlc` and `log_heads` appended when `hlc` is set.
+    pub fn signing_payload(&self, group_id: &str) -> Vec<u8> {
+        let mut payload = format!(
+            "{}\n{}\n{}\n{}\n{}",
+            group_id, self.from_pubkey, self.to_owner_id, self.to_pubkey, self.transferred_at
+        );
+        if let Some(hlc) = &self.hlc {
+            payload.push_str(&format!(
+                "\nhlc={hlc}\nlog_heads={}",
+                self.log_heads.join(",")
+            ));
+        }
+        payload.into_bytes()
+    }
 }
 
 /// Sentinel culture value indicating a group has been deleted (tombstone).
@@ -157,10 +297,124 @@ impl GroupDescriptor {
         self.checksum == Self::compute_checksum(&self.id, &self.culture)
     }
 
-    /// Canonical payload for signing: id + culture + updated_at.
+    /// Canonical payload for signing: id + culture + updated_at, plus the
+    /// admin set when non-empty, the security policy when not `{}` and the
+    /// HLC when present (unchanged for descriptors without any of them).
+    pub fn signing_payload(&self) -> Vec<u8> {
+        let mut payload = format!("{}\n{}\n{}", self.id, self.culture, self.updated_at);
+        if let Some(admins) = self.admins.as_ref().filter(|a| !a.is_empty()) {
+            payload.push_str("\nadmins=");
+            payload.push_str(&admins.join(","));
+        }
+        if let Some(policy) = self.policy() {
+            payload.push_str("\nsecurity_policy=");
+            payload.push_str(policy);
+        }
+        if let Some(hlc) = &self.hlc {
+            payload.push_str("\nhlc=");
+            payload.push_str(hlc);
+        }
+        payload.into_bytes()
+    }
+
+    /// The security policy, if any other than `{}`.
+    pub fn policy(&self) -> Option<&str> {
+        self.security_policy.as_deref().filter(|p| *p != "{}")
+    }
+
+    /// Key that made `signature`: `signer_pubkey`, else the owner's.
+    pub fn signing_key(&self) -> Option<&str> {
+        self.signer_pubkey
+            .as_deref()
+            .or(self.owner_pubkey.as_deref())
+    }
+
+    /// Whether the descriptor carries a signature made with the owner key
+    /// (not an admin's or the outgoing owner's). Deletion and resurrection
+    /// of a group require this.
+    pub fn is_owner_signed(&self) -> bool {
+        self.signature.is_some()
+            && self.owner_pubkey.is_some()
+            && self.signing_key() == self.owner_pubkey.as_deref()
+    }
+}
+
+/// Membership log operations.
+pub const MEMBERSHIP_OP_ADD: &str = "add";
+pub const MEMBERSHIP_OP_REMOVE: &str = "remove";
+pub const MEMBERSHIP_OP_ROLE: &str = "role";
+
+/// Signed membership operation for a group (add, remove, role change).
+///
+/// Entries form an append-only per-group log, exchanged alongside
+/// descriptors and replayed in causal order (`prev`, then `created_at`
+/// and `entry_id` between concurrent entries) on every node. Signed by
+/// the group owner, or by an admin whose node key an earlier entry
+/// recorded as `member_pubkey`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MembershipEntry {
+    pub group_id: String,
+    /// `add`, `remove` or `role`.
+    pub op: String,
+    pub entity_id: String,
+    /// Role granted (`add` and `role` only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Hex-encoded Ed25519 public key of the member's node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_pubkey: Option<String>,
+    /// ISO 8601 timestamp set by the signer.
+    pub created_at: String,
+    pub signer_id: String,
+    /// Hex-encoded Ed25519 public key of the signer.
+    pub signer_pubkey: String,
+    /// Hex-encoded Ed25519 signature over `signing_payload()`.
+    pub signature: String,
+    /// IDs of the log's head entries (those no other entry follows) when
+    /// the signer wrote this one. Empty for a group's first entry and from
+    /// older peers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prev: Vec<String>,
+}
+
+impl MembershipEntry {
+    /// Canonical payload for signing: every field except the signature,
+    /// `prev` appended when set.
     pub fn signing_payload(&self) -> Vec<u8> {
-        format!("{}\n{}\n{}", self.id, self.culture, self.updated_at).into_bytes()
+        let mut payload = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.group_id,
+            self.op,
+            self.entity_id,
+            self.role.as_deref().unwrap_or(""),
+            self.member_pubkey.as_deref().unwrap_or(""),
+            self.created_at,
+            self.signer_id,
+            self.signer_pubkey
+        );
+        if !self.prev.is_empty() {
+            payload.push_str("\nprev=");
+            payload.push_str(&self.prev.join(","));
+        }
+        payload.into_bytes()
     }
+
+    /// Content-addressed entry ID: SHA-256 of the signing payload.
+    pub fn entry_id(&self) -> String {
+        use sha2::{Digest, Sha256};
+        hex::encode(Sha256::digest(self.signing_payload()))
+    }
+}
+
+/// Summary of the sender's membership log for one group, so the receiver
+/// only sends back entries the sender lacks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MembershipHead {
+    pub group_id: String,
+    /// Number of entries in the log.
+    pub entries: u32,
+    /// Latest `created_at` in the log.
+    pub latest: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,6 +424,17 @@ pub struct GroupExchange {
     /// Old peers ignore this field; new peers populate it alongside `groups`.
     #[serde(default)]
     pub descriptors: Option<Vec<GroupDescriptor>>,
+    /// Optional signed membership log entries for the advertised groups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub membership: Option<Vec<MembershipEntry>>,
+    /// Heads of the sender's membership logs for the advertised groups.
+    /// Old peers omit it and are sent whole logs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub membership_heads: Option<Vec<MembershipHead>>,
+    /// Sender's clock (RFC 3339) when the message was sent, for peer clock
+    /// offset estimation. Old peers omit it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -178,6 +443,17 @@ pub struct GroupExchangeResponse {
     /// Optional group metadata descriptors (ERA_0 v1.1 extension).
     #[serde(default)]
     pub descriptors: Option<Vec<GroupDescriptor>>,
+    /// Optional signed membership log entries for the advertised groups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub membership: Option<Vec<MembershipEntry>>,
+    /// Heads of the sender's membership logs for the advertised groups.
+    /// Old peers omit it and are sent whole logs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub membership_heads: Option<Vec<MembershipHead>>,
+    /// Sender's clock (RFC 3339) when the message was sent, for peer clock
+    /// offset estimation. Old peers omit it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
 }
 
 // ============================================================================
@@ -222,6 +498,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-01-29T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         let json = serde_json::to_string(&item).unwrap();
@@ -231,6 +512,28 @@ mod tests {
         assert_eq!(decoded.encrypted_blob, vec![1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_fetched_item_expiry_backward_compat() {
+        // Old peers omit expires_at entirely
+        let old_json = r#"{"item_id":"x","item_type":"entity","encrypted_blob":"AQ==","checksum":"abc","author_id":"r","group_id":"g1","key_version":1,"parent_id":null,"is_copy":false,"updated_at":"2026-01-29T00:00:00Z"}"#;
+        let decoded: FetchedItem = serde_json::from_str(old_json).unwrap();
+        assert!(decoded.expires_at.is_none());
+
+        // No expiry -> field not serialized (old peers see identical wire format)
+        let json = serde_json::to_string(&decoded).unwrap();
+        assert!(!json.contains("expires_at"));
+        assert!(decoded.hlc.is_none());
+        assert!(!json.contains("hlc"));
+
+        let expiring = FetchedItem {
+            expires_at: Some("2026-02-01T00:00:00Z".into()),
+            ..decoded
+        };
+        let json = serde_json::to_string(&expiring).unwrap();
+        let decoded: FetchedItem = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.expires_at.as_deref(), Some("2026-02-01T00:00:00Z"));
+    }
+
     #[test]
     fn test_peer_share_roundtrip() {
         let req = PeerShareRequest { max_peers: 10 };
@@ -266,6 +569,11 @@ mod tests {
                 parent_id: None,
                 is_copy: false,
                 updated_at: "2026-01-29T00:00:00Z".into(),
+                expires_at: None,
+                author_pubkey: None,
+                author_signature: None,
+                hlc: None,
+                ancestry: None,
             }],
         };
         let json = serde_json::to_string(&req).unwrap();
@@ -279,11 +587,19 @@ mod tests {
         let req = GroupExchange {
             groups: vec!["g1".into(), "g2".into()],
             descriptors: None,
+            membership: None,
+            membership_heads: Some(vec![MembershipHead {
+                group_id: "g1".into(),
+                entries: 3,
+                latest: "2026-02-03T00:00:00.000Z".into(),
+            }]),
+            sent_at: None,
         };
         let json = serde_json::to_string(&req).unwrap();
         let decoded: GroupExchange = serde_json::from_str(&json).unwrap();
         assert_eq!(decoded.groups, vec!["g1", "g2"]);
         assert!(decoded.descriptors.is_none());
+        assert_eq!(decoded.membership_heads, req.membership_heads);
     }
 
     #[test]
@@ -300,7 +616,15 @@ mod tests {
                 owner_id: None,
                 owner_pubkey: None,
                 signature: None,
+                admins: None,
+                signer_pubkey: None,
+                transfers: None,
+                security_policy: None,
+                hlc: None,
             }]),
+            membership: None,
+            membership_heads: None,
+            sent_at: None,
         };
         let json = serde_json::to_string(&req).unwrap();
         let decoded: GroupExchange = serde_json::from_str(&json).unwrap();
@@ -321,6 +645,54 @@ mod tests {
         let decoded: GroupExchange = serde_json::from_str(old_json).unwrap();
         assert_eq!(decoded.groups, vec!["g1", "g2"]);
         assert!(decoded.descriptors.is_none());
+        assert!(decoded.membership.is_none());
+        assert!(decoded.membership_heads.is_none());
+    }
+
+    #[test]
+    fn test_membership_entry_payload_and_id() {
+        let entry = MembershipEntry {
+            group_id: "g1".into(),
+            op: MEMBERSHIP_OP_ADD.into(),
+            entity_id: "alice".into(),
+            role: Some("member".into()),
+            member_pubkey: None,
+            created_at: "2026-02-03T00:00:00Z".into(),
+            signer_id: "russell".into(),
+            signer_pubkey: "ab".into(),
+            signature: "cd".into(),
+            prev: Vec::new(),
+        };
+        assert_eq!(
+            entry.signing_payload(),
+            b"g1\nadd\nalice\nmember\n\n2026-02-03T00:00:00Z\nrussell\nab"
+        );
+        // The entries it follows are signed, so they cannot be re-linked
+        let chained = MembershipEntry {
+            prev: vec!["e1".into(), "e2".into()],
+            ..entry.clone()
+        };
+        assert_eq!(
+            chained.signing_payload(),
+            b"g1\nadd\nalice\nmember\n\n2026-02-03T00:00:00Z\nrussell\nab\nprev=e1,e2"
+        );
+        assert_ne!(entry.entry_id(), chained.entry_id());
+        // Signature is not part of the ID; any field change is
+        let resigned = MembershipEntry {
+            signature: "ef".into(),
+            ..entry.clone()
+        };
+        assert_eq!(entry.entry_id(), resigned.entry_id());
+        let demoted = MembershipEntry {
+            role: Some("viewer".into()),
+            ..entry.clone()
+        };
+        assert_ne!(entry.entry_id(), demoted.entry_id());
+
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(!json.contains("member_pubkey"));
+        let decoded: MembershipEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, entry);
     }
 
     #[test]
@@ -334,6 +706,11 @@ mod tests {
             owner_id: None,
             owner_pubkey: None,
             signature: None,
+            admins: None,
+            signer_pubkey: None,
+            transfers: None,
+            security_policy: None,
+            hlc: None,
         };
         assert!(desc.verify_checksum());
 
@@ -355,8 +732,164 @@ mod tests {
             owner_id: None,
             owner_pubkey: None,
             signature: None,
+            admins: None,
+            signer_pubkey: None,
+            transfers: None,
+            security_policy: None,
+            hlc: None,
         };
         let payload = desc.signing_payload();
         assert_eq!(payload, b"g1\n{}\n2026-02-03T00:00:00Z");
+        assert!(desc.signing_key().is_none());
+        assert!(!desc.is_owner_signed());
+
+        // Admin set is covered by the signature
+        let with_admins = GroupDescriptor {
+            admins: Some(vec!["aa".into(), "bb".into()]),
+            owner_pubkey: Some("owner".into()),
+            ..desc.clone()
+        };
+        assert_eq!(
+            with_admins.signing_payload(),
+            b"g1\n{}\n2026-02-03T00:00:00Z\nadmins=aa,bb"
+        );
+        assert_eq!(with_admins.signing_key(), Some("owner"));
+        let by_owner = GroupDescriptor {
+            signature: Some("sig".into()),
+            ..with_admins.clone()
+        };
+        assert!(by_owner.is_owner_signed());
+        let by_admin = GroupDescriptor {
+            signer_pubkey: Some("aa".into()),
+            ..by_owner
+        };
+        assert_eq!(by_admin.signing_key(), Some("aa"));
+        assert!(!by_admin.is_owner_signed());
+        assert!(!with_admins.is_owner_signed(), "unsigned");
+        // Empty set signs like no set
+        let empty = GroupDescriptor {
+            admins: Some(Vec::new()),
+            ..desc
+        };
+        assert_eq!(empty.signing_payload(), payload);
+
+        // Security policy is covered when it restricts anything
+        let open = GroupDescriptor {
+            security_policy: Some("{}".into()),
+            ..empty.clone()
+        };
+        assert_eq!(open.signing_payload(), payload);
+        let policed = GroupDescriptor {
+            security_policy: Some(r#"{"relay_storable":false}"#.into()),
+            ..empty.clone()
+        };
+        assert_eq!(
+            policed.signing_payload(),
+            br#"g1
+{}
+2026-02-03T00:00:00Z
+security_policy={"relay_storable":false}"#
+        );
+
+        // The HLC version is covered, so a relay cannot make a stale
+        // descriptor win LWW
+        let versioned = GroupDescriptor {
+            hlc: Some("001770076800000-0000000002-n1".into()),
+            ..empty
+        };
+        assert_eq!(
+            versioned.signing_payload(),
+            b"g1\n{}\n2026-02-03T00:00:00Z\nhlc=001770076800000-0000000002-n1"
+        );
+    }
+
+    #[test]
+    fn test_item_signing_payload() {
+        let fields = ItemSigningFields {
+            item_id: "i1",
+            group_id: "g1",
+            item_type: "entity",
+            checksum: "abc",
+            updated_at: "2026-02-01T00:00:00.000Z",
+            expires_at: None,
+            hlc: None,
+            ancestry: None,
+        };
+        assert_eq!(
+            fields.payload(),
+            b"i1\ng1\nentity\nabc\n2026-02-01T00:00:00.000Z"
+        );
+        let expiring = ItemSigningFields {
+            expires_at: Some("2026-03-01T00:00:00Z"),
+            ..fields
+        };
+        assert_eq!(
+            expiring.payload(),
+            b"i1\ng1\nentity\nabc\n2026-02-01T00:00:00.000Z\nexpires_at=2026-03-01T00:00:00Z"
+        );
+
+        // The HLC version is covered, so a relay cannot make a stale
+        // version win LWW
+        let versioned = ItemSigningFields {
+            hlc: Some("001769904000000-0000000002-n1"),
+            ..fields
+        };
+        assert_eq!(
+            versioned.payload(),
+            b"i1\ng1\nentity\nabc\n2026-02-01T00:00:00.000Z\nhlc=001769904000000-0000000002-n1"
+        );
+
+        // So is the ancestry, so a relay cannot claim a version supersedes
+        // a concurrent edit
+        let ancestry = vec!["c1".to_string(), "c2".to_string()];
+        let descended = ItemSigningFields {
+            ancestry: Some(&ancestry),
+            ..fields
+        };
+        assert_eq!(
+            descended.payload(),
+            b"i1\ng1\nentity\nabc\n2026-02-01T00:00:00.000Z\nancestry=c1,c2"
+        );
+    }
+
+    #[test]
+    fn test_ownership_transfer_payload() {
+        let transfer = OwnershipTransfer {
+            from_pubkey: "aa".into(),
+            to_owner_id: "bill".into(),
+            to_pubkey: "bb".into(),
+            transferred_at: "2026-03-01 12:00:00".into(),
+            signature: "sig".into(),
+            hlc: None,
+            log_heads: Vec::new(),
+        };
+        assert_eq!(
+            transfer.signing_payload("g1"),
+            b"g1\naa\nbill\nbb\n2026-03-01 12:00:00"
+        );
+        let json = serde_json::to_string(&transfer).unwrap();
+        assert!(!json.contains("hlc"));
+        assert_eq!(
+            serde_json::from_str::<OwnershipTransfer>(&json).unwrap(),
+            transfer
+        );
+
+        // The handover descriptor and log heads are bound, even when empty
+        let pinned = OwnershipTransfer {
+            hlc: Some("hlc1".into()),
+            ..transfer.clone()
+        };
+        assert_eq!(
+            pinned.signing_payload("g1"),
+            b"g1\naa\nbill\nbb\n2026-03-01 12:00:00\nhlc=hlc1\nlog_heads="
+        );
+        let headed = OwnershipTransfer {
+            log_heads: vec!["e1".into(), "e2".into()],
+            ..pinned
+        };
+        assert_eq!(
+            headed.signing_payload("g1"),
+            b"g1\naa\nbill\nbb\n2026-03-01 12:00:00\nhlc=hlc1\nlog_heads=e1,e2"
+        );
     }
 }
@@ -0,0 +1,279 @@
+//! Group culture schema -- strict parsing, canonical form and migration of
+//! loose (pre-schema) cultures.
+//!
+//! | Field                 | Values                               | Default |
+//! |-----------------------|--------------------------------------|---------|
+//! | `broadcast_eagerness` | `chatty`, `taciturn`                 | chatty  |
+//! | `departure_policy`    | `retain`, `purge`, `archive`         | retain  |
+//! | `notification_policy` | `push`, `notify`, `silent`           | push    |
+//! | `ttl_default`         | seconds, 1..=`MAX_TTL_DEFAULT_SECS`  | none    |
+//! | `version`             | `CULTURE_VERSION`                    | current |
+//!
+//! Unknown fields and values are rejected at `groups/create` and in received
+//! group descriptors. The canonical form is compact JSON with keys in
+//! lexicographic order and absent optionals omitted, so the descriptor
+//! checksum and signature over a culture are the same in every
+//! implementation.
+
+use cordelia_storage::{Storage, StorageError};
+use serde_json::Value;
+
+use crate::{DeparturePolicy, Eagerness, GroupCulture, NotificationPolicy};
+
+/// Current culture schema version.
+pub const CULTURE_VERSION: u32 = 1;
+/// Culture size limit in bytes (R4-030).
+pub const MAX_CULTURE_BYTES: usize = 4096;
+/// Upper bound for `ttl_default` (10 years).
+pub const MAX_TTL_DEFAULT_SECS: u64 = 10 * 365 * 86_400;
+
+pub(crate) fn current_version() -> u32 {
+    CULTURE_VERSION
+}
+
+impl GroupCulture {
+    /// Parse and validate a culture document (`groups/create`, descriptors).
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if json.len() > MAX_CULTURE_BYTES {
+            return Err(format!(
+                "invalid culture: {} bytes exceeds {MAX_CULTURE_BYTES}",
+                json.len()
+            ));
+        }
+        let culture: Self =
+            serde_json::from_str(json).map_err(|e| format!("invalid culture: {e}"))?;
+        if culture.version != CULTURE_VERSION {
+            return Err(format!(
+                "invalid culture: unsupported version {} (expected {CULTURE_VERSION})",
+                culture.version
+            ));
+        }
+        if let Some(ttl) = culture.ttl_default {
+            if !(1..=MAX_TTL_DEFAULT_SECS).contains(&ttl) {
+                return Err(format!(
+                    "invalid culture: ttl_default must be 1..={MAX_TTL_DEFAULT_SECS} seconds"
+                ));
+            }
+        }
+        Ok(culture)
+    }
+
+    /// Culture of a stored group. Valid documents parse as-is; loose
+    /// (pre-schema) values are migrated: bare eagerness strings, unknown
+    /// fields dropped, unknown values replaced by their defaults.
+    pub fn load(stored: &str) -> Self {
+        if let Ok(culture) = Self::parse(stored) {
+            return culture;
+        }
+        let Ok(Value::Object(map)) = serde_json::from_str::<Value>(stored) else {
+            // Bare eagerness string, e.g. "chatty"
+            return Self {
+                broadcast_eagerness: loose_eagerness(stored),
+                ..Default::default()
+            };
+        };
+        let field = |key: &str| map.get(key).and_then(Value::as_str);
+        Self {
+            broadcast_eagerness: field("broadcast_eagerness")
+                .map(loose_eagerness)
+                .unwrap_or_default(),
+            departure_policy: field("departure_policy").map(|p| DeparturePolicy::parse(Some(p))),
+            notification_policy: field("notification_policy")
+                .and_then(|p| serde_json::from_value::<NotificationPolicy>(p.into()).ok()),
+            ttl_default: map
+                .get("ttl_default")
+                .and_then(Value::as_u64)
+                .filter(|ttl| (1..=MAX_TTL_DEFAULT_SECS).contains(ttl)),
+            version: CULTURE_VERSION,
+        }
+    }
+
+    /// Canonical JSON: compact, keys in lexicographic order, no absent optionals.
+    pub fn to_canonical_json(&self) -> String {
+        serde_json::to_string(self).expect("culture serializes")
+    }
+}
+
+/// Eagerness of a loose culture. Anything but a passive value pushes, as
+/// the replication strategy always has.
+fn loose_eagerness(value: &str) -> Eagerness {
+    match value.trim_matches('"') {
+        "taciturn" | "passive" => Eagerness::Taciturn,
+        _ => Eagerness::Chatty,
+    }
+}
+
+/// Rewrite stored cultures that are not in canonical form. Groups signed by
+/// another key keep their culture (rewriting it would void the owner's
+/// signature); groups we own are re-signed on the next GroupExchange.
+/// Returns the number of groups migrated.
+pub fn migrate_stored(storage: &dyn Storage, node_pubkey: &str) -> Result<usize, StorageError> {
+    let mut migrated = 0;
+    for group in storage.list_groups()? {
+        if group.culture == cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE {
+            continue;
+        }
+        let canonical = GroupCulture::load(&group.culture).to_canonical_json();
+        if canonical == group.culture {
+            continue;
+        }
+        let foreign = group.signature.is_some()
+            && group
+                .owner_pubkey
+                .as_deref()
+                .is_some_and(|k| k != node_pubkey);
+        if foreign {
+            continue;
+        }
+        storage.write_group(&group.id, &group.name, &canonical, &group.security_policy)?;
+        tracing::info!(
+            group_id = group.id,
+            from = group.culture,
+            to = canonical,
+            "mem: migrated group culture to canonical form"
+        );
+        migrated += 1;
+    }
+    Ok(migrated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cordelia_storage::{GroupAuthority, SqliteStorage};
+
+    #[test]
+    fn test_parse_is_strict() {
+        let culture = GroupCulture::parse(
+            r#"{"broadcast_eagerness":"taciturn","departure_policy":"purge","notification_policy":"notify","ttl_default":3600}"#,
+        )
+        .unwrap();
+        assert_eq!(culture.broadcast_eagerness, Eagerness::Taciturn);
+        assert_eq!(culture.departure_policy, Some(DeparturePolicy::Purge));
+        assert_eq!(
+            culture.notification_policy,
+            Some(NotificationPolicy::Notify)
+        );
+        assert_eq!(culture.ttl_default, Some(3600));
+        assert_eq!(culture.version, CULTURE_VERSION);
+
+        assert_eq!(GroupCulture::parse("{}").unwrap(), GroupCulture::default());
+        // Deprecated alias
+        assert_eq!(
+            GroupCulture::parse(r#"{"broadcast_eagerness":"moderate"}"#)
+                .unwrap()
+                .broadcast_eagerness,
+            Eagerness::Chatty
+        );
+
+        for bad in [
+            r#"{"broadcast_eagerness":"chatyy"}"#,
+            r#"{"broadcast_eagernes":"chatty"}"#,
+            r#"{"departure_policy":"standard"}"#,
+            r#"{"notification_policy":"loud"}"#,
+            r#"{"ttl_default":0}"#,
+            r#"{"ttl_default":-5}"#,
+            r#"{"version":2}"#,
+            "chatty",
+        ] {
+            assert!(GroupCulture::parse(bad).is_err(), "{bad}");
+        }
+        let too_long = format!(r#"{{"broadcast_eagerness":"chatty"{}}}"#, " ".repeat(4096));
+        assert!(GroupCulture::parse(&too_long).is_err());
+        let too_far = format!(r#"{{"ttl_default":{}}}"#, MAX_TTL_DEFAULT_SECS + 1);
+        assert!(GroupCulture::parse(&too_far).is_err());
+    }
+
+    #[test]
+    fn test_canonical_json() {
+        let culture = GroupCulture::parse(
+            r#"{ "version": 1, "ttl_default": 60, "broadcast_eagerness": "moderate" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            culture.to_canonical_json(),
+            r#"{"broadcast_eagerness":"chatty","ttl_default":60,"version":1}"#
+        );
+        assert_eq!(
+            GroupCulture::default().to_canonical_json(),
+            r#"{"broadcast_eagerness":"chatty","version":1}"#
+        );
+        // Canonical form round-trips
+        let canonical = culture.to_canonical_json();
+        assert_eq!(
+            GroupCulture::parse(&canonical).unwrap().to_canonical_json(),
+            canonical
+        );
+    }
+
+    #[test]
+    fn test_load_migrates_loose_cultures() {
+        assert_eq!(
+            GroupCulture::load("taciturn").broadcast_eagerness,
+            Eagerness::Taciturn
+        );
+        assert_eq!(GroupCulture::load("chatty"), GroupCulture::default());
+        assert_eq!(GroupCulture::load("not json {"), GroupCulture::default());
+
+        let loose = GroupCulture::load(
+            r#"{"broadcast_eagerness":"passive","departure_policy":"standard","notification_policy":"loud","ttl_default":0,"color":"red"}"#,
+        );
+        assert_eq!(loose.broadcast_eagerness, Eagerness::Taciturn);
+        assert_eq!(loose.departure_policy, Some(DeparturePolicy::Retain));
+        assert_eq!(loose.notification_policy, None);
+        assert_eq!(loose.ttl_default, None);
+        assert_eq!(
+            loose.to_canonical_json(),
+            r#"{"broadcast_eagerness":"taciturn","departure_policy":"retain","version":1}"#
+        );
+    }
+
+    #[test]
+    fn test_migrate_stored() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        db.write_group("loose", "loose", "chatty", "{}").unwrap();
+        db.write_group(
+            "ours",
+            "ours",
+            r#"{"broadcast_eagerness":"taciturn"}"#,
+            "{}",
+        )
+        .unwrap();
+        db.write_group("theirs", "theirs", "taciturn", "{}")
+            .unwrap();
+        db.write_group(
+            "done",
+            "done",
+            r#"{"broadcast_eagerness":"chatty","version":1}"#,
+            "{}",
+        )
+        .unwrap();
+        let authority = |owner_pubkey: &str| GroupAuthority {
+            updated_at: "2026-03-01 00:00:00".into(),
+            owner_id: "owner".into(),
+            owner_pubkey: owner_pubkey.into(),
+            signature: "sig".into(),
+            signer_pubkey: None,
+            admins: Vec::new(),
+            owner_transfers: None,
+            hlc: None,
+        };
+        db.write_group_authority("ours", &authority("aa")).unwrap();
+        db.write_group_authority("theirs", &authority("bb"))
+            .unwrap();
+
+        assert_eq!(migrate_stored(&db, "aa").unwrap(), 2);
+        let culture = |id: &str| db.read_group(id).unwrap().unwrap().culture;
+        assert_eq!(
+            culture("loose"),
+            r#"{"broadcast_eagerness":"chatty","version":1}"#
+        );
+        assert_eq!(
+            culture("ours"),
+            r#"{"broadcast_eagerness":"taciturn","version":1}"#
+        );
+        assert_eq!(culture("theirs"), "taciturn", "signed by another owner");
+        assert_eq!(migrate_stored(&db, "aa").unwrap(), 0, "idempotent");
+    }
+}
@@ -0,0 +1,234 @@
+//! Departure policy -- what happens to a group's items when we leave it.
+//!
+//! Applied on local leave (`groups/delete`, `groups/leave`) and on an
+//! owner-signed group tombstone received from a peer. The policy is read
+//! from the group's culture *before* it is overwritten by the tombstone.
+
+use cordelia_protocol::messages::GROUP_TOMBSTONE_CULTURE;
+use cordelia_storage::{GroupDepartureRow, Storage, StorageError};
+use serde::{Deserialize, Serialize};
+
+use crate::GroupCulture;
+
+/// Departure trigger: this node deleted/left the group.
+pub const REASON_LOCAL_LEAVE: &str = "local_leave";
+/// Departure trigger: owner-signed deletion tombstone from a peer.
+pub const REASON_OWNER_REMOVAL: &str = "owner_removal";
+/// Departure trigger: this node left a group it does not own.
+pub const REASON_MEMBER_LEAVE: &str = "member_leave";
+
+/// Parsed `GroupCulture.departure_policy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeparturePolicy {
+    /// Keep items locally, read-only (no further writes or replication).
+    Retain,
+    /// Securely delete the group's items and vacuum the database.
+    Purge,
+    /// Export items to a local archive file and keep them read-only.
+    Archive,
+}
+
+impl DeparturePolicy {
+    /// Parse a loose (pre-schema) culture value. Absent or unknown values retain (never destroy
+    /// data on a typo). The R5 key-rotation values (`permissive`, `standard`,
+    /// `restrictive`) leave local items unchanged, so they also retain.
+    pub fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("purge") => DeparturePolicy::Purge,
+            Some("archive") => DeparturePolicy::Archive,
+            Some("retain" | "permissive" | "standard" | "restrictive") | None => {
+                DeparturePolicy::Retain
+            }
+            Some(other) => {
+                tracing::warn!(
+                    policy = other,
+                    "repl: unknown departure_policy, defaulting to retain"
+                );
+                DeparturePolicy::Retain
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeparturePolicy::Retain => "retain",
+            DeparturePolicy::Purge => "purge",
+            DeparturePolicy::Archive => "archive",
+        }
+    }
+}
+
+impl GroupCulture {
+    /// Departure policy for this culture (default: retain).
+    pub fn departure(&self) -> DeparturePolicy {
+        self.departure_policy.unwrap_or(DeparturePolicy::Retain)
+    }
+}
+
+/// Departure policy from a stored culture string (loose values migrated).
+pub fn departure_policy_for(culture: &str) -> DeparturePolicy {
+    GroupCulture::load(culture).departure()
+}
+
+/// Whether this node left `group_id` as a member: its copy is an unsigned
+/// tombstone only it holds, which must neither be advertised to peers nor
+/// replaced by their newer descriptors.
+pub fn left(storage: &dyn Storage, group_id: &str) -> bool {
+    let tombstoned = matches!(
+        storage.read_group(group_id),
+        Ok(Some(g)) if g.culture == GROUP_TOMBSTONE_CULTURE
+    );
+    tombstoned
+        && storage
+            .list_departures()
+            .ok()
+            .and_then(|d| d.into_iter().find(|d| d.group_id == group_id))
+            .is_some_and(|d| d.reason == REASON_MEMBER_LEAVE)
+}
+
+/// Apply a departure policy to a group's local items and record the outcome.
+pub fn apply_departure_policy(
+    storage: &dyn Storage,
+    group_id: &str,
+    policy: DeparturePolicy,
+    reason: &str,
+) -> Result<GroupDepartureRow, StorageError> {
+    let (items_affected, archive_path) = match policy {
+        DeparturePolicy::Retain => (storage.count_group_items(group_id)?, None),
+        DeparturePolicy::Purge => (storage.purge_group_items(group_id)?, None),
+        DeparturePolicy::Archive => {
+            let (n, path) = storage.archive_group_items(group_id)?;
+            (n, Some(path))
+        }
+    };
+
+    let departure = GroupDepartureRow {
+        group_id: group_id.to_string(),
+        policy: policy.as_str().to_string(),
+        reason: reason.to_string(),
+        items_affected,
+        archive_path,
+        departed_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    };
+    storage.record_departure(&departure)?;
+
+    tracing::info!(
+        group_id,
+        policy = departure.policy,
+        reason,
+        items = items_affected,
+        archive = departure.archive_path.as_deref().unwrap_or("-"),
+        "repl: departure policy applied"
+    );
+
+    Ok(departure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cordelia_storage::{L2ItemWrite, SqliteStorage};
+
+    fn db_with_items(group_id: &str, n: usize) -> (tempfile::TempDir, SqliteStorage) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = SqliteStorage::open(&dir.path().join("test.db")).unwrap();
+        for i in 0..n {
+            db.write_l2_item(&L2ItemWrite {
+                id: format!("{group_id}-{i}"),
+                item_type: "entity".into(),
+                data: b"blob".to_vec(),
+                owner_id: None,
+                visibility: "group".into(),
+                group_id: Some(group_id.into()),
+                author_id: Some("russell".into()),
+                key_version: 1,
+                parent_id: None,
+                is_copy: false,
+                updated_at: None,
+                expires_at: None,
+                author_pubkey: None,
+                author_signature: None,
+                hlc: None,
+                ancestry: None,
+            })
+            .unwrap();
+        }
+        (dir, db)
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!(DeparturePolicy::parse(None), DeparturePolicy::Retain);
+        assert_eq!(
+            DeparturePolicy::parse(Some("purge")),
+            DeparturePolicy::Purge
+        );
+        assert_eq!(
+            DeparturePolicy::parse(Some("archive")),
+            DeparturePolicy::Archive
+        );
+        assert_eq!(
+            DeparturePolicy::parse(Some("standard")),
+            DeparturePolicy::Retain
+        );
+        assert_eq!(
+            DeparturePolicy::parse(Some("shred")),
+            DeparturePolicy::Retain
+        );
+        assert_eq!(
+            departure_policy_for(r#"{"broadcast_eagerness":"chatty","departure_policy":"purge"}"#),
+            DeparturePolicy::Purge
+        );
+        // Bare eagerness string (legacy culture) retains
+        assert_eq!(departure_policy_for("chatty"), DeparturePolicy::Retain);
+    }
+
+    #[test]
+    fn test_apply_retain_keeps_items() {
+        let (_dir, db) = db_with_items("g", 3);
+        let d =
+            apply_departure_policy(&db, "g", DeparturePolicy::Retain, REASON_LOCAL_LEAVE).unwrap();
+        assert_eq!(d.items_affected, 3);
+        assert_eq!(db.count_group_items("g").unwrap(), 3);
+        assert_eq!(db.list_departures().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_apply_purge_and_archive() {
+        let (_dir, db) = db_with_items("g", 2);
+        let d =
+            apply_departure_policy(&db, "g", DeparturePolicy::Purge, REASON_OWNER_REMOVAL).unwrap();
+        assert_eq!(d.items_affected, 2);
+        assert_eq!(db.count_group_items("g").unwrap(), 0);
+
+        let (_dir2, db2) = db_with_items("h", 2);
+        let d = apply_departure_policy(&db2, "h", DeparturePolicy::Archive, REASON_LOCAL_LEAVE)
+            .unwrap();
+        assert_eq!(d.items_affected, 2);
+        let path = d.archive_path.unwrap();
+        assert_eq!(std::fs::read_to_string(path).unwrap().lines().count(), 2);
+        assert_eq!(db2.count_group_items("h").unwrap(), 2);
+
+        let recorded = db2.list_departures().unwrap();
+        assert_eq!(recorded[0].policy, "archive");
+        assert_eq!(recorded[0].reason, REASON_LOCAL_LEAVE);
+    }
+
+    #[test]
+    fn test_left() {
+        let (_dir, db) = db_with_items("g", 0);
+        db.write_group("g", "g", "{}", "{}").unwrap();
+        apply_departure_policy(&db, "g", DeparturePolicy::Retain, REASON_MEMBER_LEAVE).unwrap();
+        // Only once the group is tombstoned locally
+        assert!(!left(&db, "g"));
+        db.write_group("g", "g", GROUP_TOMBSTONE_CULTURE, "{}")
+            .unwrap();
+        assert!(left(&db, "g"));
+
+        // A later owner deletion is not a member leave
+        apply_departure_policy(&db, "g", DeparturePolicy::Retain, REASON_OWNER_REMOVAL).unwrap();
+        assert!(!left(&db, "g"));
+        assert!(!left(&db, "unknown"));
+    }
+}
@@ -2,17 +2,21 @@
 
 use cordelia_protocol::messages::FetchedItem;
 use cordelia_protocol::GroupId;
-use cordelia_storage::{L2ItemWrite, Storage};
+use cordelia_storage::{L2ConflictRow, L2ItemRow, L2ItemWrite, Storage};
+use std::sync::Arc;
 
+use crate::hlc::{Hlc, HlcClock};
 use crate::{
-    checksum, validate_checksum, GroupCulture, ReceiveOutcome, ReplicationConfig,
-    ReplicationStrategy,
+    format_timestamp, is_expired, item_checksum, parse_timestamp, validate_checksum, GroupAction,
+    GroupCulture, ReceiveOutcome, ReplicationConfig, ReplicationStrategy, SecurityPolicy,
 };
 
 /// The replication engine -- coordinates outbound and inbound replication.
 pub struct ReplicationEngine {
     config: ReplicationConfig,
     entity_id: String,
+    /// Node HLC, advanced by every accepted item's version.
+    clock: Option<Arc<HlcClock>>,
 }
 
 /// Outbound action to send to peers.
@@ -30,10 +34,25 @@ pub enum OutboundAction {
 
 impl ReplicationEngine {
     pub fn new(config: ReplicationConfig, entity_id: String) -> Self {
-        Self { config, entity_id }
+        Self {
+            config,
+            entity_id,
+            clock: None,
+        }
+    }
+
+    /// Advance `clock` on receive, so later local writes order after every
+    /// version this node has seen.
+    pub fn with_clock(mut self, clock: Arc<HlcClock>) -> Self {
+        self.clock = Some(clock);
+        self
     }
 
     /// Determine outbound action when a local write occurs.
+    ///
+    /// `expires_at` is the item's expiry (explicit or derived from the
+    /// culture's `ttl_default`); it travels with the item and is bound into
+    /// the checksum.
     #[allow(clippy::too_many_arguments)]
     pub fn on_local_write(
         &self,
@@ -45,6 +64,7 @@ impl ReplicationEngine {
         key_version: u32,
         parent_id: Option<String>,
         is_copy: bool,
+        expires_at: Option<String>,
     ) -> OutboundAction {
         // Enforce item size limit before replication dispatch
         if data.len() > cordelia_protocol::MAX_ITEM_BYTES {
@@ -58,7 +78,7 @@ impl ReplicationEngine {
         }
 
         let strategy = culture.strategy();
-        let cs = checksum(data);
+        let cs = item_checksum(data, expires_at.as_deref());
 
         match strategy {
             ReplicationStrategy::EagerPush => OutboundAction::BroadcastItem {
@@ -73,7 +93,12 @@ impl ReplicationEngine {
                     key_version,
                     parent_id,
                     is_copy,
-                    updated_at: chrono::Utc::now().to_rfc3339(),
+                    updated_at: format_timestamp(chrono::Utc::now()),
+                    expires_at,
+                    author_pubkey: None,
+                    author_signature: None,
+                    hlc: None,
+                    ancestry: None,
                 },
             },
             ReplicationStrategy::Passive => OutboundAction::None,
@@ -101,6 +126,15 @@ impl ReplicationEngine {
             ));
         }
 
+        // 1b. Bound the ancestry (local writes keep at most MAX_ITEM_ANCESTRY)
+        let ancestry_len = item.ancestry.as_ref().map_or(0, Vec::len);
+        if ancestry_len > cordelia_protocol::MAX_ITEM_ANCESTRY {
+            return ReceiveOutcome::Rejected(format!(
+                "Conditions Not Met: ancestry of {ancestry_len} versions exceeds {}",
+                cordelia_protocol::MAX_ITEM_ANCESTRY
+            ));
+        }
+
         // 2. Validate group membership (or relay acceptance)
         let accepted = if let Some(relay_check) = relay_accepts {
             // Relay: use posture-based acceptance predicate
@@ -117,46 +151,164 @@ impl ReplicationEngine {
             ));
         }
 
-        // 3. Handle tombstones: delete the local copy
-        if item.item_type == "__tombstone__" {
-            match storage.delete_l2_item(&item.item_id) {
-                Ok(true) => {
-                    tracing::info!(
-                        item_id = item.item_id,
-                        group_id = item.group_id,
-                        "repl: tombstone applied -- item deleted"
-                    );
-                    return ReceiveOutcome::Stored; // Treated as a successful receive
-                }
-                Ok(false) => {
-                    return ReceiveOutcome::Duplicate; // Already gone
-                }
-                Err(e) => {
-                    return ReceiveOutcome::Rejected(format!("tombstone delete failed: {e}"));
-                }
-            }
+        // 2b. Author must hold a writer role when the membership list is known
+        if let Err(reason) = crate::roles::check_in_storage(
+            storage,
+            &item.group_id,
+            &item.author_id,
+            &self.entity_id,
+            GroupAction::Write,
+        ) {
+            tracing::warn!(
+                item_id = item.item_id,
+                group_id = item.group_id,
+                author_id = item.author_id,
+                reason,
+                "repl: rejected item from non-writer"
+            );
+            let _ = storage.log_access(&cordelia_storage::AccessLogEntry {
+                entity_id: item.author_id.clone(),
+                action: "replicate_denied".into(),
+                resource_type: "l2_item".into(),
+                resource_id: Some(item.item_id.clone()),
+                group_id: Some(item.group_id.clone()),
+                detail: Some(reason.clone()),
+            });
+            return ReceiveOutcome::Rejected(format!("Unacceptable Behaviour: {reason}"));
         }
 
-        // 4. Validate checksum
+        // 2c. Group security policy (types, size, relay storage, author signature)
+        let relay_only = relay_accepts.is_some() && !our_groups.contains(&item.group_id);
+        if let Err(reason) =
+            SecurityPolicy::of(storage, &item.group_id).check_receive(storage, item, relay_only)
+        {
+            tracing::warn!(
+                item_id = item.item_id,
+                group_id = item.group_id,
+                author_id = item.author_id,
+                reason,
+                "repl: rejected item by group security policy"
+            );
+            return ReceiveOutcome::Rejected(format!("Conditions Not Met: {reason}"));
+        }
+
+        // 3. Validate checksum
         if !validate_checksum(item) {
             return ReceiveOutcome::Rejected(
                 "integrity violation: checksum mismatch -- item corrupted or tampered".into(),
             );
         }
 
-        // 5. Dedup: check if we already have this exact item
+        // 4. Reject expired items (expiry is covered by the checksum above)
+        let now = chrono::Utc::now();
+        if is_expired(item.expires_at.as_deref(), now) {
+            return ReceiveOutcome::Rejected(format!(
+                "Conditions Not Met: item {} expired at {}",
+                item.item_id,
+                item.expires_at.as_deref().unwrap_or_default()
+            ));
+        }
+
+        // 5. Timestamp and version: parseable, and not dated beyond the
+        // allowed clock skew (a fast clock would otherwise win every LWW
+        // conflict)
+        let Some(updated_at) = parse_timestamp(&item.updated_at) else {
+            return ReceiveOutcome::Rejected(format!(
+                "Conditions Not Met: unparseable updated_at '{}'",
+                item.updated_at
+            ));
+        };
+        let version = match item.hlc.as_deref() {
+            Some(hlc) => match Hlc::parse(hlc) {
+                Some(v) => v,
+                None => {
+                    return ReceiveOutcome::Rejected(format!(
+                        "Conditions Not Met: unparseable hlc '{hlc}'"
+                    ));
+                }
+            },
+            None => Hlc::from_timestamp(&item.updated_at).unwrap_or_default(),
+        };
+        let ahead = updated_at
+            .max(version.wall())
+            .signed_duration_since(now)
+            .num_seconds();
+        if ahead > self.config.max_clock_skew_secs as i64 {
+            tracing::warn!(
+                item_id = item.item_id,
+                group_id = item.group_id,
+                author_id = item.author_id,
+                updated_at = item.updated_at,
+                ahead_secs = ahead,
+                "repl: rejected item dated in the future (clock skew)"
+            );
+            return ReceiveOutcome::Rejected(format!(
+                "Conditions Not Met: item dated {ahead}s in the future (max skew {}s)",
+                self.config.max_clock_skew_secs
+            ));
+        }
+
+        // 6. Tombstones: delete the local copy unless it is the newer
+        // version, as LWW decides for puts, so a stale or replayed deletion
+        // cannot remove a later edit
+        if item.item_type == "__tombstone__" {
+            return self.apply_tombstone(storage, item, &version);
+        }
+
+        // 6b. Dedup and conflict detection against the local version. The
+        // ancestry is only trusted (to fast-forward, or to resolve siblings)
+        // when the author signature covering it verifies.
+        let vouched = item
+            .ancestry
+            .as_deref()
+            .filter(|_| crate::security::verify_author(storage, item).is_ok());
+        let mut displaced = None;
         if let Ok(Some(existing)) = storage.read_l2_item(&item.item_id) {
             if existing.checksum.as_deref() == Some(&item.checksum) {
                 return ReceiveOutcome::Duplicate;
             }
+            let siblings = storage
+                .list_l2_conflicts(Some(&item.item_id))
+                .unwrap_or_default();
+            if siblings.iter().any(|c| c.checksum == item.checksum) {
+                return ReceiveOutcome::Duplicate; // already kept as a sibling
+            }
 
-            // Conflict resolution: last-writer-wins by updated_at
-            if existing.updated_at >= item.updated_at {
-                return ReceiveOutcome::Duplicate; // our version is newer or equal
+            let local = Hlc::of(existing.hlc.as_deref(), &existing.updated_at);
+            let local_newer = local.is_some_and(|local| local >= version);
+            let succeeds = matches!(
+                (&item.ancestry, &existing.checksum),
+                (Some(ancestry), Some(cs)) if ancestry.contains(cs)
+            );
+            match (&item.ancestry, &existing.ancestry, &existing.checksum) {
+                // Signed successor of our version: fast-forward
+                _ if succeeds && vouched.is_some() => {}
+                // One of our version's ancestors: stale
+                (_, Some(ours), _) if ours.contains(&item.checksum) => {
+                    return ReceiveOutcome::Duplicate;
+                }
+                // Neither descends from the other (or the claim that it does
+                // is unsigned): keep both, the HLC picking which one is current
+                (Some(_), ours, Some(_)) if succeeds || ours.is_some() => {
+                    if local_newer {
+                        return self.keep_sibling(storage, item, vouched);
+                    }
+                    displaced = Some(existing);
+                }
+                // No ancestry on one side (older peer or pre-ancestry row):
+                // last-writer-wins, totally ordered on the HLC (rows without
+                // one are versioned from their updated_at)
+                _ if local_newer => {
+                    return ReceiveOutcome::Duplicate; // our version is newer or equal
+                }
+                _ => {}
             }
         }
+        if let Some(clock) = &self.clock {
+            clock.observe(&version);
+        }
 
-        // 6. Store the item (encrypted blob, no decryption).
+        // 7. Store the item (encrypted blob, no decryption).
         // Preserve the original writer's updated_at to maintain causal ordering
         // across hops -- without this, intermediate nodes would reset the timestamp
         // to datetime('now'), allowing stale items to appear newer after traversing
@@ -172,14 +324,31 @@ impl ReplicationEngine {
             key_version: item.key_version as i32,
             parent_id: item.parent_id.clone(),
             is_copy: item.is_copy,
-            updated_at: Some(item.updated_at.clone()),
+            updated_at: Some(format_timestamp(updated_at)),
+            expires_at: item.expires_at.clone(),
+            author_pubkey: item.author_pubkey.clone(),
+            author_signature: item.author_signature.clone(),
+            hlc: item.hlc.clone(),
+            ancestry: item.ancestry.clone(),
         };
 
+        if let Some(existing) = &displaced {
+            if let Err(e) = storage.write_l2_conflict(
+                &conflict_from_row(existing),
+                cordelia_protocol::MAX_ITEM_SIBLINGS,
+            ) {
+                return ReceiveOutcome::Rejected(format!("storage error: {e}"));
+            }
+        }
         if let Err(e) = storage.write_l2_item(&write) {
             return ReceiveOutcome::Rejected(format!("storage error: {e}"));
         }
+        // Siblings this version merged (or succeeded) are resolved
+        if let Some(ancestry) = vouched {
+            let _ = storage.delete_l2_conflicts(&item.item_id, ancestry);
+        }
 
-        // 7. Log access
+        // 8. Log access
         let _ = storage.log_access(&cordelia_storage::AccessLogEntry {
             entity_id: item.author_id.clone(),
             action: "replicate_receive".into(),
@@ -189,9 +358,96 @@ impl ReplicationEngine {
             detail: None,
         });
 
+        if displaced.is_some() {
+            tracing::info!(
+                item_id = item.item_id,
+                group_id = item.group_id,
+                author_id = item.author_id,
+                "repl: concurrent edit kept as conflict sibling"
+            );
+            return ReceiveOutcome::Conflict;
+        }
         ReceiveOutcome::Stored
     }
 
+    /// Delete the local copy of a tombstoned item if the tombstone's
+    /// `version` is newer.
+    fn apply_tombstone(
+        &self,
+        storage: &dyn Storage,
+        item: &FetchedItem,
+        version: &Hlc,
+    ) -> ReceiveOutcome {
+        let existing = match storage.read_l2_item(&item.item_id) {
+            Ok(Some(existing)) => existing,
+            Ok(None) => return ReceiveOutcome::Duplicate, // Already gone
+            Err(e) => return ReceiveOutcome::Rejected(format!("tombstone delete failed: {e}")),
+        };
+        let local = Hlc::of(existing.hlc.as_deref(), &existing.updated_at);
+        if local.is_some_and(|local| local >= *version) {
+            tracing::debug!(
+                item_id = item.item_id,
+                group_id = item.group_id,
+                "repl: stale tombstone ignored -- local version is newer"
+            );
+            return ReceiveOutcome::Duplicate;
+        }
+        if let Some(clock) = &self.clock {
+            clock.observe(version);
+        }
+        match storage.delete_l2_item(&item.item_id) {
+            Ok(true) => {
+                tracing::info!(
+                    item_id = item.item_id,
+                    group_id = item.group_id,
+                    "repl: tombstone applied -- item deleted"
+                );
+                ReceiveOutcome::Stored // Treated as a successful receive
+            }
+            Ok(false) => ReceiveOutcome::Duplicate, // Already gone
+            Err(e) => ReceiveOutcome::Rejected(format!("tombstone delete failed: {e}")),
+        }
+    }
+
+    /// Keep a concurrent version that lost to the local one as a conflict
+    /// sibling. `vouched` is its ancestry if the author signature verified.
+    fn keep_sibling(
+        &self,
+        storage: &dyn Storage,
+        item: &FetchedItem,
+        vouched: Option<&[String]>,
+    ) -> ReceiveOutcome {
+        let conflict = L2ConflictRow {
+            item_id: item.item_id.clone(),
+            checksum: item.checksum.clone(),
+            item_type: item.item_type.clone(),
+            data: item.encrypted_blob.clone(),
+            group_id: Some(item.group_id.clone()),
+            author_id: Some(item.author_id.clone()),
+            key_version: item.key_version as i32,
+            updated_at: item.updated_at.clone(),
+            hlc: item.hlc.clone(),
+            ancestry: item.ancestry.clone(),
+            expires_at: item.expires_at.clone(),
+            author_pubkey: item.author_pubkey.clone(),
+            author_signature: item.author_signature.clone(),
+            detected_at: String::new(),
+        };
+        if let Err(e) = storage.write_l2_conflict(&conflict, cordelia_protocol::MAX_ITEM_SIBLINGS) {
+            return ReceiveOutcome::Rejected(format!("storage error: {e}"));
+        }
+        if let Some(ancestry) = vouched {
+            let _ = storage.delete_l2_conflicts(&item.item_id, ancestry);
+        }
+        tracing::info!(
+            item_id = item.item_id,
+            group_id = item.group_id,
+            author_id = item.author_id,
+            "repl: concurrent edit kept as conflict sibling"
+        );
+        ReceiveOutcome::Conflict
+    }
+
     /// Get the anti-entropy sync interval for a group culture.
     pub fn sync_interval(&self, culture: &GroupCulture) -> u64 {
         culture
@@ -214,11 +470,38 @@ impl ReplicationEngine {
     pub fn config(&self) -> &ReplicationConfig {
         &self.config
     }
+
+    /// Entity this node writes and applies postures as.
+    pub fn entity_id(&self) -> &str {
+        &self.entity_id
+    }
+}
+
+/// The displaced local version of an item, as a conflict sibling.
+fn conflict_from_row(row: &L2ItemRow) -> L2ConflictRow {
+    L2ConflictRow {
+        item_id: row.id.clone(),
+        checksum: row.checksum.clone().unwrap_or_default(),
+        item_type: row.item_type.clone(),
+        data: row.data.clone(),
+        group_id: row.group_id.clone(),
+        author_id: row.author_id.clone(),
+        key_version: row.key_version,
+        updated_at: row.updated_at.clone(),
+        hlc: row.hlc.clone(),
+        ancestry: row.ancestry.clone(),
+        expires_at: row.expires_at.clone(),
+        author_pubkey: row.author_pubkey.clone(),
+        author_signature: row.author_signature.clone(),
+        detected_at: String::new(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{checksum, Eagerness};
+    use ring::signature::{Ed25519KeyPair, KeyPair};
 
     fn default_engine() -> ReplicationEngine {
         ReplicationEngine::new(ReplicationConfig::default(), "russell".into())
@@ -228,7 +511,7 @@ mod tests {
     fn test_on_local_write_eager() {
         let engine = default_engine();
         let culture = GroupCulture {
-            broadcast_eagerness: "chatty".into(),
+            broadcast_eagerness: Eagerness::Chatty,
             ..Default::default()
         };
 
@@ -241,6 +524,7 @@ mod tests {
             1,
             None,
             false,
+            None,
         );
 
         match action {
@@ -257,10 +541,7 @@ mod tests {
     fn test_on_local_write_moderate_maps_to_chatty() {
         let engine = default_engine();
         // "moderate" is deprecated and maps to EagerPush (chatty)
-        let culture = GroupCulture {
-            broadcast_eagerness: "moderate".into(),
-            ..Default::default()
-        };
+        let culture = GroupCulture::parse(r#"{"broadcast_eagerness":"moderate"}"#).unwrap();
 
         let action = engine.on_local_write(
             "seed-drill",
@@ -271,6 +552,7 @@ mod tests {
             1,
             None,
             false,
+            None,
         );
 
         match action {
@@ -286,7 +568,7 @@ mod tests {
     fn test_on_local_write_oversized_suppressed() {
         let engine = default_engine();
         let culture = GroupCulture {
-            broadcast_eagerness: "chatty".into(),
+            broadcast_eagerness: Eagerness::Chatty,
             ..Default::default()
         };
 
@@ -300,6 +582,7 @@ mod tests {
             1,
             None,
             false,
+            None,
         );
 
         assert!(
@@ -312,7 +595,7 @@ mod tests {
     fn test_on_local_write_passive() {
         let engine = default_engine();
         let culture = GroupCulture {
-            broadcast_eagerness: "taciturn".into(),
+            broadcast_eagerness: Eagerness::Taciturn,
             ..Default::default()
         };
 
@@ -325,6 +608,7 @@ mod tests {
             1,
             None,
             false,
+            None,
         );
 
         assert!(matches!(action, OutboundAction::None));
@@ -334,7 +618,7 @@ mod tests {
     fn test_on_local_write_propagates_cow_fields() {
         let engine = default_engine();
         let culture = GroupCulture {
-            broadcast_eagerness: "chatty".into(),
+            broadcast_eagerness: Eagerness::Chatty,
             ..Default::default()
         };
 
@@ -347,6 +631,7 @@ mod tests {
             1,
             Some("parent-abc".into()),
             true,
+            None,
         );
 
         match action {
@@ -377,6 +662,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         let result = engine.on_receive(&db, &item, &["seed-drill".into()], None);
@@ -410,6 +700,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         let result = engine.on_receive(&db, &item, &["seed-drill".into()], None);
@@ -433,6 +728,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-01-29T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         let result = engine.on_receive(&db, &item, &["seed-drill".into()], None);
@@ -457,6 +757,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-01-29T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         let result = engine.on_receive(&db, &item, &["seed-drill".into()], None);
@@ -467,6 +772,53 @@ mod tests {
         assert_eq!(result2, ReceiveOutcome::Duplicate);
     }
 
+    #[test]
+    fn test_on_receive_enforces_writer_role() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        db.write_group("seed-drill", "seed-drill", "{}", "standard")
+            .unwrap();
+        for (entity, role) in [
+            ("russell", "owner"),
+            ("martin", "member"),
+            ("vera", "viewer"),
+        ] {
+            db.add_member("seed-drill", entity, role).unwrap();
+        }
+
+        let item_by = |author: &str, id: &str| FetchedItem {
+            item_id: id.into(),
+            item_type: "entity".into(),
+            encrypted_blob: b"blob".to_vec(),
+            checksum: checksum(b"blob"),
+            author_id: author.into(),
+            group_id: "seed-drill".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2026-01-29T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        };
+        let groups = ["seed-drill".to_string()];
+
+        let ok = engine.on_receive(&db, &item_by("martin", "m-1"), &groups, None);
+        assert_eq!(ok, ReceiveOutcome::Stored);
+
+        for author in ["vera", "mallory"] {
+            let result = engine.on_receive(&db, &item_by(author, "x-1"), &groups, None);
+            assert!(
+                matches!(&result, ReceiveOutcome::Rejected(r) if r.contains("Unacceptable Behaviour")),
+                "{author}: {result:?}"
+            );
+        }
+        assert!(db.read_l2_item("x-1").unwrap().is_none());
+    }
+
     #[test]
     fn test_on_receive_relay_accepts_any_group() {
         let engine = default_engine();
@@ -485,6 +837,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         // Non-relay: rejected (not a member)
@@ -515,6 +872,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         // Dynamic edge relay that only knows about "shared-xorg"
@@ -532,6 +894,133 @@ mod tests {
         assert_eq!(result, ReceiveOutcome::Stored);
     }
 
+    #[test]
+    fn test_on_local_write_carries_expiry() {
+        let engine = default_engine();
+        let culture = GroupCulture::default();
+        let expiry = "2099-01-01T00:00:00Z".to_string();
+
+        let action = engine.on_local_write(
+            "seed-drill",
+            &culture,
+            "ttl-1",
+            "entity",
+            b"blob",
+            1,
+            None,
+            false,
+            Some(expiry.clone()),
+        );
+
+        match action {
+            OutboundAction::BroadcastItem { item, .. } => {
+                assert_eq!(item.expires_at.as_deref(), Some(expiry.as_str()));
+                assert_eq!(item.checksum, item_checksum(b"blob", Some(&expiry)));
+                assert!(validate_checksum(&item));
+            }
+            _ => panic!("expected BroadcastItem"),
+        }
+    }
+
+    #[test]
+    fn test_on_receive_rejects_expired() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+
+        let data = b"stale";
+        let expiry = "2020-01-01T00:00:00Z";
+        let item = FetchedItem {
+            item_id: "expired-1".into(),
+            item_type: "entity".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: item_checksum(data, Some(expiry)),
+            author_id: "russell".into(),
+            group_id: "seed-drill".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2019-12-31T00:00:00Z".into(),
+            expires_at: Some(expiry.into()),
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        };
+
+        let result = engine.on_receive(&db, &item, &["seed-drill".into()], None);
+        assert!(matches!(result, ReceiveOutcome::Rejected(_)));
+        assert!(db.read_l2_item("expired-1").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_on_receive_rejects_extended_expiry() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+
+        // A relay rewrites expires_at without the author's checksum changing
+        let data = b"short-lived";
+        let item = FetchedItem {
+            item_id: "ttl-2".into(),
+            item_type: "entity".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: item_checksum(data, Some("2020-01-01T00:00:00Z")),
+            author_id: "russell".into(),
+            group_id: "seed-drill".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2019-12-31T00:00:00Z".into(),
+            expires_at: Some("2099-01-01T00:00:00Z".into()),
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        };
+
+        match engine.on_receive(&db, &item, &["seed-drill".into()], None) {
+            ReceiveOutcome::Rejected(reason) => assert!(reason.contains("checksum")),
+            other => panic!("expected Rejected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_on_receive_stores_expiry() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+
+        let data = b"ephemeral";
+        let expiry = "2099-01-01T00:00:00Z";
+        let item = FetchedItem {
+            item_id: "ttl-3".into(),
+            item_type: "entity".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: item_checksum(data, Some(expiry)),
+            author_id: "russell".into(),
+            group_id: "seed-drill".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2026-02-01T00:00:00Z".into(),
+            expires_at: Some(expiry.into()),
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        };
+
+        assert_eq!(
+            engine.on_receive(&db, &item, &["seed-drill".into()], None),
+            ReceiveOutcome::Stored
+        );
+        let stored = db.read_l2_item("ttl-3").unwrap().unwrap();
+        assert_eq!(stored.expires_at.as_deref(), Some(expiry));
+        // Stored checksum matches the wire checksum, so sync sees no diff
+        assert_eq!(stored.checksum.as_deref(), Some(item.checksum.as_str()));
+    }
+
     #[test]
     fn test_on_receive_tombstone_deletes_item() {
         let engine = default_engine();
@@ -551,6 +1040,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
         let result = engine.on_receive(&db, &item, &["seed-drill".into()], None);
         assert_eq!(result, ReceiveOutcome::Stored);
@@ -570,6 +1064,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-09T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
         let result = engine.on_receive(&db, &tombstone, &["seed-drill".into()], None);
         assert_eq!(result, ReceiveOutcome::Stored);
@@ -596,11 +1095,98 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-09T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
         let result = engine.on_receive(&db, &tombstone, &["seed-drill".into()], None);
         assert_eq!(result, ReceiveOutcome::Duplicate); // Already gone
     }
 
+    #[test]
+    fn test_on_receive_tombstone_ordered_like_puts() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        let groups = vec!["seed-drill".to_string()];
+
+        let data = b"rewritten";
+        let item = FetchedItem {
+            item_id: "doc".into(),
+            item_type: "entity".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: checksum(data),
+            author_id: "russell".into(),
+            group_id: "seed-drill".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2026-02-05T00:00:00.000Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: Some("001770249600000-0000000000-n1".into()),
+            ancestry: None,
+        };
+        assert_eq!(
+            engine.on_receive(&db, &item, &groups, None),
+            ReceiveOutcome::Stored
+        );
+
+        // A deletion of an earlier version (stale, or replayed) loses LWW
+        let stale = FetchedItem {
+            item_type: "__tombstone__".into(),
+            encrypted_blob: Vec::new(),
+            checksum: checksum(b""),
+            updated_at: "2026-02-01T00:00:00.000Z".into(),
+            hlc: Some("001769904000000-0000000000-n1".into()),
+            ..item.clone()
+        };
+        assert_eq!(
+            engine.on_receive(&db, &stale, &groups, None),
+            ReceiveOutcome::Duplicate
+        );
+        assert!(db.read_l2_item("doc").unwrap().is_some());
+
+        // Nor can a far-future deletion win by its clock
+        let future = (chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339();
+        let ahead = FetchedItem {
+            updated_at: future,
+            hlc: None,
+            ..stale.clone()
+        };
+        assert!(matches!(
+            engine.on_receive(&db, &ahead, &groups, None),
+            ReceiveOutcome::Rejected(_)
+        ));
+        // A tampered tombstone fails the checksum like any item
+        let tampered = FetchedItem {
+            checksum: checksum(b"x"),
+            updated_at: "2026-02-09T00:00:00.000Z".into(),
+            hlc: None,
+            ..stale.clone()
+        };
+        assert!(matches!(
+            engine.on_receive(&db, &tampered, &groups, None),
+            ReceiveOutcome::Rejected(_)
+        ));
+        assert!(db.read_l2_item("doc").unwrap().is_some());
+
+        // A later deletion applies
+        let newer = FetchedItem {
+            updated_at: "2026-02-09T00:00:00.000Z".into(),
+            hlc: Some("001770595200000-0000000000-n1".into()),
+            ..stale
+        };
+        assert_eq!(
+            engine.on_receive(&db, &newer, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        assert!(db.read_l2_item("doc").unwrap().is_none());
+    }
+
     #[test]
     fn test_on_receive_lww_preserves_original_timestamp() {
         // Regression test: write_l2_item must preserve the source's updated_at
@@ -625,16 +1211,22 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:02Z".into(), // T2
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
         assert_eq!(
             engine.on_receive(&db, &v3, &groups, None),
             ReceiveOutcome::Stored
         );
 
-        // Verify the stored item has the original updated_at, not datetime('now')
+        // Verify the stored item has the original updated_at (in canonical
+        // form), not datetime('now')
         let stored = db.read_l2_item("lww-item").unwrap().unwrap();
         assert_eq!(
-            stored.updated_at, "2026-02-01T00:00:02Z",
+            stored.updated_at, "2026-02-01T00:00:02.000Z",
             "must preserve source timestamp"
         );
 
@@ -654,6 +1246,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-01T00:00:01Z".into(), // T1 < T2
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
         assert_eq!(
             engine.on_receive(&db, &v2, &groups, None),
@@ -667,7 +1264,310 @@ mod tests {
             v3_data.to_vec(),
             "newer version must survive"
         );
-        assert_eq!(final_item.updated_at, "2026-02-01T00:00:02Z");
+        assert_eq!(final_item.updated_at, "2026-02-01T00:00:02.000Z");
+    }
+
+    #[test]
+    fn test_on_receive_clock_skew() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        let groups = vec!["g1".to_string()];
+        let item = |data: &[u8], updated_at: String| FetchedItem {
+            item_id: "skew-item".into(),
+            item_type: "learning".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: checksum(data),
+            author_id: "bravo".into(),
+            group_id: "g1".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at,
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        };
+        let in_secs = |secs: i64| {
+            (chrono::Utc::now() + chrono::Duration::try_seconds(secs).unwrap()).to_rfc3339()
+        };
+
+        // Years ahead: rejected rather than winning every conflict
+        let future = item(b"from-the-future", in_secs(3 * 365 * 86_400));
+        assert!(matches!(
+            engine.on_receive(&db, &future, &groups, None),
+            ReceiveOutcome::Rejected(_)
+        ));
+        let garbled = item(b"garbled", "next tuesday".into());
+        assert!(matches!(
+            engine.on_receive(&db, &garbled, &groups, None),
+            ReceiveOutcome::Rejected(_)
+        ));
+        // Within the allowed skew: accepted
+        let slightly_ahead = item(b"slightly-ahead", in_secs(60));
+        assert_eq!(
+            engine.on_receive(&db, &slightly_ahead, &groups, None),
+            ReceiveOutcome::Stored
+        );
+
+        // Legacy local row in SQLite format: compared as a time, not a string
+        // ("2026-02-01 00:00:03" < "2026-02-01T..." lexicographically)
+        db.write_l2_item(&L2ItemWrite {
+            id: "legacy-item".into(),
+            item_type: "learning".into(),
+            data: b"local-newer".to_vec(),
+            owner_id: None,
+            visibility: "group".into(),
+            group_id: Some("g1".into()),
+            author_id: Some("russell".into()),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: Some("2026-02-01 00:00:03".into()),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        })
+        .unwrap();
+        let older = FetchedItem {
+            item_id: "legacy-item".into(),
+            ..item(b"remote-older", "2026-02-01T00:00:02.5+00:00".into())
+        };
+        assert_eq!(
+            engine.on_receive(&db, &older, &groups, None),
+            ReceiveOutcome::Duplicate
+        );
+        let newer = FetchedItem {
+            item_id: "legacy-item".into(),
+            ..item(b"remote-newer", "2026-02-01T00:00:04+00:00".into())
+        };
+        assert_eq!(
+            engine.on_receive(&db, &newer, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        let stored = db.read_l2_item("legacy-item").unwrap().unwrap();
+        assert_eq!(stored.updated_at, "2026-02-01T00:00:04.000Z");
+    }
+
+    #[test]
+    fn test_on_receive_hlc_order() {
+        let engine = default_engine().with_clock(Arc::new(HlcClock::new("node-local", 300)));
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        let groups = vec!["g1".to_string()];
+        let item = |data: &[u8], hlc: Option<&str>| FetchedItem {
+            item_id: "hlc-item".into(),
+            item_type: "learning".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: checksum(data),
+            author_id: "bravo".into(),
+            group_id: "g1".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2026-02-01T00:00:01.000Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: hlc.map(String::from),
+            ancestry: None,
+        };
+
+        // Pre-HLC copy, then a write in the same millisecond: the HLC wins
+        let legacy = item(b"legacy", None);
+        assert_eq!(
+            engine.on_receive(&db, &legacy, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        let a = item(b"node-a", Some("001769904001000-0000000000-node-a"));
+        assert_eq!(
+            engine.on_receive(&db, &a, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        // Concurrent write, same time and counter: node id breaks the tie,
+        // whichever order the writes arrive in
+        let b = item(b"node-b", Some("001769904001000-0000000000-node-b"));
+        assert_eq!(
+            engine.on_receive(&db, &b, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        assert_eq!(
+            engine.on_receive(&db, &a, &groups, None),
+            ReceiveOutcome::Duplicate
+        );
+        // Higher counter wins over an equal wall clock
+        let a2 = item(b"node-a-2", Some("001769904001000-0000000001-node-a"));
+        assert_eq!(
+            engine.on_receive(&db, &a2, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        let stored = db.read_l2_item("hlc-item").unwrap().unwrap();
+        assert_eq!(stored.data, b"node-a-2".to_vec());
+        assert_eq!(
+            stored.hlc.as_deref(),
+            Some("001769904001000-0000000001-node-a")
+        );
+
+        let garbled = item(b"garbled", Some("not-an-hlc"));
+        assert!(matches!(
+            engine.on_receive(&db, &garbled, &groups, None),
+            ReceiveOutcome::Rejected(_)
+        ));
+    }
+
+    #[test]
+    fn test_on_receive_ancestry_conflicts() {
+        let engine = default_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let db = cordelia_storage::SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        let groups = vec!["g1".to_string()];
+        // bravo owns g1, so its signature vouches for the ancestry
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let bravo = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        db.write_group("g1", "g1", "{}", "{}").unwrap();
+        db.write_group_authority(
+            "g1",
+            &cordelia_storage::GroupAuthority {
+                updated_at: "2026-02-01 00:00:00".into(),
+                owner_id: "bravo".into(),
+                owner_pubkey: hex::encode(bravo.public_key().as_ref()),
+                signature: "sig".into(),
+                signer_pubkey: None,
+                admins: Vec::new(),
+                owner_transfers: None,
+                hlc: None,
+            },
+        )
+        .unwrap();
+        let unsigned = |data: &[u8], logical: u32, node: &str, ancestry: &[&[u8]]| FetchedItem {
+            item_id: "doc".into(),
+            item_type: "learning".into(),
+            encrypted_blob: data.to_vec(),
+            checksum: checksum(data),
+            author_id: "bravo".into(),
+            group_id: "g1".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2026-02-01T00:00:01.000Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: Some(format!("001769904001000-{logical:010}-{node}")),
+            ancestry: Some(ancestry.iter().map(|d| checksum(d)).collect()),
+        };
+        let item = |data: &[u8], logical: u32, node: &str, ancestry: &[&[u8]]| {
+            let mut it = unsigned(data, logical, node, ancestry);
+            it.author_pubkey = Some(hex::encode(bravo.public_key().as_ref()));
+            it.author_signature = Some(hex::encode(bravo.sign(&it.signing_payload()).as_ref()));
+            it
+        };
+        let current =
+            |db: &cordelia_storage::SqliteStorage| db.read_l2_item("doc").unwrap().unwrap().data;
+        let siblings = |db: &cordelia_storage::SqliteStorage| -> Vec<Vec<u8>> {
+            db.list_l2_conflicts(Some("doc"))
+                .unwrap()
+                .into_iter()
+                .map(|c| c.data)
+                .collect()
+        };
+
+        // v1, then its successor fast-forwards; v1 again is stale
+        let v1 = item(b"v1", 0, "node-a", &[]);
+        let v2 = item(b"v2", 1, "node-a", &[b"v1"]);
+        assert_eq!(
+            engine.on_receive(&db, &v1, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        assert_eq!(
+            engine.on_receive(&db, &v2, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        assert_eq!(
+            engine.on_receive(&db, &v1, &groups, None),
+            ReceiveOutcome::Duplicate
+        );
+        assert_eq!(current(&db), b"v2".to_vec());
+        assert!(siblings(&db).is_empty());
+
+        // A successor that skipped versions (missed by anti-entropy) still
+        // fast-forwards, even with an older HLC
+        let v4 = item(b"v4", 0, "node-a", &[b"v3", b"v2", b"v1"]);
+        assert_eq!(
+            engine.on_receive(&db, &v4, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        assert_eq!(current(&db), b"v4".to_vec());
+
+        // Concurrent edit that loses on HLC: kept as a sibling
+        let old_fork = item(b"fork-b", 0, "node-0", &[b"v2", b"v1"]);
+        assert_eq!(
+            engine.on_receive(&db, &old_fork, &groups, None),
+            ReceiveOutcome::Conflict
+        );
+        assert_eq!(current(&db), b"v4".to_vec());
+        assert_eq!(siblings(&db), vec![b"fork-b".to_vec()]);
+        assert_eq!(
+            engine.on_receive(&db, &old_fork, &groups, None),
+            ReceiveOutcome::Duplicate
+        );
+
+        // Concurrent edit that wins on HLC: current, ours becomes a sibling
+        let new_fork = item(b"fork-c", 9, "node-c", &[b"v3"]);
+        assert_eq!(
+            engine.on_receive(&db, &new_fork, &groups, None),
+            ReceiveOutcome::Conflict
+        );
+        assert_eq!(current(&db), b"fork-c".to_vec());
+        let mut kept = siblings(&db);
+        kept.sort();
+        assert_eq!(kept, vec![b"fork-b".to_vec(), b"v4".to_vec()]);
+
+        // A merge descending from everything clears the siblings
+        let merged = item(b"merged", 10, "node-a", &[b"fork-c", b"fork-b", b"v4"]);
+        assert_eq!(
+            engine.on_receive(&db, &merged, &groups, None),
+            ReceiveOutcome::Stored
+        );
+        assert_eq!(current(&db), b"merged".to_vec());
+        assert!(siblings(&db).is_empty());
+
+        // An unsigned claim to succeed our version is only a concurrent
+        // edit: it wins on HLC, but ours is kept as a sibling
+        let forged = unsigned(b"forged", 11, "node-x", &[b"merged"]);
+        assert_eq!(
+            engine.on_receive(&db, &forged, &groups, None),
+            ReceiveOutcome::Conflict
+        );
+        assert_eq!(current(&db), b"forged".to_vec());
+        assert_eq!(siblings(&db), vec![b"merged".to_vec()]);
+        // Nor does re-labelling a signed version's ancestry resolve siblings
+        let mut relabelled = item(b"relabelled", 12, "node-a", &[b"forged"]);
+        relabelled.ancestry = Some(vec![checksum(b"forged"), checksum(b"merged")]);
+        assert_eq!(
+            engine.on_receive(&db, &relabelled, &groups, None),
+            ReceiveOutcome::Conflict
+        );
+        let mut kept = siblings(&db);
+        kept.sort();
+        assert_eq!(kept, vec![b"forged".to_vec(), b"merged".to_vec()]);
+
+        // Ancestry longer than any honest writer keeps is refused
+        let mut padded = item(b"padded", 13, "node-a", &[b"merged"]);
+        padded.ancestry = Some(vec![
+            checksum(b"merged");
+            cordelia_protocol::MAX_ITEM_ANCESTRY + 1
+        ]);
+        assert!(matches!(
+            engine.on_receive(&db, &padded, &groups, None),
+            ReceiveOutcome::Rejected(_)
+        ));
+        assert_eq!(current(&db), b"relabelled".to_vec());
     }
 
     #[test]
@@ -688,6 +1588,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-02-09T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
         let result = engine.on_receive(&db, &tombstone, &["seed-drill".into()], None);
         assert!(matches!(result, ReceiveOutcome::Rejected(_)));
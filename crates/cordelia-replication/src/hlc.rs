@@ -0,0 +1,214 @@
+//! Hybrid logical clock (HLC) -- version stamps for items and group
+//! descriptors.
+//!
+//! An HLC is (physical ms, logical counter, node id), ordered in that order.
+//! Physical time tracks the wall clock; the counter orders events within the
+//! same millisecond (or while the wall clock is behind a peer's); the node id
+//! breaks the remaining ties, so two devices writing in the same instant
+//! still resolve the same way everywhere.
+//!
+//! Encoded as `<physical:015>-<logical:010>-<node>`. Items and descriptors
+//! written before HLCs existed are versioned from their `updated_at` with
+//! counter 0 and an empty node id (see [`Hlc::of`]).
+
+use std::fmt;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use crate::parse_timestamp;
+
+/// A hybrid logical clock reading.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hlc {
+    /// Wall-clock milliseconds since the Unix epoch.
+    pub physical_ms: u64,
+    /// Counter for events sharing `physical_ms`.
+    pub logical: u32,
+    /// Node that produced the reading.
+    pub node: String,
+}
+
+impl Hlc {
+    /// Parse an encoded HLC.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.splitn(3, '-');
+        let physical_ms = parts.next()?.parse().ok()?;
+        let logical = parts.next()?.parse().ok()?;
+        let node = parts.next()?.to_string();
+        Some(Self {
+            physical_ms,
+            logical,
+            node,
+        })
+    }
+
+    /// Version of a pre-HLC record, from its timestamp.
+    pub fn from_timestamp(ts: &str) -> Option<Self> {
+        let physical_ms = u64::try_from(parse_timestamp(ts)?.timestamp_millis()).ok()?;
+        Some(Self {
+            physical_ms,
+            logical: 0,
+            node: String::new(),
+        })
+    }
+
+    /// Version of a stored or received record: its HLC, else derived from
+    /// `updated_at` (records from before HLCs, or from older peers).
+    pub fn of(hlc: Option<&str>, updated_at: &str) -> Option<Self> {
+        hlc.and_then(Self::parse)
+            .or_else(|| Self::from_timestamp(updated_at))
+    }
+
+    /// Wall-clock part as a time.
+    pub fn wall(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.physical_ms as i64).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Hlc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:015}-{:010}-{}",
+            self.physical_ms, self.logical, self.node
+        )
+    }
+}
+
+/// A node's HLC, shared by everything that writes or receives versions.
+pub struct HlcClock {
+    node: String,
+    max_ahead_ms: u64,
+    last: Mutex<Hlc>,
+}
+
+impl HlcClock {
+    /// Clock for `node`. Remote readings more than `max_skew_secs` ahead of
+    /// our wall clock are not merged, so a peer with a fast clock cannot drag
+    /// every later local version into the future.
+    pub fn new(node: impl Into<String>, max_skew_secs: u64) -> Self {
+        let node = node.into();
+        Self {
+            last: Mutex::new(Hlc {
+                physical_ms: 0,
+                logical: 0,
+                node: node.clone(),
+            }),
+            node,
+            max_ahead_ms: max_skew_secs.saturating_mul(1000),
+        }
+    }
+
+    /// Reading for a local write (or send).
+    pub fn now(&self) -> Hlc {
+        self.tick(wall_ms(), None)
+    }
+
+    /// Merge a received reading. Returns `false` (and leaves the clock
+    /// alone) if it is further ahead than the allowed skew.
+    pub fn observe(&self, remote: &Hlc) -> bool {
+        let wall = wall_ms();
+        if remote.physical_ms > wall.saturating_add(self.max_ahead_ms) {
+            return false;
+        }
+        self.tick(wall, Some(remote));
+        true
+    }
+
+    fn tick(&self, wall: u64, remote: Option<&Hlc>) -> Hlc {
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        let remote_ms = remote.map_or(0, |r| r.physical_ms);
+        let physical_ms = wall.max(last.physical_ms).max(remote_ms);
+        let logical = match remote {
+            Some(r) if physical_ms == last.physical_ms && physical_ms == r.physical_ms => {
+                last.logical.max(r.logical) + 1
+            }
+            Some(r) if physical_ms == r.physical_ms && physical_ms != last.physical_ms => {
+                r.logical + 1
+            }
+            _ if physical_ms == last.physical_ms => last.logical + 1,
+            _ => 0,
+        };
+        *last = Hlc {
+            physical_ms,
+            logical,
+            node: self.node.clone(),
+        };
+        last.clone()
+    }
+}
+
+fn wall_ms() -> u64 {
+    u64::try_from(Utc::now().timestamp_millis()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hlc(physical_ms: u64, logical: u32, node: &str) -> Hlc {
+        Hlc {
+            physical_ms,
+            logical,
+            node: node.into(),
+        }
+    }
+
+    #[test]
+    fn test_encoding_and_order() {
+        let a = hlc(1_772_366_400_000, 7, "node-a");
+        let encoded = a.to_string();
+        assert_eq!(encoded, "001772366400000-0000000007-node-a");
+        assert_eq!(Hlc::parse(&encoded), Some(a.clone()));
+        assert!(Hlc::parse("garbage").is_none());
+
+        // Total order: physical, then counter, then node
+        assert!(hlc(1, 9, "z") < hlc(2, 0, "a"));
+        assert!(hlc(2, 0, "z") < hlc(2, 1, "a"));
+        assert!(hlc(2, 1, "a") < hlc(2, 1, "b"));
+        // The encoding sorts the same way
+        assert!(hlc(2, 1, "a").to_string() < hlc(2, 1, "b").to_string());
+        assert!(hlc(9, 0, "a").to_string() < hlc(10, 0, "a").to_string());
+    }
+
+    #[test]
+    fn test_migration_from_timestamps() {
+        let legacy = Hlc::of(None, "2026-03-01 12:00:00").unwrap();
+        assert_eq!(legacy, hlc(1_772_366_400_000, 0, ""));
+        assert_eq!(
+            Hlc::of(None, "2026-03-01T12:00:00.000Z"),
+            Some(legacy.clone())
+        );
+        // An HLC wins over the timestamp; any real write that millisecond
+        // orders after the derived version
+        let stamped = hlc(1_772_366_400_000, 0, "node-a");
+        assert_eq!(
+            Hlc::of(Some(&stamped.to_string()), "1999-01-01 00:00:00"),
+            Some(stamped.clone())
+        );
+        assert!(legacy < stamped);
+        assert_eq!(legacy.wall().to_rfc3339(), "2026-03-01T12:00:00+00:00");
+    }
+
+    #[test]
+    fn test_clock_monotonic_and_merges() {
+        let clock = HlcClock::new("node-a", 300);
+        let first = clock.now();
+        let second = clock.now();
+        assert!(second > first, "same-millisecond writes still order");
+        assert_eq!(second.node, "node-a");
+
+        // A peer slightly ahead: our next reading follows it
+        let remote = hlc(first.physical_ms + 5_000, 3, "node-b");
+        assert!(clock.observe(&remote));
+        let after = clock.now();
+        assert!(after > remote);
+        assert_eq!(after.physical_ms, remote.physical_ms);
+
+        // A peer years ahead is not merged
+        let wild = hlc(first.physical_ms + 3 * 365 * 86_400_000, 0, "node-c");
+        assert!(!clock.observe(&wild));
+        assert!(clock.now() < wild);
+    }
+}
@@ -4,19 +4,33 @@
 //!   - EagerPush (chatty): send full item to all hot group peers
 //!   - Passive (taciturn): do nothing, peers discover on periodic sync
 //!
-//! "moderate" is accepted as a culture string for backward compatibility
-//! but maps to EagerPush (chatty). See docs/design/replication-routing.md
+//! "moderate" is accepted as an alias for backward compatibility and maps
+//! to EagerPush (chatty). See docs/design/replication-routing.md
 //! Section 10 for rationale.
 //!
 //! Anti-entropy sync runs per-group at culture-determined intervals.
 
+use chrono::{DateTime, NaiveDateTime, Utc};
 use cordelia_protocol::messages::{FetchedItem, ItemHeader};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+pub mod culture;
+pub mod departure;
 pub mod engine;
+pub mod hlc;
+pub mod membership;
+pub mod posture;
+pub mod roles;
+pub mod security;
 
+pub use cordelia_storage::item_checksum;
+pub use departure::DeparturePolicy;
 pub use engine::ReplicationEngine;
+pub use membership::MembershipState;
+pub use posture::Posture;
+pub use roles::{GroupAction, Role};
+pub use security::SecurityPolicy;
 
 /// Replication strategy derived from group culture.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -44,6 +58,8 @@ pub struct ReplicationConfig {
     pub sync_interval_taciturn_secs: u64,
     pub tombstone_retention_days: u32,
     pub max_batch_size: u32,
+    /// Reject received items dated more than this far in the future.
+    pub max_clock_skew_secs: u64,
 }
 
 impl Default for ReplicationConfig {
@@ -52,47 +68,85 @@ impl Default for ReplicationConfig {
             sync_interval_taciturn_secs: cordelia_protocol::SYNC_INTERVAL_TACITURN_SECS,
             tombstone_retention_days: cordelia_protocol::TOMBSTONE_RETENTION_DAYS,
             max_batch_size: cordelia_protocol::MAX_BATCH_SIZE,
+            max_clock_skew_secs: cordelia_protocol::MAX_CLOCK_SKEW_SECS,
         }
     }
 }
 
 /// Group culture configuration (parsed from groups.culture JSON).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields are declared in key order: the canonical form (see [`culture`])
+/// is this struct serialized as compact JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct GroupCulture {
-    #[serde(default = "default_eagerness")]
-    pub broadcast_eagerness: String,
-    pub ttl_default: Option<u64>,
     #[serde(default)]
-    pub notification_policy: Option<String>,
-    #[serde(default)]
-    pub departure_policy: Option<String>,
+    pub broadcast_eagerness: Eagerness,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub departure_policy: Option<DeparturePolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_policy: Option<NotificationPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_default: Option<u64>,
+    #[serde(default = "culture::current_version")]
+    pub version: u32,
 }
 
-fn default_eagerness() -> String {
-    "chatty".into()
+/// Parsed `GroupCulture.broadcast_eagerness`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Eagerness {
+    /// Push every write to all hot group peers ("moderate" is a deprecated alias).
+    #[default]
+    #[serde(alias = "moderate")]
+    Chatty,
+    /// No push; peers discover writes on periodic sync.
+    Taciturn,
 }
 
 impl GroupCulture {
     /// Determine replication strategy from culture.
-    ///
-    /// "moderate" is accepted for backward compatibility but maps to
-    /// EagerPush (chatty). See docs/design/replication-routing.md Section 10.
     pub fn strategy(&self) -> ReplicationStrategy {
-        match self.broadcast_eagerness.as_str() {
-            "chatty" | "moderate" => ReplicationStrategy::EagerPush,
-            "taciturn" => ReplicationStrategy::Passive,
-            _ => ReplicationStrategy::EagerPush, // safe default
+        match self.broadcast_eagerness {
+            Eagerness::Chatty => ReplicationStrategy::EagerPush,
+            Eagerness::Taciturn => ReplicationStrategy::Passive,
         }
     }
+
+    /// Notification policy (default: push).
+    pub fn notification(&self) -> NotificationPolicy {
+        self.notification_policy.unwrap_or(NotificationPolicy::Push)
+    }
+
+    /// Expiry for a new item under this culture's `ttl_default`, as RFC 3339.
+    pub fn default_expiry(&self, now: DateTime<Utc>) -> Option<String> {
+        let ttl = self.ttl_default?;
+        let secs = i64::try_from(ttl).ok()?;
+        let expires = now.checked_add_signed(chrono::Duration::try_seconds(secs)?)?;
+        Some(expires.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+    }
+}
+
+/// Parsed `GroupCulture.notification_policy` -- which change events a group emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationPolicy {
+    /// Full event per change (item id, type, author).
+    Push,
+    /// Content-free activity marker; subscribers poll for details.
+    Notify,
+    /// No events. Members must poll.
+    Silent,
 }
 
 impl Default for GroupCulture {
     fn default() -> Self {
         Self {
-            broadcast_eagerness: "chatty".into(),
-            ttl_default: None,
-            notification_policy: None,
+            broadcast_eagerness: Eagerness::Chatty,
             departure_policy: None,
+            notification_policy: None,
+            ttl_default: None,
+            version: culture::CULTURE_VERSION,
         }
     }
 }
@@ -104,6 +158,9 @@ pub enum ReceiveOutcome {
     Stored,
     /// Item already exists with same checksum -- skipped.
     Duplicate,
+    /// Concurrent edit: both versions kept (the HLC winner current, the
+    /// other as a conflict sibling).
+    Conflict,
     /// Item rejected (not a member of the group, or private).
     Rejected(String),
 }
@@ -115,9 +172,56 @@ pub fn checksum(data: &[u8]) -> String {
     hex::encode(hasher.finalize())
 }
 
-/// Validate that a received item's checksum matches its blob.
+/// Validate that a received item's checksum matches its blob (and expiry).
 pub fn validate_checksum(item: &FetchedItem) -> bool {
-    checksum(&item.encrypted_blob) == item.checksum
+    item_checksum(&item.encrypted_blob, item.expires_at.as_deref()) == item.checksum
+}
+
+/// Parse a stored timestamp: RFC 3339 (replicated) or SQLite `datetime('now')` (local).
+pub fn parse_timestamp(ts: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(ts) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// Format a time as a canonical item timestamp: RFC 3339 UTC with
+/// millisecond precision (`2026-03-01T12:00:00.000Z`). The width is fixed,
+/// so string order is time order; SQLite writes the same format with
+/// `strftime('%Y-%m-%dT%H:%M:%fZ', 'now')`.
+pub fn format_timestamp(ts: DateTime<Utc>) -> String {
+    ts.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+/// Re-format any parseable timestamp canonically.
+pub fn canonical_timestamp(ts: &str) -> Option<String> {
+    parse_timestamp(ts).map(format_timestamp)
+}
+
+/// Whether an expiry timestamp has passed. Unparseable expiries count as
+/// expired so a malformed value cannot keep an item alive forever.
+pub fn is_expired(expires_at: Option<&str>, now: DateTime<Utc>) -> bool {
+    match expires_at {
+        None => false,
+        Some(ts) => parse_timestamp(ts).is_none_or(|exp| exp <= now),
+    }
+}
+
+/// Whether a remote header is past its expiry, or older than the group's
+/// `ttl_default` when it carries no explicit expiry. Such items are not fetched.
+pub fn header_expired(header: &ItemHeader, ttl_default: Option<u64>, now: DateTime<Utc>) -> bool {
+    if header.expires_at.is_some() {
+        return is_expired(header.expires_at.as_deref(), now);
+    }
+    match (ttl_default, parse_timestamp(&header.updated_at)) {
+        (Some(ttl), Some(updated)) => {
+            let age = now.signed_duration_since(updated).num_seconds();
+            age >= 0 && age as u64 >= ttl
+        }
+        _ => false,
+    }
 }
 
 /// Compare local and remote headers to find items needing fetch.
@@ -149,16 +253,13 @@ mod tests {
     #[test]
     fn test_culture_strategy() {
         let chatty = GroupCulture {
-            broadcast_eagerness: "chatty".into(),
+            broadcast_eagerness: Eagerness::Chatty,
             ..Default::default()
         };
         assert_eq!(chatty.strategy(), ReplicationStrategy::EagerPush);
 
         // "moderate" maps to EagerPush (deprecated, backward compat)
-        let moderate = GroupCulture {
-            broadcast_eagerness: "moderate".into(),
-            ..Default::default()
-        };
+        let moderate = GroupCulture::load(r#"{"broadcast_eagerness":"moderate"}"#);
         assert_eq!(moderate.strategy(), ReplicationStrategy::EagerPush);
 
         // Default culture is chatty
@@ -166,7 +267,7 @@ mod tests {
         assert_eq!(default.strategy(), ReplicationStrategy::EagerPush);
 
         let taciturn = GroupCulture {
-            broadcast_eagerness: "taciturn".into(),
+            broadcast_eagerness: Eagerness::Taciturn,
             ..Default::default()
         };
         assert_eq!(taciturn.strategy(), ReplicationStrategy::Passive);
@@ -186,6 +287,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: "2026-01-29T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         assert!(validate_checksum(&item));
@@ -207,6 +313,7 @@ mod tests {
                 updated_at: "2026-01-01".into(),
                 author_id: "r".into(),
                 is_deletion: false,
+                expires_at: None,
             },
             ItemHeader {
                 item_id: "b".into(),
@@ -215,6 +322,7 @@ mod tests {
                 updated_at: "2026-01-01".into(),
                 author_id: "r".into(),
                 is_deletion: false,
+                expires_at: None,
             },
         ];
 
@@ -226,6 +334,7 @@ mod tests {
                 updated_at: "2026-01-01".into(),
                 author_id: "r".into(),
                 is_deletion: false,
+                expires_at: None,
             },
             ItemHeader {
                 item_id: "b".into(),
@@ -234,6 +343,7 @@ mod tests {
                 updated_at: "2026-01-02".into(),
                 author_id: "r".into(),
                 is_deletion: false,
+                expires_at: None,
             },
             ItemHeader {
                 item_id: "c".into(),
@@ -242,6 +352,7 @@ mod tests {
                 updated_at: "2026-01-01".into(),
                 author_id: "m".into(),
                 is_deletion: false,
+                expires_at: None,
             },
         ];
 
@@ -249,6 +360,87 @@ mod tests {
         assert_eq!(needed, vec!["b", "c"]);
     }
 
+    #[test]
+    fn test_canonical_timestamp() {
+        let local = canonical_timestamp("2026-03-01 12:00:00").unwrap();
+        let pushed = canonical_timestamp("2026-03-01T12:00:00.000000123+00:00").unwrap();
+        assert_eq!(local, "2026-03-01T12:00:00.000Z");
+        assert_eq!(pushed, local);
+        assert_eq!(
+            canonical_timestamp("2026-03-01T14:00:00.5+02:00").as_deref(),
+            Some("2026-03-01T12:00:00.500Z")
+        );
+        assert!(canonical_timestamp("soon").is_none());
+        // Fixed width: lexicographic order is time order
+        assert!(
+            canonical_timestamp("2026-03-01 12:00:01").unwrap()
+                > canonical_timestamp("2026-03-01T12:00:00.999Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_expiry_helpers() {
+        let now = parse_timestamp("2026-03-01T12:00:00Z").unwrap();
+
+        // Both timestamp formats in the tree parse
+        assert_eq!(parse_timestamp("2026-03-01 12:00:00"), Some(now));
+        assert!(parse_timestamp("yesterday").is_none());
+
+        assert!(!is_expired(None, now));
+        assert!(!is_expired(Some("2026-03-01T12:00:01Z"), now));
+        assert!(is_expired(Some("2026-03-01T12:00:00Z"), now));
+        // Unparseable expiry fails closed
+        assert!(is_expired(Some("never"), now));
+
+        let culture = GroupCulture {
+            ttl_default: Some(3600),
+            ..Default::default()
+        };
+        assert_eq!(
+            culture.default_expiry(now).as_deref(),
+            Some("2026-03-01T13:00:00Z")
+        );
+        assert!(GroupCulture::default().default_expiry(now).is_none());
+
+        let header = ItemHeader {
+            item_id: "a".into(),
+            item_type: "entity".into(),
+            checksum: "h".into(),
+            updated_at: "2026-03-01 10:00:00".into(),
+            author_id: "r".into(),
+            is_deletion: false,
+            expires_at: None,
+        };
+        assert!(!header_expired(&header, None, now));
+        assert!(header_expired(&header, Some(3600), now));
+        assert!(!header_expired(&header, Some(86400), now));
+
+        // Explicit expiry takes precedence over the group default
+        let explicit = ItemHeader {
+            expires_at: Some("2026-03-02T00:00:00Z".into()),
+            ..header
+        };
+        assert!(!header_expired(&explicit, Some(3600), now));
+    }
+
+    #[test]
+    fn test_notification_policy() {
+        assert_eq!(
+            GroupCulture::default().notification(),
+            NotificationPolicy::Push
+        );
+        let culture = GroupCulture::parse(
+            r#"{"broadcast_eagerness":"chatty","notification_policy":"silent"}"#,
+        )
+        .unwrap();
+        assert_eq!(culture.notification(), NotificationPolicy::Silent);
+        let notify = GroupCulture {
+            notification_policy: Some(NotificationPolicy::Notify),
+            ..Default::default()
+        };
+        assert_eq!(notify.notification(), NotificationPolicy::Notify);
+    }
+
     #[test]
     fn test_sync_intervals() {
         assert_eq!(
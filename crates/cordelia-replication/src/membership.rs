@@ -0,0 +1,840 @@
+//! Membership log -- owner/admin-signed membership operations, replicated
+//! alongside group descriptors and replayed into `group_members`.
+//!
+//! Each entry names the log heads its signer held (`prev`), so the log is
+//! a DAG. Every node replays it in causal order -- an entry after
+//! everything it names, concurrent entries by (`created_at`, `entry_id`)
+//! -- from the same starting state (the descriptor's owner, implicitly
+//! `owner`), so nodes holding the same entries agree on membership. An
+//! entry whose `prev` names an entry not held yet waits for it. An entry
+//! takes effect only if, at its point in the replay:
+//!
+//! - it is signed by the owner key stored on `groups`, or by an `admin`
+//!   whose `member_pubkey` an earlier entry recorded;
+//! - an admin signer neither grants `admin` nor touches an owner or
+//!   another admin;
+//! - it does not remove or demote the owner (ownership is not a
+//!   membership op);
+//! - its signer is not an admin removed or demoted by a chained entry
+//!   that neither follows it nor precedes it. A removed admin cannot
+//!   backdate `created_at` to slip entries in ahead of the removal; the
+//!   cost is that entries the admin signed before the removal but which
+//!   its signer had not yet seen are dropped too.
+//!
+//! After an ownership transfer, entries signed by an earlier owner key
+//! keep their authority only if the handover's `log_heads` are or follow
+//! them (older transfers without an HLC: if dated up to the handover), and
+//! earlier owners' member rows become `admin`.
+//!
+//! Signatures are checked by the caller on receipt (see `cordelia-node`);
+//! this module only decides authority and applies the outcome.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap};
+
+use cordelia_protocol::messages::{
+    MembershipEntry, OwnershipTransfer, MEMBERSHIP_OP_ADD, MEMBERSHIP_OP_REMOVE, MEMBERSHIP_OP_ROLE,
+};
+use cordelia_storage::{MembershipLogRow, Storage, StorageError};
+
+use crate::roles::Role;
+
+/// A member as established by the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMember {
+    pub role: Role,
+    /// Node key recorded for the member (lets admins sign entries).
+    pub pubkey: Option<String>,
+}
+
+/// Membership of one group after replaying some prefix of its log.
+#[derive(Debug, Clone)]
+pub struct MembershipState {
+    owner_id: String,
+    owner_pubkey: String,
+    /// Entities the log has mentioned; `None` = removed.
+    members: BTreeMap<String, Option<LogMember>>,
+    /// Earlier owner keys and where each one's authority ends.
+    former_owners: Vec<(String, Handover)>,
+}
+
+/// Where an earlier owner key's authority over log entries ends.
+#[derive(Debug, Clone)]
+enum Handover {
+    /// Older transfer: entries dated up to `transferred_at`.
+    Until(String),
+    /// Entries the handover's log heads are or follow. Holds the heads
+    /// until a replay adds their ancestors.
+    Covering(BTreeSet<String>),
+}
+
+impl MembershipState {
+    /// Initial state: the descriptor's owner, nothing else.
+    pub fn new(owner_id: &str, owner_pubkey: &str) -> Self {
+        let mut members = BTreeMap::new();
+        members.insert(
+            owner_id.to_string(),
+            Some(LogMember {
+                role: Role::Owner,
+                pubkey: Some(owner_pubkey.to_string()),
+            }),
+        );
+        Self {
+            owner_id: owner_id.to_string(),
+            owner_pubkey: owner_pubkey.to_string(),
+            members,
+            former_owners: Vec::new(),
+        }
+    }
+
+    /// Also accept entries signed by earlier owners, up to their handover.
+    pub fn with_transfers(mut self, transfers: &[OwnershipTransfer]) -> Self {
+        self.former_owners = transfers
+            .iter()
+            .map(|t| {
+                let handover = match t.hlc {
+                    Some(_) => Handover::Covering(t.log_heads.iter().cloned().collect()),
+                    None => Handover::Until(t.transferred_at.clone()),
+                };
+                (t.from_pubkey.clone(), handover)
+            })
+            .collect();
+        self
+    }
+
+    /// Replay `entries` (any order) from the owner-only state. Entries
+    /// without authority at their point in the replay are skipped.
+    pub fn replay(owner_id: &str, owner_pubkey: &str, entries: &[MembershipLogRow]) -> Self {
+        Self::new(owner_id, owner_pubkey).replayed(entries)
+    }
+
+    /// Replay `entries` (any order) onto this state.
+    pub fn replayed(self, entries: &[MembershipLogRow]) -> Self {
+        self.replay_accepting(entries).0
+    }
+
+    /// Replay `entries` (any order) onto this state, returning it with the
+    /// entries that took effect, in replay order.
+    fn replay_accepting(mut self, entries: &[MembershipLogRow]) -> (Self, Vec<&MembershipLogRow>) {
+        let order = causal_order(entries);
+        let by_id: HashMap<&str, &MembershipLogRow> =
+            order.iter().map(|e| (e.entry_id.as_str(), *e)).collect();
+        for (_, handover) in &mut self.former_owners {
+            if let Handover::Covering(covered) = handover {
+                let heads: Vec<String> = covered.iter().cloned().collect();
+                for head in heads {
+                    if let Some(entry) = by_id.get(head.as_str()) {
+                        covered.extend(ancestors(&by_id, entry));
+                    }
+                }
+            }
+        }
+
+        // Chained entries that end an admin's authority
+        let mut probe = self.clone();
+        let mut revocations: Vec<&MembershipLogRow> = Vec::new();
+        for entry in &order {
+            let was_admin = probe.role_of(&entry.entity_id) == Some(Role::Admin);
+            if probe.apply(entry).is_ok()
+                && was_admin
+                && probe.role_of(&entry.entity_id) != Some(Role::Admin)
+                && !entry.prev.is_empty()
+            {
+                revocations.push(entry);
+            }
+        }
+
+        let mut state = self;
+        let mut accepted = Vec::new();
+        for entry in order {
+            let concurrent = revocations.iter().find(|r| {
+                r.entity_id == entry.signer_id
+                    && !follows(&by_id, entry, &r.entry_id)
+                    && !follows(&by_id, r, &entry.entry_id)
+            });
+            let result = match concurrent {
+                Some(r) => Err(format!(
+                    "signer '{}' was concurrently revoked by entry {}",
+                    entry.signer_id, r.entry_id
+                )),
+                None => state.apply(entry),
+            };
+            match result {
+                Ok(()) => accepted.push(entry),
+                Err(reason) => tracing::debug!(
+                    group_id = entry.group_id,
+                    entry_id = entry.entry_id,
+                    reason,
+                    "repl: skipping unauthorized membership entry"
+                ),
+            }
+        }
+        (state, accepted)
+    }
+
+    /// Role of `entity_id`, if currently a member.
+    pub fn role_of(&self, entity_id: &str) -> Option<Role> {
+        self.members
+            .get(entity_id)
+            .and_then(|m| m.as_ref())
+            .map(|m| m.role)
+    }
+
+    /// Node key recorded for `entity_id`, if currently a member.
+    pub fn pubkey_of(&self, entity_id: &str) -> Option<&str> {
+        self.members
+            .get(entity_id)
+            .and_then(|m| m.as_ref())
+            .and_then(|m| m.pubkey.as_deref())
+    }
+
+    /// Every entity the log has mentioned (`None` = removed).
+    pub fn members(&self) -> &BTreeMap<String, Option<LogMember>> {
+        &self.members
+    }
+
+    /// Whether `entry` may take effect in the current state.
+    pub fn authorize(&self, entry: &MembershipLogRow) -> Result<(), String> {
+        let granted = match entry.op.as_str() {
+            MEMBERSHIP_OP_ADD | MEMBERSHIP_OP_ROLE => {
+                let role = entry.role.as_deref().unwrap_or_default();
+                Some(Role::parse(role).ok_or_else(|| format!("unknown role '{role}'"))?)
+            }
+            MEMBERSHIP_OP_REMOVE => None,
+            other => return Err(format!("unknown membership op '{other}'")),
+        };
+        if entry.op == MEMBERSHIP_OP_ROLE && self.role_of(&entry.entity_id).is_none() {
+            return Err(format!("'{}' is not a member", entry.entity_id));
+        }
+        if entry.entity_id == self.owner_id {
+            return Err("the owner cannot be removed or change role".into());
+        }
+        if granted == Some(Role::Owner) {
+            return Err("owner cannot be granted via the membership log".into());
+        }
+
+        if entry.signer_pubkey == self.owner_pubkey && entry.signer_id == self.owner_id {
+            return Ok(());
+        }
+        if self.signed_by_former_owner(entry) {
+            return Ok(());
+        }
+        let signer = self
+            .members
+            .get(&entry.signer_id)
+            .and_then(|m| m.as_ref())
+            .filter(|m| m.pubkey.as_deref() == Some(entry.signer_pubkey.as_str()));
+        match signer.map(|m| m.role) {
+            Some(Role::Owner) => Ok(()),
+            Some(Role::Admin) => match self.role_of(&entry.entity_id) {
+                Some(Role::Owner | Role::Admin) => {
+                    Err("admins cannot change owners or other admins".into())
+                }
+                _ if granted == Some(Role::Admin) => Err("admins cannot grant admin".into()),
+                _ => Ok(()),
+            },
+            _ => Err(format!(
+                "signer '{}' is not the owner or an admin with a recorded key",
+                entry.signer_id
+            )),
+        }
+    }
+
+    /// Whether an earlier owner key signed `entry` before handing over.
+    fn signed_by_former_owner(&self, entry: &MembershipLogRow) -> bool {
+        self.former_owners.iter().any(|(pubkey, handover)| {
+            *pubkey == entry.signer_pubkey
+                && match handover {
+                    Handover::Covering(covered) => covered.contains(&entry.entry_id),
+                    Handover::Until(until) => crate::parse_timestamp(&entry.created_at)
+                        .zip(crate::parse_timestamp(until))
+                        .is_some_and(|(created, until)| created <= until),
+                }
+        })
+    }
+
+    /// Authorize and apply one entry.
+    pub fn apply(&mut self, entry: &MembershipLogRow) -> Result<(), String> {
+        self.authorize(entry)?;
+        let member = match entry.op.as_str() {
+            MEMBERSHIP_OP_REMOVE => None,
+            _ => {
+                let prior_key = self
+                    .members
+                    .get(&entry.entity_id)
+                    .and_then(|m| m.as_ref())
+                    .and_then(|m| m.pubkey.clone());
+                Some(LogMember {
+                    // authorize() checked the role parses
+                    role: Role::parse(entry.role.as_deref().unwrap_or_default())
+                        .unwrap_or(Role::Member),
+                    pubkey: entry.member_pubkey.clone().or(prior_key),
+                })
+            }
+        };
+        self.members.insert(entry.entity_id.clone(), member);
+        Ok(())
+    }
+}
+
+/// Entries in replay order: each after every entry its `prev` names,
+/// ready entries by (`created_at`, `entry_id`). Duplicates are dropped, as
+/// are entries naming an entry not in `entries` (and their descendants).
+fn causal_order(entries: &[MembershipLogRow]) -> Vec<&MembershipLogRow> {
+    let by_id: BTreeMap<&str, &MembershipLogRow> =
+        entries.iter().map(|e| (e.entry_id.as_str(), e)).collect();
+    let mut waiting: HashMap<&str, usize> = HashMap::new();
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut ready = BinaryHeap::new();
+    for (id, entry) in &by_id {
+        let parents: BTreeSet<&str> = entry.prev.iter().map(String::as_str).collect();
+        if parents.iter().any(|p| !by_id.contains_key(p)) {
+            continue;
+        }
+        if parents.is_empty() {
+            ready.push(Reverse((entry.created_at.as_str(), *id)));
+        }
+        waiting.insert(id, parents.len());
+        for parent in parents {
+            children.entry(parent).or_default().push(id);
+        }
+    }
+
+    let mut order = Vec::with_capacity(by_id.len());
+    while let Some(Reverse((_, id))) = ready.pop() {
+        order.push(by_id[id]);
+        for child in children.get(id).into_iter().flatten() {
+            let Some(count) = waiting.get_mut(child) else {
+                continue;
+            };
+            *count -= 1;
+            if *count == 0 {
+                ready.push(Reverse((by_id[child].created_at.as_str(), *child)));
+            }
+        }
+    }
+    order
+}
+
+/// Whether `entry` follows `ancestor_id` through its `prev` links.
+fn follows(
+    by_id: &HashMap<&str, &MembershipLogRow>,
+    entry: &MembershipLogRow,
+    ancestor_id: &str,
+) -> bool {
+    ancestors(by_id, entry).contains(ancestor_id)
+}
+
+/// IDs of every entry `entry` follows through its `prev` links.
+fn ancestors(
+    by_id: &HashMap<&str, &MembershipLogRow>,
+    entry: &MembershipLogRow,
+) -> BTreeSet<String> {
+    let mut seen = BTreeSet::new();
+    let mut stack: Vec<&str> = entry.prev.iter().map(String::as_str).collect();
+    while let Some(id) = stack.pop() {
+        if seen.insert(id.to_string()) {
+            if let Some(parent) = by_id.get(id) {
+                stack.extend(parent.prev.iter().map(String::as_str));
+            }
+        }
+    }
+    seen
+}
+
+/// IDs of the entries in `log` that no other entry names in `prev`: what
+/// a new entry should follow.
+pub fn heads(log: &[MembershipLogRow]) -> Vec<String> {
+    let followed: BTreeSet<&str> = log
+        .iter()
+        .flat_map(|e| e.prev.iter().map(String::as_str))
+        .collect();
+    let heads: BTreeSet<&str> = log
+        .iter()
+        .map(|e| e.entry_id.as_str())
+        .filter(|id| !followed.contains(id))
+        .collect();
+    heads.into_iter().map(str::to_string).collect()
+}
+
+/// Storage row for a wire entry (computes `entry_id`).
+pub fn to_row(entry: &MembershipEntry) -> MembershipLogRow {
+    MembershipLogRow {
+        entry_id: entry.entry_id(),
+        group_id: entry.group_id.clone(),
+        op: entry.op.clone(),
+        entity_id: entry.entity_id.clone(),
+        role: entry.role.clone(),
+        member_pubkey: entry.member_pubkey.clone(),
+        created_at: entry.created_at.clone(),
+        signer_id: entry.signer_id.clone(),
+        signer_pubkey: entry.signer_pubkey.clone(),
+        signature: entry.signature.clone(),
+        prev: entry.prev.clone(),
+    }
+}
+
+/// Wire entry for a storage row.
+pub fn to_entry(row: &MembershipLogRow) -> MembershipEntry {
+    MembershipEntry {
+        group_id: row.group_id.clone(),
+        op: row.op.clone(),
+        entity_id: row.entity_id.clone(),
+        role: row.role.clone(),
+        member_pubkey: row.member_pubkey.clone(),
+        created_at: row.created_at.clone(),
+        signer_id: row.signer_id.clone(),
+        signer_pubkey: row.signer_pubkey.clone(),
+        signature: row.signature.clone(),
+        prev: row.prev.clone(),
+    }
+}
+
+/// Parse a stored ownership handover chain (absent or invalid = none).
+pub fn parse_transfers(owner_transfers: Option<&str>) -> Vec<OwnershipTransfer> {
+    owner_transfers
+        .and_then(|t| serde_json::from_str(t).ok())
+        .unwrap_or_default()
+}
+
+/// Owner-only starting state of a group, from its stored descriptor
+/// fields, or None if the group has no known owner key yet.
+fn initial_state(
+    storage: &dyn Storage,
+    group_id: &str,
+) -> Result<Option<MembershipState>, StorageError> {
+    let Some(group) = storage.read_group(group_id)? else {
+        return Ok(None);
+    };
+    let transfers = parse_transfers(group.owner_transfers.as_deref());
+    Ok(group
+        .owner_id
+        .zip(group.owner_pubkey)
+        .map(|(id, key)| MembershipState::new(&id, &key).with_transfers(&transfers)))
+}
+
+/// Current log-derived membership of a group, or None if the group has no
+/// known owner key yet.
+pub fn state_of(
+    storage: &dyn Storage,
+    group_id: &str,
+) -> Result<Option<MembershipState>, StorageError> {
+    let Some(state) = initial_state(storage, group_id)? else {
+        return Ok(None);
+    };
+    let log = storage.list_membership_log(group_id)?;
+    Ok(Some(state.replayed(&log)))
+}
+
+/// Append the entries of `incoming` (one group, signatures already
+/// verified) that have authority when replayed together with the stored
+/// log. Entries for groups without a known owner key are dropped, as are
+/// entries following one not held yet (a later sync brings both).
+/// Returns how many were new.
+pub fn ingest(
+    storage: &dyn Storage,
+    group_id: &str,
+    incoming: &[MembershipLogRow],
+) -> Result<usize, StorageError> {
+    let Some(state) = initial_state(storage, group_id)? else {
+        return Ok(0);
+    };
+    let mut all = storage.list_membership_log(group_id)?;
+    all.extend(incoming.iter().filter(|e| e.group_id == group_id).cloned());
+
+    let mut appended = 0;
+    for entry in state.replay_accepting(&all).1 {
+        if storage.append_membership_entry(entry)? {
+            appended += 1;
+        }
+    }
+    Ok(appended)
+}
+
+/// Bring `group_members` in line with the replayed log. Only entities the
+/// log mentions are touched, and only when their row differs (so local
+/// postures survive); after a transfer, other `owner` rows become `admin`.
+/// Returns the entities whose membership changed.
+pub fn apply(storage: &dyn Storage, group_id: &str) -> Result<Vec<String>, StorageError> {
+    let Some(state) = state_of(storage, group_id)? else {
+        return Ok(Vec::new());
+    };
+    let transferred = !state.former_owners.is_empty();
+    if !transferred && storage.list_membership_log(group_id)?.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut changed = Vec::new();
+    if transferred {
+        for row in storage.list_members(group_id)? {
+            if row.role == Role::Owner.as_str() && row.entity_id != state.owner_id {
+                storage.add_member(group_id, &row.entity_id, Role::Admin.as_str())?;
+                changed.push(row.entity_id);
+            }
+        }
+    }
+    for (entity_id, member) in state.members() {
+        let current = storage.get_membership(group_id, entity_id)?;
+        match (member, current) {
+            (Some(m), Some(row)) if row.role == m.role.as_str() => {}
+            (Some(m), _) => {
+                storage.add_member(group_id, entity_id, m.role.as_str())?;
+                changed.push(entity_id.clone());
+            }
+            (None, Some(_)) => {
+                storage.remove_member(group_id, entity_id)?;
+                changed.push(entity_id.clone());
+            }
+            (None, None) => {}
+        }
+    }
+    if !changed.is_empty() {
+        tracing::info!(group_id, changed = ?changed, "repl: applied membership log");
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        op: &str,
+        entity: &str,
+        role: Option<&str>,
+        at: &str,
+        signer: (&str, &str),
+    ) -> MembershipLogRow {
+        let wire = MembershipEntry {
+            group_id: "grp".into(),
+            op: op.into(),
+            entity_id: entity.into(),
+            role: role.map(str::to_string),
+            member_pubkey: Some(format!("key-{entity}")),
+            created_at: at.into(),
+            signer_id: signer.0.into(),
+            signer_pubkey: signer.1.into(),
+            signature: "sig".into(),
+            prev: Vec::new(),
+        };
+        to_row(&wire)
+    }
+
+    /// `entry` re-signed after `prev`.
+    fn after(entry: MembershipLogRow, prev: &[&MembershipLogRow]) -> MembershipLogRow {
+        let mut wire = to_entry(&entry);
+        wire.prev = prev.iter().map(|e| e.entry_id.clone()).collect();
+        to_row(&wire)
+    }
+
+    const OWNER: (&str, &str) = ("russell", "key-russell");
+
+    #[test]
+    fn test_replay_is_order_independent() {
+        let add = entry(
+            "add",
+            "alice",
+            Some("member"),
+            "2026-02-01T00:00:00Z",
+            OWNER,
+        );
+        let promote = entry(
+            "role",
+            "alice",
+            Some("admin"),
+            "2026-02-02T00:00:00Z",
+            OWNER,
+        );
+        let remove = entry("remove", "alice", None, "2026-02-03T00:00:00Z", OWNER);
+
+        let forward =
+            MembershipState::replay("russell", "key-russell", &[add.clone(), promote.clone()]);
+        let backward =
+            MembershipState::replay("russell", "key-russell", &[promote.clone(), add.clone()]);
+        assert_eq!(forward.role_of("alice"), Some(Role::Admin));
+        assert_eq!(backward.role_of("alice"), Some(Role::Admin));
+        assert_eq!(forward.role_of("russell"), Some(Role::Owner));
+
+        let removed = MembershipState::replay("russell", "key-russell", &[remove, add, promote]);
+        assert_eq!(removed.role_of("alice"), None);
+        assert_eq!(removed.members().get("alice"), Some(&None));
+    }
+
+    #[test]
+    fn test_authority() {
+        let owner_adds_admin = entry("add", "alice", Some("admin"), "2026-02-01T00:00:00Z", OWNER);
+        let admin = ("alice", "key-alice");
+        let admin_adds_bob = entry("add", "bob", Some("member"), "2026-02-02T00:00:00Z", admin);
+        let admin_grants_admin =
+            entry("add", "carol", Some("admin"), "2026-02-02T00:00:00Z", admin);
+        let admin_removes_owner = entry("remove", "russell", None, "2026-02-02T00:00:00Z", admin);
+        let stranger = entry(
+            "add",
+            "mallory",
+            Some("admin"),
+            "2026-02-02T00:00:00Z",
+            ("mallory", "key-mallory"),
+        );
+        let wrong_key = entry(
+            "add",
+            "dave",
+            Some("member"),
+            "2026-02-02T00:00:00Z",
+            ("alice", "forged"),
+        );
+        let grant_owner = entry(
+            "role",
+            "alice",
+            Some("owner"),
+            "2026-02-03T00:00:00Z",
+            OWNER,
+        );
+
+        let state = MembershipState::replay(
+            "russell",
+            "key-russell",
+            &[
+                owner_adds_admin,
+                admin_adds_bob,
+                admin_grants_admin,
+                admin_removes_owner,
+                stranger,
+                wrong_key,
+                grant_owner,
+            ],
+        );
+        assert_eq!(state.role_of("alice"), Some(Role::Admin));
+        assert_eq!(state.role_of("bob"), Some(Role::Member));
+        assert_eq!(state.role_of("carol"), None);
+        assert_eq!(state.role_of("russell"), Some(Role::Owner));
+        assert_eq!(state.role_of("mallory"), None);
+        assert_eq!(state.role_of("dave"), None);
+
+        // Admin authority ends with the admin role
+        let demote = entry(
+            "role",
+            "alice",
+            Some("member"),
+            "2026-02-04T00:00:00Z",
+            OWNER,
+        );
+        let late = entry("add", "erin", Some("member"), "2026-02-05T00:00:00Z", admin);
+        let mut state = state;
+        state.apply(&demote).unwrap();
+        assert!(state.authorize(&late).is_err());
+    }
+
+    #[test]
+    fn test_replay_follows_chain() {
+        let admin = ("alice", "key-alice");
+        let add_admin = entry("add", "alice", Some("admin"), "2026-02-01T00:00:00Z", OWNER);
+        let add_bob = after(
+            entry("add", "bob", Some("member"), "2026-02-02T00:00:00Z", admin),
+            &[&add_admin],
+        );
+        let remove_admin = after(
+            entry("remove", "alice", None, "2026-02-03T00:00:00Z", OWNER),
+            &[&add_bob],
+        );
+        // Signed after the removal, dated before it
+        let backdated = after(
+            entry(
+                "add",
+                "mallory",
+                Some("member"),
+                "2026-02-02T12:00:00Z",
+                admin,
+            ),
+            &[&add_bob],
+        );
+        let dangling = after(
+            entry(
+                "add",
+                "carol",
+                Some("member"),
+                "2026-02-04T00:00:00Z",
+                OWNER,
+            ),
+            &[&backdated, &remove_admin],
+        );
+        assert_eq!(
+            heads(&[add_admin.clone(), add_bob.clone()]),
+            vec![add_bob.entry_id.clone()]
+        );
+
+        let log = [
+            dangling.clone(),
+            backdated.clone(),
+            remove_admin.clone(),
+            add_bob.clone(),
+            add_admin.clone(),
+        ];
+        let state = MembershipState::replay("russell", "key-russell", &log);
+        assert_eq!(state.role_of("bob"), Some(Role::Member));
+        assert_eq!(state.role_of("alice"), None);
+        assert_eq!(state.role_of("mallory"), None);
+        // Follows a skipped entry, but the entry itself is held and authorized
+        assert_eq!(state.role_of("carol"), Some(Role::Member));
+
+        // Entries following one not held yet wait for it
+        let partial = MembershipState::replay(
+            "russell",
+            "key-russell",
+            &[add_admin.clone(), remove_admin.clone(), add_bob.clone()][1..],
+        );
+        assert_eq!(partial.role_of("bob"), None);
+        assert_eq!(partial.members().get("alice"), None);
+
+        // Timestamps only order concurrent entries: a chained entry
+        // dated before its parent still replays after it
+        let readd = after(
+            entry(
+                "add",
+                "alice",
+                Some("member"),
+                "2026-01-01T00:00:00Z",
+                OWNER,
+            ),
+            &[&remove_admin],
+        );
+        let state = MembershipState::replay(
+            "russell",
+            "key-russell",
+            &[add_admin, add_bob, remove_admin, readd],
+        );
+        assert_eq!(state.role_of("alice"), Some(Role::Member));
+    }
+
+    #[test]
+    fn test_former_owner_authority_ends_at_transfer() {
+        let transfer = OwnershipTransfer {
+            from_pubkey: "key-russell".into(),
+            to_owner_id: "bill".into(),
+            to_pubkey: "key-bill".into(),
+            transferred_at: "2026-02-10 00:00:00".into(),
+            signature: "sig".into(),
+            hlc: None,
+            log_heads: Vec::new(),
+        };
+        let before = entry(
+            "add",
+            "alice",
+            Some("member"),
+            "2026-02-01T00:00:00Z",
+            OWNER,
+        );
+        let late = entry(
+            "add",
+            "mallory",
+            Some("member"),
+            "2026-02-11T00:00:00Z",
+            OWNER,
+        );
+        let by_new_owner = entry(
+            "add",
+            "carol",
+            Some("viewer"),
+            "2026-02-12T00:00:00Z",
+            ("bill", "key-bill"),
+        );
+
+        let state = MembershipState::new("bill", "key-bill")
+            .with_transfers(std::slice::from_ref(&transfer))
+            .replayed(&[before.clone(), late, by_new_owner.clone()]);
+        assert_eq!(state.role_of("alice"), Some(Role::Member));
+        assert_eq!(state.role_of("mallory"), None);
+        assert_eq!(state.role_of("carol"), Some(Role::Viewer));
+        assert_eq!(state.role_of("bill"), Some(Role::Owner));
+
+        // A transfer naming the log heads: the old key keeps only what they
+        // cover, whatever it dates later entries
+        let add_bob = after(
+            entry("add", "bob", Some("member"), "2026-02-02T00:00:00Z", OWNER),
+            &[&before],
+        );
+        let pinned = OwnershipTransfer {
+            hlc: Some("hlc".into()),
+            log_heads: vec![add_bob.entry_id.clone()],
+            ..transfer
+        };
+        let backdated = after(
+            entry(
+                "add",
+                "mallory",
+                Some("member"),
+                "2026-02-03T00:00:00Z",
+                OWNER,
+            ),
+            &[&add_bob],
+        );
+        let unchained = entry("add", "dave", Some("member"), "2026-01-01T00:00:00Z", OWNER);
+        let state = MembershipState::new("bill", "key-bill")
+            .with_transfers(&[pinned])
+            .replayed(&[before, add_bob, backdated, unchained, by_new_owner]);
+        assert_eq!(state.role_of("alice"), Some(Role::Member));
+        assert_eq!(state.role_of("bob"), Some(Role::Member));
+        assert_eq!(state.role_of("mallory"), None);
+        assert_eq!(state.role_of("dave"), None);
+        assert_eq!(state.role_of("carol"), Some(Role::Viewer));
+    }
+
+    #[test]
+    fn test_ingest_and_apply() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage =
+            cordelia_storage::SqliteStorage::create_new(&dir.path().join("t.db")).unwrap();
+        storage.write_group("grp", "grp", "{}", "{}").unwrap();
+        let add_alice = entry(
+            "add",
+            "alice",
+            Some("member"),
+            "2026-02-01T00:00:00Z",
+            OWNER,
+        );
+
+        // No owner key yet: nothing is accepted
+        assert_eq!(
+            ingest(&storage, "grp", std::slice::from_ref(&add_alice)).unwrap(),
+            0
+        );
+        storage
+            .write_group_signature("grp", "russell", "key-russell", "sig")
+            .unwrap();
+
+        let forged = entry(
+            "add",
+            "mallory",
+            Some("admin"),
+            "2026-02-01T00:00:00Z",
+            ("mallory", "x"),
+        );
+        assert_eq!(
+            ingest(&storage, "grp", &[add_alice.clone(), forged]).unwrap(),
+            1
+        );
+        assert_eq!(ingest(&storage, "grp", &[add_alice]).unwrap(), 0);
+
+        // Local posture survives replay of an unchanged member
+        storage.add_member("grp", "russell", "owner").unwrap();
+        storage
+            .update_member_posture("grp", "russell", "silent")
+            .unwrap();
+        let mut changed = apply(&storage, "grp").unwrap();
+        changed.sort();
+        assert_eq!(changed, vec!["alice"]);
+        assert_eq!(
+            storage
+                .get_membership("grp", "russell")
+                .unwrap()
+                .unwrap()
+                .posture
+                .as_deref(),
+            Some("silent")
+        );
+        assert!(apply(&storage, "grp").unwrap().is_empty());
+
+        let remove = entry("remove", "alice", None, "2026-02-02T00:00:00Z", OWNER);
+        assert_eq!(ingest(&storage, "grp", &[remove]).unwrap(), 1);
+        assert_eq!(apply(&storage, "grp").unwrap(), vec!["alice"]);
+        assert!(storage.get_membership("grp", "alice").unwrap().is_none());
+    }
+}
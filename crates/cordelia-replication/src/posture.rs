@@ -0,0 +1,105 @@
+//! Member posture -- what this node emits for a group.
+//!
+//! Set per member via `groups/update_posture`; the node applies its own
+//! entity's posture:
+//!
+//! | Posture  | Eager push | Answer sync/fetch | Anti-entropy pull | GroupExchange |
+//! |----------|------------|-------------------|-------------------|---------------|
+//! | `active` | yes        | yes               | yes               | advertised    |
+//! | `silent` | no         | yes               | yes               | advertised    |
+//! | `emcon`  | no         | no                | no                | hidden        |
+//!
+//! All postures still accept inbound pushes. `emcon` does not pull, since
+//! a SyncRequest names the group on the wire. `removed` members are no
+//! longer in the group at all.
+
+use cordelia_storage::Storage;
+use serde::{Deserialize, Serialize};
+
+/// Parsed `group_members.posture` of the local entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Posture {
+    Active,
+    /// Receive, and serve peers on request, but never push our writes.
+    Silent,
+    /// Emission control: no push, no sync/fetch answers, not advertised.
+    Emcon,
+}
+
+impl Posture {
+    /// Parse a stored posture. Absent or unknown values are active.
+    pub fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("silent") => Posture::Silent,
+            Some("emcon") => Posture::Emcon,
+            _ => Posture::Active,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Posture::Active => "active",
+            Posture::Silent => "silent",
+            Posture::Emcon => "emcon",
+        }
+    }
+
+    /// Eagerly push local writes to group peers.
+    pub fn pushes(&self) -> bool {
+        matches!(self, Posture::Active)
+    }
+
+    /// Answer peers' sync and fetch requests for the group.
+    pub fn serves(&self) -> bool {
+        !matches!(self, Posture::Emcon)
+    }
+
+    /// Send anti-entropy SyncRequests (which name the group) for the group.
+    pub fn pulls(&self) -> bool {
+        !matches!(self, Posture::Emcon)
+    }
+
+    /// List the group in GroupExchange.
+    pub fn advertises(&self) -> bool {
+        !matches!(self, Posture::Emcon)
+    }
+
+    /// Posture of `entity_id` (normally our own) in `group_id`. Not being a
+    /// listed member, or a storage error, reads as active.
+    pub fn of(storage: &dyn Storage, group_id: &str, entity_id: &str) -> Self {
+        let membership = storage.get_membership(group_id, entity_id).ok().flatten();
+        Self::parse(membership.and_then(|m| m.posture).as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cordelia_storage::SqliteStorage;
+
+    #[test]
+    fn test_posture_effects() {
+        assert!(Posture::Active.pushes() && Posture::Active.serves());
+        assert!(!Posture::Silent.pushes());
+        assert!(Posture::Silent.serves() && Posture::Silent.advertises());
+        assert!(Posture::Active.pulls() && Posture::Silent.pulls());
+        assert!(!Posture::Emcon.pushes() && !Posture::Emcon.serves());
+        assert!(!Posture::Emcon.advertises() && !Posture::Emcon.pulls());
+        assert_eq!(Posture::parse(Some("emcon")), Posture::Emcon);
+        assert_eq!(Posture::parse(Some("bogus")), Posture::Active);
+        assert_eq!(Posture::parse(None), Posture::Active);
+    }
+
+    #[test]
+    fn test_posture_of_local_entity() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        db.write_group("g", "g", "{}", "standard").unwrap();
+
+        assert_eq!(Posture::of(&db, "g", "russell"), Posture::Active);
+        db.add_member("g", "russell", "owner").unwrap();
+        db.update_member_posture("g", "russell", "emcon").unwrap();
+        assert_eq!(Posture::of(&db, "g", "russell"), Posture::Emcon);
+    }
+}
@@ -0,0 +1,222 @@
+//! Group roles -- who may write items into, administer, or delete a group.
+//!
+//! `group_members.role` is one of `owner`, `admin`, `member`, `viewer`:
+//!
+//! | Action              | owner | admin | member | viewer |
+//! |---------------------|-------|-------|--------|--------|
+//! | write/delete items  | yes   | yes   | yes    | no     |
+//! | culture, members    | yes   | yes   | no     | no     |
+//! | delete group        | yes   | no    | no     | no     |
+//! | transfer, admin keys| yes   | no    | no     | no     |
+//!
+//! Membership is local until it replicates, so a node usually only knows
+//! about itself. The list counts as *known* once it names anyone other than
+//! the local entity; until then, entities that are not listed are allowed
+//! (roles of listed entities are always enforced).
+
+use cordelia_storage::{GroupMemberRow, Storage};
+
+/// Role of a group member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Owner,
+    Admin,
+    Member,
+    Viewer,
+}
+
+impl Role {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "owner" => Some(Role::Owner),
+            "admin" => Some(Role::Admin),
+            "member" => Some(Role::Member),
+            "viewer" => Some(Role::Viewer),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Owner => "owner",
+            Role::Admin => "admin",
+            Role::Member => "member",
+            Role::Viewer => "viewer",
+        }
+    }
+
+    /// Whether this role permits `action`.
+    pub fn permits(&self, action: GroupAction) -> bool {
+        match action {
+            GroupAction::Write => !matches!(self, Role::Viewer),
+            GroupAction::Administer => matches!(self, Role::Owner | Role::Admin),
+            GroupAction::Delete | GroupAction::Transfer => matches!(self, Role::Owner),
+        }
+    }
+}
+
+/// Role-gated group operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupAction {
+    /// Write or delete items.
+    Write,
+    /// Change culture, add/remove members, change postures.
+    Administer,
+    /// Delete the group.
+    Delete,
+    /// Transfer ownership or change the descriptor's admin keys.
+    Transfer,
+}
+
+impl GroupAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupAction::Write => "write",
+            GroupAction::Administer => "administer",
+            GroupAction::Delete => "delete",
+            GroupAction::Transfer => "transfer",
+        }
+    }
+}
+
+/// Check `entity_id` may perform `action` in a group with `members`
+/// (active members only). `local_entity` is this node's own entity.
+/// Returns the denial reason on failure.
+pub fn check(
+    members: &[GroupMemberRow],
+    entity_id: &str,
+    local_entity: &str,
+    action: GroupAction,
+) -> Result<(), String> {
+    match members.iter().find(|m| m.entity_id == entity_id) {
+        Some(m) => match Role::parse(&m.role) {
+            Some(role) if role.permits(action) => Ok(()),
+            _ => Err(format!(
+                "role '{}' of '{}' does not permit {} in group '{}'",
+                m.role,
+                entity_id,
+                action.as_str(),
+                m.group_id
+            )),
+        },
+        None if members.iter().any(|m| m.entity_id != local_entity) => Err(format!(
+            "'{}' is not an active member of group '{}'",
+            entity_id, members[0].group_id
+        )),
+        None => Ok(()),
+    }
+}
+
+/// As `check`, loading the group's members from storage. Storage errors deny.
+pub fn check_in_storage(
+    storage: &dyn Storage,
+    group_id: &str,
+    entity_id: &str,
+    local_entity: &str,
+    action: GroupAction,
+) -> Result<(), String> {
+    let members = storage
+        .list_members(group_id)
+        .map_err(|e| format!("membership lookup failed: {e}"))?;
+    check(&members, entity_id, local_entity, action)
+}
+
+/// Check `actor` may add, re-role (`granted` = new role) or remove
+/// (`granted` = None) `target` in a group with `members`, on top of
+/// `Administer`: owner is never granted here (see transfer), the owner's
+/// membership is fixed, and admins cannot touch owners or admins or grant
+/// admin -- the same rules the membership log applies to signers.
+pub fn check_member_change(
+    members: &[GroupMemberRow],
+    actor: &str,
+    target: &str,
+    granted: Option<Role>,
+) -> Result<(), String> {
+    let role_of = |entity: &str| {
+        members
+            .iter()
+            .find(|m| m.entity_id == entity)
+            .and_then(|m| Role::parse(&m.role))
+    };
+    if granted == Some(Role::Owner) {
+        return Err("owner cannot be granted -- transfer ownership instead".into());
+    }
+    if role_of(target) == Some(Role::Owner) {
+        return Err("the owner cannot be removed or change role".into());
+    }
+    if role_of(actor) == Some(Role::Admin) {
+        if role_of(target) == Some(Role::Admin) {
+            return Err("admins cannot change owners or other admins".into());
+        }
+        if granted == Some(Role::Admin) {
+            return Err("admins cannot grant admin".into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(entity_id: &str, role: &str) -> GroupMemberRow {
+        GroupMemberRow {
+            group_id: "g".into(),
+            entity_id: entity_id.into(),
+            role: role.into(),
+            posture: Some("active".into()),
+            joined_at: "2026-01-01 00:00:00".into(),
+        }
+    }
+
+    #[test]
+    fn test_role_permissions() {
+        assert!(Role::Member.permits(GroupAction::Write));
+        assert!(!Role::Viewer.permits(GroupAction::Write));
+        assert!(Role::Admin.permits(GroupAction::Administer));
+        assert!(!Role::Member.permits(GroupAction::Administer));
+        assert!(Role::Owner.permits(GroupAction::Delete));
+        assert!(!Role::Admin.permits(GroupAction::Delete));
+        assert!(!Role::Admin.permits(GroupAction::Transfer));
+        assert_eq!(Role::parse("admin"), Some(Role::Admin));
+        assert_eq!(Role::parse("root"), None);
+    }
+
+    #[test]
+    fn test_check_membership_known() {
+        // Unknown membership (empty, or only ourselves): unlisted entities allowed
+        assert!(check(&[], "bob", "russell", GroupAction::Delete).is_ok());
+        let only_us = [member("russell", "owner")];
+        assert!(check(&only_us, "bob", "russell", GroupAction::Write).is_ok());
+
+        // Listed roles are always enforced
+        let members = [member("russell", "owner"), member("carol", "viewer")];
+        assert!(check(&members, "russell", "russell", GroupAction::Delete).is_ok());
+        assert!(check(&members, "carol", "russell", GroupAction::Write).is_err());
+
+        // Known membership: unlisted entities denied
+        let err = check(&members, "bob", "russell", GroupAction::Write).unwrap_err();
+        assert!(err.contains("not an active member"));
+    }
+
+    #[test]
+    fn test_check_member_change() {
+        let members = [
+            member("russell", "owner"),
+            member("alice", "admin"),
+            member("carol", "member"),
+        ];
+        // Owner is never granted, and the owner's membership is fixed
+        assert!(check_member_change(&members, "russell", "carol", Some(Role::Owner)).is_err());
+        assert!(check_member_change(&members, "russell", "russell", Some(Role::Admin)).is_err());
+        assert!(check_member_change(&members, "russell", "russell", None).is_err());
+
+        // The owner manages admins; admins only manage members and viewers
+        assert!(check_member_change(&members, "russell", "carol", Some(Role::Admin)).is_ok());
+        assert!(check_member_change(&members, "russell", "alice", None).is_ok());
+        assert!(check_member_change(&members, "alice", "carol", Some(Role::Viewer)).is_ok());
+        assert!(check_member_change(&members, "alice", "bob", Some(Role::Member)).is_ok());
+        assert!(check_member_change(&members, "alice", "carol", Some(Role::Admin)).is_err());
+        assert!(check_member_change(&members, "alice", "alice", None).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+//! Group security policy -- `groups.security_policy`, set at `groups/create`,
+//! carried in the signed group descriptor and enforced wherever items or
+//! descriptors cross the wire.
+//!
+//! | Field                      | Default    | Enforced                                     |
+//! |----------------------------|------------|----------------------------------------------|
+//! | `require_author_signature` | false      | receive: author key from the membership log  |
+//! | `allowed_item_types`       | any        | local write, receive                         |
+//! | `max_item_bytes`           | era limit  | local write, receive (never above the era)   |
+//! | `relay_storable`           | true       | receive on relays that are not members       |
+//! | `exchange_visible`         | true       | GroupExchange: no group ID, no descriptor    |
+//!
+//! An empty policy (`{}`) restricts nothing, so groups created before
+//! policies were enforced behave as before.
+
+use cordelia_crypto::verify_ed25519_hex;
+use cordelia_protocol::messages::FetchedItem;
+use cordelia_protocol::MAX_ITEM_BYTES;
+use cordelia_storage::Storage;
+use serde::{Deserialize, Serialize};
+
+use crate::membership;
+
+/// Item type of a replicated deletion (not subject to type or size rules).
+const ITEM_TOMBSTONE_TYPE: &str = "__tombstone__";
+
+/// Parsed `groups.security_policy`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecurityPolicy {
+    /// Received items must be signed by the author's node key, as recorded
+    /// in the group's membership log.
+    #[serde(default)]
+    pub require_author_signature: bool,
+    /// Item types the group accepts (`None` = any).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_item_types: Option<Vec<String>>,
+    /// Per-item size limit in bytes, at most the era limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_item_bytes: Option<usize>,
+    /// Whether relays that are not members may store the group's items.
+    #[serde(default = "default_true")]
+    pub relay_storable: bool,
+    /// Whether the group may appear in GroupExchange at all.
+    #[serde(default = "default_true")]
+    pub exchange_visible: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for SecurityPolicy {
+    fn default() -> Self {
+        Self {
+            require_author_signature: false,
+            allowed_item_types: None,
+            max_item_bytes: None,
+            relay_storable: true,
+            exchange_visible: true,
+        }
+    }
+}
+
+impl SecurityPolicy {
+    /// Parse and validate a policy document (`groups/create`, descriptors).
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let policy: Self =
+            serde_json::from_str(json).map_err(|e| format!("invalid security_policy: {e}"))?;
+        if let Some(max) = policy.max_item_bytes {
+            if max == 0 || max > MAX_ITEM_BYTES {
+                return Err(format!(
+                    "invalid security_policy: max_item_bytes must be 1..={MAX_ITEM_BYTES} (the era limit)"
+                ));
+            }
+        }
+        Ok(policy)
+    }
+
+    /// Policy of a stored group. Absent groups and unparseable (pre-validation)
+    /// values get the default, unrestricted policy.
+    pub fn of(storage: &dyn Storage, group_id: &str) -> Self {
+        storage
+            .read_group(group_id)
+            .ok()
+            .flatten()
+            .and_then(|g| Self::parse(&g.security_policy).ok())
+            .unwrap_or_default()
+    }
+
+    /// A stored policy as carried in a group descriptor: `None` for `{}`
+    /// and for unparseable (pre-validation) values, which are unrestricted.
+    pub fn for_descriptor(stored: &str) -> Option<String> {
+        (stored != "{}" && Self::parse(stored).is_ok()).then(|| stored.to_string())
+    }
+
+    /// Whether the group accepts items of `item_type`.
+    pub fn allows_type(&self, item_type: &str) -> bool {
+        self.allowed_item_types
+            .as_ref()
+            .is_none_or(|types| types.iter().any(|t| t == item_type))
+    }
+
+    /// Effective per-item size limit (the era limit unless lower).
+    pub fn max_item_bytes(&self) -> usize {
+        self.max_item_bytes
+            .map_or(MAX_ITEM_BYTES, |max| max.min(MAX_ITEM_BYTES))
+    }
+
+    /// Check a received item against the policy. `relay_only`: we would
+    /// store it as a relay, not as a member.
+    pub fn check_receive(
+        &self,
+        storage: &dyn Storage,
+        item: &FetchedItem,
+        relay_only: bool,
+    ) -> Result<(), String> {
+        if relay_only && !self.relay_storable {
+            return Err("group policy forbids relay storage".into());
+        }
+        if item.item_type != ITEM_TOMBSTONE_TYPE {
+            if !self.allows_type(&item.item_type) {
+                return Err(format!(
+                    "group policy does not allow item type '{}'",
+                    item.item_type
+                ));
+            }
+            if item.encrypted_blob.len() > self.max_item_bytes() {
+                return Err(format!(
+                    "item {} bytes exceeds group limit of {} bytes",
+                    item.encrypted_blob.len(),
+                    self.max_item_bytes()
+                ));
+            }
+        }
+        if self.require_author_signature {
+            verify_author(storage, item)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check `item`'s author signature against the key the group's membership
+/// log records for `author_id`.
+pub fn verify_author(storage: &dyn Storage, item: &FetchedItem) -> Result<(), String> {
+    let (Some(key), Some(sig)) = (&item.author_pubkey, &item.author_signature) else {
+        return Err("item is not signed by its author".into());
+    };
+    let state = membership::state_of(storage, &item.group_id)
+        .map_err(|e| e.to_string())?
+        .ok_or("group owner key not known yet")?;
+    match state.pubkey_of(&item.author_id) {
+        Some(recorded) if recorded.eq_ignore_ascii_case(key) => {}
+        Some(_) => return Err("author key does not match the membership log".into()),
+        None => return Err(format!("no key recorded for author '{}'", item.author_id)),
+    }
+    if !verify_ed25519_hex(&item.signing_payload(), key, sig) {
+        return Err("invalid author signature".into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cordelia_storage::{GroupAuthority, SqliteStorage};
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    fn keypair() -> Ed25519KeyPair {
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+    }
+
+    fn item(item_type: &str, blob: &[u8]) -> FetchedItem {
+        FetchedItem {
+            item_id: "i1".into(),
+            item_type: item_type.into(),
+            encrypted_blob: blob.to_vec(),
+            checksum: crate::item_checksum(blob, None),
+            author_id: "russell".into(),
+            group_id: "g1".into(),
+            key_version: 1,
+            parent_id: None,
+            is_copy: false,
+            updated_at: "2026-03-01T00:00:00Z".into(),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_validates() {
+        assert_eq!(
+            SecurityPolicy::parse("{}").unwrap(),
+            SecurityPolicy::default()
+        );
+        let policy = SecurityPolicy::parse(
+            r#"{"require_author_signature":true,"allowed_item_types":["entity"],"max_item_bytes":1024,"relay_storable":false,"exchange_visible":false}"#,
+        )
+        .unwrap();
+        assert!(policy.allows_type("entity"));
+        assert!(!policy.allows_type("session"));
+        assert_eq!(policy.max_item_bytes(), 1024);
+        assert!(!policy.relay_storable && !policy.exchange_visible);
+
+        assert!(SecurityPolicy::parse(r#"{"max_item_bytes":0}"#).is_err());
+        let above_era = format!(r#"{{"max_item_bytes":{}}}"#, MAX_ITEM_BYTES + 1);
+        assert!(SecurityPolicy::parse(&above_era).is_err());
+        assert!(
+            SecurityPolicy::parse(r#"{"relay_storabel":false}"#).is_err(),
+            "typo"
+        );
+        assert!(SecurityPolicy::parse("not json").is_err());
+
+        assert_eq!(SecurityPolicy::for_descriptor("{}"), None);
+        assert_eq!(
+            SecurityPolicy::for_descriptor("standard"),
+            None,
+            "legacy value"
+        );
+        assert_eq!(
+            SecurityPolicy::for_descriptor(r#"{"relay_storable":false}"#).as_deref(),
+            Some(r#"{"relay_storable":false}"#)
+        );
+    }
+
+    #[test]
+    fn test_check_receive() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = SqliteStorage::create_new(&dir.path().join("test.db")).unwrap();
+        let owner = keypair();
+        let owner_hex = hex::encode(owner.public_key().as_ref());
+        db.write_group(
+            "g1",
+            "g1",
+            "{}",
+            r#"{"require_author_signature":true,"allowed_item_types":["entity"],"max_item_bytes":8,"relay_storable":false}"#,
+        )
+        .unwrap();
+        db.write_group_authority(
+            "g1",
+            &GroupAuthority {
+                updated_at: "2026-03-01 00:00:00".into(),
+                owner_id: "russell".into(),
+                owner_pubkey: owner_hex.clone(),
+                signature: "sig".into(),
+                signer_pubkey: None,
+                admins: Vec::new(),
+                owner_transfers: None,
+                hlc: None,
+            },
+        )
+        .unwrap();
+        let policy = SecurityPolicy::of(&db, "g1");
+
+        let sign = |mut it: FetchedItem, key: &Ed25519KeyPair| {
+            it.author_pubkey = Some(hex::encode(key.public_key().as_ref()));
+            it.author_signature = Some(hex::encode(key.sign(&it.signing_payload()).as_ref()));
+            it
+        };
+        let good = sign(item("entity", b"{}"), &owner);
+        assert_eq!(policy.check_receive(&db, &good, false), Ok(()));
+        assert!(policy.check_receive(&db, &good, true).is_err(), "no relays");
+
+        assert!(policy
+            .check_receive(&db, &item("entity", b"{}"), false)
+            .is_err());
+        let wrong_type = sign(item("session", b"{}"), &owner);
+        assert!(policy.check_receive(&db, &wrong_type, false).is_err());
+        let too_big = sign(item("entity", b"0123456789"), &owner);
+        assert!(policy.check_receive(&db, &too_big, false).is_err());
+        // Valid signature, but not the author's recorded key
+        let forged = sign(item("entity", b"{}"), &keypair());
+        assert!(policy.check_receive(&db, &forged, false).is_err());
+        let mut tampered = good.clone();
+        tampered.item_id = "i2".into();
+        assert!(policy.check_receive(&db, &tampered, false).is_err());
+
+        // A relay extending the expiry cannot hide it by recomputing the
+        // (unkeyed) checksum: the signature covers the expiry too
+        let mut expiring = item("entity", b"{}");
+        expiring.expires_at = Some("2026-03-02T00:00:00Z".into());
+        expiring.checksum = crate::item_checksum(b"{}", expiring.expires_at.as_deref());
+        let expiring = sign(expiring, &owner);
+        assert_eq!(policy.check_receive(&db, &expiring, false), Ok(()));
+        let mut extended = expiring.clone();
+        extended.expires_at = Some("2099-01-01T00:00:00Z".into());
+        extended.checksum = crate::item_checksum(b"{}", extended.expires_at.as_deref());
+        assert!(crate::validate_checksum(&extended));
+        assert!(policy.check_receive(&db, &extended, false).is_err());
+
+        // Nor can it re-version an item to win (or lose) LWW
+        let mut versioned = item("entity", b"{}");
+        versioned.hlc = Some("001772323200000-0000000000-n1".into());
+        let versioned = sign(versioned, &owner);
+        assert_eq!(policy.check_receive(&db, &versioned, false), Ok(()));
+        let mut bumped = versioned.clone();
+        bumped.hlc = Some("001772323200000-0000000009-n1".into());
+        assert!(policy.check_receive(&db, &bumped, false).is_err());
+        let mut stripped = versioned.clone();
+        stripped.hlc = None;
+        assert!(policy.check_receive(&db, &stripped, false).is_err());
+        let mut redated = versioned;
+        redated.updated_at = "2026-03-02T00:00:00Z".into();
+        assert!(policy.check_receive(&db, &redated, false).is_err());
+
+        // Unrestricted groups accept anything
+        let open = SecurityPolicy::of(&db, "missing");
+        assert_eq!(
+            open.check_receive(&db, &item("session", b"{}"), true),
+            Ok(())
+        );
+    }
+}
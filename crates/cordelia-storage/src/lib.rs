@@ -26,6 +26,88 @@ pub enum StorageError {
 
 pub type Result<T> = std::result::Result<T, StorageError>;
 
+/// Compute the integrity checksum for an L2 item.
+///
+/// Items without an expiry hash the blob alone (unchanged from v4). Items with
+/// an expiry also bind `expires_at`. The hash is unkeyed, so it catches
+/// corruption, not tampering: anyone can recompute it. The author signature,
+/// which covers the expiry, is what stops a relay extending an item's
+/// lifetime.
+pub fn item_checksum(data: &[u8], expires_at: Option<&str>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    if let Some(ts) = expires_at {
+        hasher.update(b"\0expires_at=");
+        hasher.update(ts.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Column list matching `l2_item_from_row`.
+const L2_ITEM_COLUMNS: &str = "id, type, owner_id, visibility, data, checksum,
+     group_id, author_id, key_version, parent_id, is_copy,
+     access_count, last_accessed_at, created_at, updated_at,
+     ttl_expires_at, author_pubkey, author_signature, hlc, ancestry";
+
+fn l2_item_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<L2ItemRow> {
+    Ok(L2ItemRow {
+        id: row.get(0)?,
+        item_type: row.get(1)?,
+        owner_id: row.get(2)?,
+        visibility: row.get(3)?,
+        data: row.get(4)?,
+        checksum: row.get(5)?,
+        group_id: row.get(6)?,
+        author_id: row.get(7)?,
+        key_version: row.get::<_, Option<i32>>(8)?.unwrap_or(1),
+        parent_id: row.get(9)?,
+        is_copy: row.get::<_, Option<i32>>(10)?.unwrap_or(0) != 0,
+        access_count: row.get::<_, Option<i64>>(11)?.unwrap_or(0),
+        last_accessed_at: row.get(12)?,
+        created_at: row.get(13)?,
+        updated_at: row.get(14)?,
+        expires_at: row.get(15)?,
+        author_pubkey: row.get(16)?,
+        author_signature: row.get(17)?,
+        hlc: row.get(18)?,
+        ancestry: json_list(row.get(19)?),
+    })
+}
+
+/// Decode a JSON string array column (NULL or malformed = None).
+fn json_list(value: Option<String>) -> Option<Vec<String>> {
+    value.and_then(|v| serde_json::from_str(&v).ok())
+}
+
+/// Column list matching `l2_conflict_from_row`.
+const L2_CONFLICT_COLUMNS: &str = "item_id, checksum, type, data, group_id, author_id,
+     key_version, updated_at, hlc, ancestry, ttl_expires_at, author_pubkey,
+     author_signature, detected_at";
+
+fn l2_conflict_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<L2ConflictRow> {
+    Ok(L2ConflictRow {
+        item_id: row.get(0)?,
+        checksum: row.get(1)?,
+        item_type: row.get(2)?,
+        data: row.get(3)?,
+        group_id: row.get(4)?,
+        author_id: row.get(5)?,
+        key_version: row.get::<_, Option<i32>>(6)?.unwrap_or(1),
+        updated_at: row.get(7)?,
+        hlc: row.get(8)?,
+        ancestry: json_list(row.get(9)?),
+        expires_at: row.get(10)?,
+        author_pubkey: row.get(11)?,
+        author_signature: row.get(12)?,
+        detected_at: row.get(13)?,
+    })
+}
+
+/// SQL predicate: item has not expired (unparseable expiry counts as expired).
+/// Never NULL, so `NOT {NOT_EXPIRED}` selects unparseable rows too.
+const NOT_EXPIRED: &str =
+    "(ttl_expires_at IS NULL OR IFNULL(datetime(ttl_expires_at) > datetime('now'), 0))";
+
 /// Row types matching the SQLite schema v4.
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +127,18 @@ pub struct L2ItemRow {
     pub last_accessed_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// Expiry timestamp (column `ttl_expires_at`). None = never expires.
+    pub expires_at: Option<String>,
+    /// Hex-encoded key of the author's node (with `author_signature`).
+    pub author_pubkey: Option<String>,
+    /// Author signature over the item (see `ItemSigningFields`).
+    pub author_signature: Option<String>,
+    /// Hybrid logical clock version. None = derived from `updated_at`
+    /// (items written before HLCs, or received from older peers).
+    pub hlc: Option<String>,
+    /// Checksums of the versions this one replaced, newest first. None =
+    /// unknown (items written before ancestry tracking).
+    pub ancestry: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +167,37 @@ pub struct L2ItemWrite {
     /// When set, preserves the original writer's timestamp (for replicated items).
     /// When None, uses `datetime('now')` (for local writes).
     pub updated_at: Option<String>,
+    /// Expiry timestamp (RFC 3339). Covered by the stored checksum.
+    pub expires_at: Option<String>,
+    /// Hex-encoded key of the author's node.
+    pub author_pubkey: Option<String>,
+    /// Author signature, kept so peers fetching the item can verify it.
+    pub author_signature: Option<String>,
+    /// Hybrid logical clock version (see `L2ItemRow::hlc`).
+    pub hlc: Option<String>,
+    /// Replaced versions (see `L2ItemRow::ancestry`).
+    pub ancestry: Option<Vec<String>>,
+}
+
+/// A concurrent version of an L2 item, kept alongside the current one (the
+/// loser of a last-writer-wins conflict) until a merge write resolves it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct L2ConflictRow {
+    pub item_id: String,
+    pub checksum: String,
+    pub item_type: String,
+    pub data: Vec<u8>,
+    pub group_id: Option<String>,
+    pub author_id: Option<String>,
+    pub key_version: i32,
+    pub updated_at: String,
+    pub hlc: Option<String>,
+    pub ancestry: Option<Vec<String>>,
+    pub expires_at: Option<String>,
+    pub author_pubkey: Option<String>,
+    pub author_signature: Option<String>,
+    /// Set by storage on insert.
+    pub detected_at: String,
 }
 
 /// Lightweight header for sync protocol.
@@ -84,6 +209,7 @@ pub struct ItemHeader {
     pub updated_at: String,
     pub author_id: String,
     pub is_deletion: bool,
+    pub expires_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +226,35 @@ pub struct GroupRow {
     pub owner_pubkey: Option<String>,
     /// Hex-encoded Ed25519 signature over group descriptor.
     pub signature: Option<String>,
+    /// Hex-encoded keys allowed to sign culture updates besides the owner.
+    #[serde(default)]
+    pub admins: Vec<String>,
+    /// Key that made `signature`, when not the owner.
+    #[serde(default)]
+    pub signer_pubkey: Option<String>,
+    /// Ownership handover chain (JSON array of `OwnershipTransfer`).
+    #[serde(default)]
+    pub owner_transfers: Option<String>,
+    /// Hybrid logical clock version of the descriptor. None = derived from
+    /// `updated_at`.
+    #[serde(default)]
+    pub hlc: Option<String>,
+}
+
+/// Signed descriptor state of a group, written as one unit so the stored
+/// signature always matches the stored content (R4-030).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupAuthority {
+    /// The signed `updated_at` (kept verbatim so the signature verifies).
+    pub updated_at: String,
+    pub owner_id: String,
+    pub owner_pubkey: String,
+    pub signature: String,
+    pub signer_pubkey: Option<String>,
+    pub admins: Vec<String>,
+    pub owner_transfers: Option<String>,
+    /// The signed HLC version, if the descriptor carries one.
+    pub hlc: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,6 +276,62 @@ pub struct AccessLogEntry {
     pub detail: Option<String>,
 }
 
+/// Signed membership log entry (see `cordelia_protocol::messages::MembershipEntry`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MembershipLogRow {
+    /// SHA-256 of the signing payload.
+    pub entry_id: String,
+    pub group_id: String,
+    /// `add`, `remove`, or `role`.
+    pub op: String,
+    pub entity_id: String,
+    pub role: Option<String>,
+    pub member_pubkey: Option<String>,
+    pub created_at: String,
+    pub signer_id: String,
+    pub signer_pubkey: String,
+    pub signature: String,
+    /// Entry IDs this entry was signed after (empty for legacy entries).
+    #[serde(default)]
+    pub prev: Vec<String>,
+}
+
+/// Record of a departure_policy being applied to a group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDepartureRow {
+    pub group_id: String,
+    /// `retain`, `purge`, or `archive`.
+    pub policy: String,
+    /// What triggered the departure (`local_leave`, `owner_removal`).
+    pub reason: String,
+    /// Items purged or archived (items retained for `retain`).
+    pub items_affected: u32,
+    /// Archive file written (`archive` only).
+    pub archive_path: Option<String>,
+    /// Set by storage on insert.
+    pub departed_at: String,
+}
+
+/// A peer in the governor's persisted peer book.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeerBookRow {
+    pub peer_id: String,
+    /// Multiaddrs, in dial preference order.
+    pub addrs: Vec<String>,
+    pub groups: Vec<String>,
+    pub is_relay: bool,
+    pub rtt_ms: Option<f64>,
+    /// Consecutive failed connections (reconnect backoff).
+    pub disconnect_count: u32,
+    /// Bans so far; the next ban lasts longer.
+    pub ban_escalation: u32,
+    /// RFC 3339 end of a ban still in force.
+    pub banned_until: Option<String>,
+    pub ban_reason: Option<String>,
+    /// RFC 3339 time the peer was last active.
+    pub last_seen: String,
+}
+
 /// Device registration row.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceRow {
@@ -129,6 +340,8 @@ pub struct DeviceRow {
     pub device_name: Option<String>,
     pub device_type: String,
     pub auth_token_hash: String,
+    /// Space-separated API scopes: `read`, `write`, `admin`, `group:<id>`.
+    pub scopes: String,
     pub created_at: String,
     pub last_seen_at: Option<String>,
     pub revoked_at: Option<String>,
@@ -143,7 +356,15 @@ pub trait Storage: Send + Sync {
 
     fn read_l2_item(&self, id: &str) -> Result<Option<L2ItemRow>>;
     fn write_l2_item(&self, item: &L2ItemWrite) -> Result<()>;
+    /// Delete an item and any conflict siblings it has.
     fn delete_l2_item(&self, id: &str) -> Result<bool>;
+    /// Keep a concurrent version of an item, evicting the oldest (by HLC)
+    /// past `max_siblings`. Returns false if already kept or evicted.
+    fn write_l2_conflict(&self, conflict: &L2ConflictRow, max_siblings: usize) -> Result<bool>;
+    /// Conflict siblings of one item (or of all items), oldest first.
+    fn list_l2_conflicts(&self, item_id: Option<&str>) -> Result<Vec<L2ConflictRow>>;
+    /// Drop resolved conflict siblings of an item. Returns the number removed.
+    fn delete_l2_conflicts(&self, item_id: &str, checksums: &[String]) -> Result<u32>;
     fn read_l2_item_meta(&self, id: &str) -> Result<Option<L2ItemMeta>>;
     fn list_group_items(
         &self,
@@ -162,6 +383,10 @@ pub trait Storage: Send + Sync {
         owner_pubkey: &str,
         signature: &str,
     ) -> Result<()>;
+    /// Replace a group's signed descriptor state, including `updated_at`.
+    fn write_group_authority(&self, id: &str, authority: &GroupAuthority) -> Result<()>;
+    /// Set the HLC version of an unsigned group descriptor.
+    fn set_group_hlc(&self, id: &str, hlc: Option<&str>) -> Result<()>;
     fn read_group(&self, id: &str) -> Result<Option<GroupRow>>;
     fn list_groups(&self) -> Result<Vec<GroupRow>>;
     fn list_members(&self, group_id: &str) -> Result<Vec<GroupMemberRow>>;
@@ -174,9 +399,36 @@ pub trait Storage: Send + Sync {
     fn delete_group(&self, id: &str) -> Result<bool>;
     /// Remove groups with tombstone culture older than retention_days.
     fn purge_deleted_groups(&self, tombstone_culture: &str, retention_days: u32) -> Result<u32>;
+    /// Remove items whose expiry has passed (and conflict siblings that
+    /// expired or whose item is gone). Returns the number of items removed.
+    fn purge_expired_items(&self) -> Result<u32>;
+    /// Remove items in a group with no explicit expiry whose `updated_at` is
+    /// older than `ttl_secs` (group `ttl_default` fallback).
+    fn purge_group_items_older_than(&self, group_id: &str, ttl_secs: u64) -> Result<u32>;
+    /// Count items stored for a group (including expired, not yet GC'd).
+    fn count_group_items(&self, group_id: &str) -> Result<u32>;
+    /// Securely delete all items in a group and vacuum freed pages.
+    fn purge_group_items(&self, group_id: &str) -> Result<u32>;
+    /// Export all items in a group to a JSON-lines archive next to the
+    /// database. Items are kept (read-only, as for retain). Returns
+    /// (items archived, archive path).
+    fn archive_group_items(&self, group_id: &str) -> Result<(u32, String)>;
+    /// Record a departure_policy enforcement.
+    fn record_departure(&self, departure: &GroupDepartureRow) -> Result<()>;
+    /// List recorded departures, newest first.
+    fn list_departures(&self) -> Result<Vec<GroupDepartureRow>>;
+    /// Append a membership log entry. Returns false if already present.
+    fn append_membership_entry(&self, entry: &MembershipLogRow) -> Result<bool>;
+    /// A group's membership log in replay order (`created_at`, `entry_id`).
+    fn list_membership_log(&self, group_id: &str) -> Result<Vec<MembershipLogRow>>;
 
     fn log_access(&self, entry: &AccessLogEntry) -> Result<()>;
 
+    /// Replace the persisted peer book with `peers`.
+    fn save_peer_book(&self, peers: &[PeerBookRow]) -> Result<()>;
+    /// The persisted peer book.
+    fn load_peer_book(&self) -> Result<Vec<PeerBookRow>>;
+
     // L2 index (encrypted blob, singleton)
     fn read_l2_index(&self) -> Result<Option<Vec<u8>>>;
     fn write_l2_index(&self, data: &[u8]) -> Result<()>;
@@ -192,10 +444,14 @@ pub trait Storage: Send + Sync {
     fn storage_stats(&self) -> Result<StorageStats>;
 
     // Device management (portal enrollment)
+    /// Register a device, or re-register an existing one: rebinds it to
+    /// `device.entity_id` with the new token and clears any revocation.
     fn register_device(&self, device: &DeviceRow) -> Result<()>;
     fn list_devices(&self, entity_id: &str) -> Result<Vec<DeviceRow>>;
     fn revoke_device(&self, entity_id: &str, device_id: &str) -> Result<bool>;
     fn get_device_by_token_hash(&self, token_hash: &str) -> Result<Option<DeviceRow>>;
+    /// Record device activity (`last_seen_at`), at most once a minute.
+    fn touch_device(&self, device_id: &str) -> Result<()>;
 }
 
 /// Aggregate storage statistics for diagnostics.
@@ -223,7 +479,6 @@ pub struct GroupStats {
 /// Connection wrapped in Mutex for Send + Sync (rusqlite Connection is !Sync).
 pub struct SqliteStorage {
     conn: Mutex<Connection>,
-    #[allow(dead_code)]
     db_path: PathBuf,
 }
 
@@ -360,14 +615,241 @@ impl SqliteStorage {
             tracing::info!("storage: migrated schema v6 -> v7 (CoW member soft-delete)");
         }
 
+        // Re-read version after v6->v7 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v7 -> v8: item expiry (ttl_default / explicit expiry)
+        if version == 7 {
+            // Check if ttl_expires_at column already exists (v4 base schema includes it)
+            let has_expiry: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('l2_items') WHERE name = 'ttl_expires_at'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_expiry {
+                conn.execute(
+                    "UPDATE schema_version SET version = 8, migrated_at = datetime('now') WHERE version = 7",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v8.sql"))?;
+            }
+            tracing::info!("storage: migrated schema v7 -> v8 (item expiry)");
+        }
+
+        // Re-read version after v7->v8 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v8 -> v9: group departure records (departure_policy)
+        if version == 8 {
+            conn.execute_batch(include_str!("schema_v9.sql"))?;
+            tracing::info!("storage: migrated schema v8 -> v9 (group departures)");
+        }
+
+        // Re-read version after v8->v9 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v9 -> v10: device token scopes
+        if version == 9 {
+            // Databases initialised from v4 (version 6) never ran the v5 devices migration
+            conn.execute_batch(include_str!("schema_v5.sql"))?;
+            let has_scopes: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('devices') WHERE name = 'scopes'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_scopes {
+                conn.execute(
+                    "UPDATE schema_version SET version = 10, migrated_at = datetime('now') WHERE version = 9",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v10.sql"))?;
+            }
+            conn.execute_batch(
+                "CREATE INDEX IF NOT EXISTS idx_devices_token ON devices(auth_token_hash);",
+            )?;
+            tracing::info!("storage: migrated schema v9 -> v10 (device token scopes)");
+        }
+
+        // Re-read version after v9->v10 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v10 -> v11: replicated membership log
+        if version == 10 {
+            conn.execute_batch(include_str!("schema_v11.sql"))?;
+            tracing::info!("storage: migrated schema v10 -> v11 (membership log)");
+        }
+
+        // Re-read version after v10->v11 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v11 -> v12: descriptor admins and ownership transfers
+        if version == 11 {
+            // Check if admins column already exists (v4 base schema includes it)
+            let has_admins: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('groups') WHERE name = 'admins'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_admins {
+                conn.execute(
+                    "UPDATE schema_version SET version = 12, migrated_at = datetime('now') WHERE version = 11",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v12.sql"))?;
+            }
+            tracing::info!(
+                "storage: migrated schema v11 -> v12 (group admins and ownership transfer)"
+            );
+        }
+
+        // Re-read version after potential v11->v12 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v12 -> v13: item author signatures
+        if version == 12 {
+            // Check if author_signature column already exists (v4 base schema includes it)
+            let has_signature: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('l2_items') WHERE name = 'author_signature'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_signature {
+                conn.execute(
+                    "UPDATE schema_version SET version = 13, migrated_at = datetime('now') WHERE version = 12",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v13.sql"))?;
+            }
+            tracing::info!("storage: migrated schema v12 -> v13 (item author signatures)");
+        }
+
+        // Re-read version after potential v12->v13 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v13 -> v14: canonical item timestamps (data only, always safe to run)
+        if version == 13 {
+            conn.execute_batch(include_str!("schema_v14.sql"))?;
+            tracing::info!("storage: migrated schema v13 -> v14 (canonical item timestamps)");
+        }
+
+        // Re-read version after potential v13->v14 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v14 -> v15: hybrid logical clock versions
+        if version == 14 {
+            // Check if hlc column already exists (v4 base schema includes it)
+            let has_hlc: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('l2_items') WHERE name = 'hlc'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_hlc {
+                conn.execute(
+                    "UPDATE schema_version SET version = 15, migrated_at = datetime('now') WHERE version = 14",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v15.sql"))?;
+            }
+            tracing::info!("storage: migrated schema v14 -> v15 (hybrid logical clocks)");
+        }
+
+        // Re-read version after potential v14->v15 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v15 -> v16: item ancestry and conflict siblings
+        if version == 15 {
+            // Check if ancestry column already exists (v4 base schema includes it)
+            let has_ancestry: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('l2_items') WHERE name = 'ancestry'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_ancestry {
+                conn.execute(
+                    "UPDATE schema_version SET version = 16, migrated_at = datetime('now') WHERE version = 15",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v16.sql"))?;
+            }
+            tracing::info!("storage: migrated schema v15 -> v16 (item conflicts)");
+        }
+
+        // Re-read version after potential v15->v16 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v16 -> v17: persistent governor peer book
+        if version == 16 {
+            conn.execute_batch(include_str!("schema_v17.sql"))?;
+            tracing::info!("storage: migrated schema v16 -> v17 (peer book)");
+        }
+
+        // Re-read version after potential v16->v17 migration
+        let version: u32 =
+            conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                row.get(0)
+            })?;
+
+        // Migrate v17 -> v18: causal links in the membership log
+        if version == 17 {
+            // Check if prev column already exists (v4 base schema includes it)
+            let has_prev: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('group_membership_log') WHERE name = 'prev'",
+                [],
+                |row| row.get(0),
+            )?;
+            if has_prev {
+                conn.execute(
+                    "UPDATE schema_version SET version = 18, migrated_at = datetime('now') WHERE version = 17",
+                    [],
+                )?;
+            } else {
+                conn.execute_batch(include_str!("schema_v18.sql"))?;
+            }
+            tracing::info!("storage: migrated schema v17 -> v18 (membership prev)");
+        }
+
         Ok(())
     }
 
-    /// Compute SHA-256 checksum for data.
-    fn checksum(data: &[u8]) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        hex::encode(hasher.finalize())
+    /// Compute SHA-256 checksum for data (and expiry, if any).
+    fn checksum(data: &[u8], expires_at: Option<&str>) -> String {
+        item_checksum(data, expires_at)
     }
 }
 
@@ -416,42 +898,29 @@ impl Storage for SqliteStorage {
         let conn = self.db()?;
         let result = conn
             .query_row(
-                "SELECT id, type, owner_id, visibility, data, checksum,
-                        group_id, author_id, key_version, parent_id, is_copy,
-                        access_count, last_accessed_at, created_at, updated_at
-                 FROM l2_items WHERE id = ?1",
+                &format!("SELECT {L2_ITEM_COLUMNS} FROM l2_items WHERE id = ?1"),
                 params![id],
-                |row| {
-                    Ok(L2ItemRow {
-                        id: row.get(0)?,
-                        item_type: row.get(1)?,
-                        owner_id: row.get(2)?,
-                        visibility: row.get(3)?,
-                        data: row.get(4)?,
-                        checksum: row.get(5)?,
-                        group_id: row.get(6)?,
-                        author_id: row.get(7)?,
-                        key_version: row.get::<_, Option<i32>>(8)?.unwrap_or(1),
-                        parent_id: row.get(9)?,
-                        is_copy: row.get::<_, Option<i32>>(10)?.unwrap_or(0) != 0,
-                        access_count: row.get::<_, Option<i64>>(11)?.unwrap_or(0),
-                        last_accessed_at: row.get(12)?,
-                        created_at: row.get(13)?,
-                        updated_at: row.get(14)?,
-                    })
-                },
+                l2_item_from_row,
             )
             .optional()?;
         Ok(result)
     }
 
     fn write_l2_item(&self, item: &L2ItemWrite) -> Result<()> {
-        let checksum = Self::checksum(&item.data);
+        let checksum = Self::checksum(&item.data, item.expires_at.as_deref());
+        let ancestry = item
+            .ancestry
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(std::io::Error::other)?;
         let conn = self.db()?;
         conn.execute(
             "INSERT INTO l2_items (id, type, owner_id, visibility, data, checksum,
-                                   group_id, author_id, key_version, parent_id, is_copy, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, COALESCE(?12, datetime('now')))
+                                   group_id, author_id, key_version, parent_id, is_copy, updated_at,
+                                   ttl_expires_at, author_pubkey, author_signature, hlc, ancestry)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, COALESCE(?12, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')), ?13,
+                     ?14, ?15, ?16, ?17)
              ON CONFLICT(id) DO UPDATE SET
                type = excluded.type,
                owner_id = excluded.owner_id,
@@ -463,7 +932,12 @@ impl Storage for SqliteStorage {
                key_version = excluded.key_version,
                parent_id = excluded.parent_id,
                is_copy = excluded.is_copy,
-               updated_at = excluded.updated_at",
+               updated_at = excluded.updated_at,
+               ttl_expires_at = excluded.ttl_expires_at,
+               author_pubkey = excluded.author_pubkey,
+               author_signature = excluded.author_signature,
+               hlc = excluded.hlc,
+               ancestry = excluded.ancestry",
             params![
                 item.id,
                 item.item_type,
@@ -477,6 +951,11 @@ impl Storage for SqliteStorage {
                 item.parent_id,
                 if item.is_copy { 1 } else { 0 },
                 item.updated_at,
+                item.expires_at,
+                item.author_pubkey,
+                item.author_signature,
+                item.hlc,
+                ancestry,
             ],
         )?;
         Ok(())
@@ -485,9 +964,82 @@ impl Storage for SqliteStorage {
     fn delete_l2_item(&self, id: &str) -> Result<bool> {
         let conn = self.db()?;
         let changes = conn.execute("DELETE FROM l2_items WHERE id = ?1", params![id])?;
+        conn.execute("DELETE FROM l2_conflicts WHERE item_id = ?1", params![id])?;
         Ok(changes > 0)
     }
 
+    fn write_l2_conflict(&self, conflict: &L2ConflictRow, max_siblings: usize) -> Result<bool> {
+        let ancestry = conflict
+            .ancestry
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(std::io::Error::other)?;
+        let mut conn = self.db()?;
+        let tx = conn.transaction()?;
+        let inserted = tx.execute(
+            "INSERT INTO l2_conflicts (item_id, checksum, type, data, group_id, author_id,
+                                       key_version, updated_at, hlc, ancestry, ttl_expires_at,
+                                       author_pubkey, author_signature)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+             ON CONFLICT(item_id, checksum) DO NOTHING",
+            params![
+                conflict.item_id,
+                conflict.checksum,
+                conflict.item_type,
+                conflict.data,
+                conflict.group_id,
+                conflict.author_id,
+                conflict.key_version,
+                conflict.updated_at,
+                conflict.hlc,
+                ancestry,
+                conflict.expires_at,
+                conflict.author_pubkey,
+                conflict.author_signature,
+            ],
+        )?;
+        // Rows without an HLC (older peers) sort first, so go first
+        tx.execute(
+            "DELETE FROM l2_conflicts WHERE item_id = ?1 AND checksum NOT IN (
+                 SELECT checksum FROM l2_conflicts WHERE item_id = ?1
+                 ORDER BY hlc DESC, updated_at DESC, checksum DESC LIMIT ?2)",
+            params![conflict.item_id, max_siblings as i64],
+        )?;
+        let kept: bool = tx.query_row(
+            "SELECT COUNT(*) > 0 FROM l2_conflicts WHERE item_id = ?1 AND checksum = ?2",
+            params![conflict.item_id, conflict.checksum],
+            |row| row.get(0),
+        )?;
+        tx.commit()?;
+        Ok(inserted > 0 && kept)
+    }
+
+    fn list_l2_conflicts(&self, item_id: Option<&str>) -> Result<Vec<L2ConflictRow>> {
+        let conn = self.db()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {L2_CONFLICT_COLUMNS} FROM l2_conflicts
+             WHERE ?1 IS NULL OR item_id = ?1
+             ORDER BY item_id, detected_at, checksum"
+        ))?;
+        let rows = stmt
+            .query_map(params![item_id], l2_conflict_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    fn delete_l2_conflicts(&self, item_id: &str, checksums: &[String]) -> Result<u32> {
+        let conn = self.db()?;
+        let mut removed = 0;
+        for checksum in checksums {
+            removed += conn.execute(
+                "DELETE FROM l2_conflicts WHERE item_id = ?1 AND checksum = ?2",
+                params![item_id, checksum],
+            )?;
+        }
+        Ok(removed as u32)
+    }
+
     fn read_l2_item_meta(&self, id: &str) -> Result<Option<L2ItemMeta>> {
         let conn = self.db()?;
         let result = conn
@@ -526,29 +1078,31 @@ impl Storage for SqliteStorage {
                 updated_at: row.get(3)?,
                 author_id: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
                 is_deletion: row.get::<_, i32>(5)? != 0,
+                expires_at: row.get(6)?,
             })
         };
 
+        // Expired items are never advertised (GC may not have run yet)
         if let Some(since_ts) = since {
-            let mut stmt = conn.prepare(
-                "SELECT id, type, checksum, updated_at, author_id, 0
+            let mut stmt = conn.prepare(&format!(
+                "SELECT id, type, checksum, updated_at, author_id, 0, ttl_expires_at
                  FROM l2_items
-                 WHERE group_id = ?1 AND updated_at > ?2
+                 WHERE group_id = ?1 AND updated_at > ?2 AND {NOT_EXPIRED}
                  ORDER BY updated_at ASC
-                 LIMIT ?3",
-            )?;
+                 LIMIT ?3"
+            ))?;
             let rows = stmt
                 .query_map(params![group_id, since_ts, limit], map_row)?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
             Ok(rows)
         } else {
-            let mut stmt = conn.prepare(
-                "SELECT id, type, checksum, updated_at, author_id, 0
+            let mut stmt = conn.prepare(&format!(
+                "SELECT id, type, checksum, updated_at, author_id, 0, ttl_expires_at
                  FROM l2_items
-                 WHERE group_id = ?1
+                 WHERE group_id = ?1 AND {NOT_EXPIRED}
                  ORDER BY updated_at ASC
-                 LIMIT ?2",
-            )?;
+                 LIMIT ?2"
+            ))?;
             let rows = stmt
                 .query_map(params![group_id, limit], map_row)?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -593,27 +1147,41 @@ impl Storage for SqliteStorage {
         Ok(())
     }
 
+    fn write_group_authority(&self, id: &str, authority: &GroupAuthority) -> Result<()> {
+        let conn = self.db()?;
+        let admins = serde_json::to_string(&authority.admins).map_err(std::io::Error::other)?;
+        conn.execute(
+            "UPDATE groups SET updated_at = ?2, owner_id = ?3, owner_pubkey = ?4, signature = ?5,
+                    signer_pubkey = ?6, admins = ?7, owner_transfers = ?8, hlc = ?9
+             WHERE id = ?1",
+            params![
+                id,
+                authority.updated_at,
+                authority.owner_id,
+                authority.owner_pubkey,
+                authority.signature,
+                authority.signer_pubkey,
+                admins,
+                authority.owner_transfers,
+                authority.hlc,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn set_group_hlc(&self, id: &str, hlc: Option<&str>) -> Result<()> {
+        let conn = self.db()?;
+        conn.execute("UPDATE groups SET hlc = ?2 WHERE id = ?1", params![id, hlc])?;
+        Ok(())
+    }
+
     fn read_group(&self, id: &str) -> Result<Option<GroupRow>> {
         let conn = self.db()?;
         let result = conn
             .query_row(
-                "SELECT id, name, culture, security_policy, created_at, updated_at,
-                        owner_id, owner_pubkey, signature
-                 FROM groups WHERE id = ?1",
+                &format!("SELECT {GROUP_COLUMNS} FROM groups WHERE id = ?1"),
                 params![id],
-                |row| {
-                    Ok(GroupRow {
-                        id: row.get(0)?,
-                        name: row.get(1)?,
-                        culture: row.get(2)?,
-                        security_policy: row.get(3)?,
-                        created_at: row.get(4)?,
-                        updated_at: row.get(5)?,
-                        owner_id: row.get(6)?,
-                        owner_pubkey: row.get(7)?,
-                        signature: row.get(8)?,
-                    })
-                },
+                group_from_row,
             )
             .optional()?;
         Ok(result)
@@ -621,25 +1189,10 @@ impl Storage for SqliteStorage {
 
     fn list_groups(&self) -> Result<Vec<GroupRow>> {
         let conn = self.db()?;
-        let mut stmt = conn.prepare(
-            "SELECT id, name, culture, security_policy, created_at, updated_at,
-                    owner_id, owner_pubkey, signature
-             FROM groups ORDER BY name",
-        )?;
+        let mut stmt =
+            conn.prepare(&format!("SELECT {GROUP_COLUMNS} FROM groups ORDER BY name"))?;
         let rows = stmt
-            .query_map([], |row| {
-                Ok(GroupRow {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    culture: row.get(2)?,
-                    security_policy: row.get(3)?,
-                    created_at: row.get(4)?,
-                    updated_at: row.get(5)?,
-                    owner_id: row.get(6)?,
-                    owner_pubkey: row.get(7)?,
-                    signature: row.get(8)?,
-                })
-            })?
+            .query_map([], group_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
         Ok(rows)
     }
@@ -758,6 +1311,270 @@ impl Storage for SqliteStorage {
         Ok(changes as u32)
     }
 
+    fn purge_expired_items(&self) -> Result<u32> {
+        let conn = self.db()?;
+        let changes = conn.execute(
+            &format!("DELETE FROM l2_items WHERE ttl_expires_at IS NOT NULL AND NOT {NOT_EXPIRED}"),
+            [],
+        )?;
+        // Conflict siblings go with their item, or when they expire themselves
+        conn.execute(
+            &format!(
+                "DELETE FROM l2_conflicts
+                 WHERE item_id NOT IN (SELECT id FROM l2_items)
+                    OR (ttl_expires_at IS NOT NULL AND NOT {NOT_EXPIRED})"
+            ),
+            [],
+        )?;
+        Ok(changes as u32)
+    }
+
+    fn purge_group_items_older_than(&self, group_id: &str, ttl_secs: u64) -> Result<u32> {
+        let conn = self.db()?;
+        // datetime() normalises both RFC 3339 (replicated) and SQLite (local) timestamps
+        let cutoff = format!("-{ttl_secs} seconds");
+        let changes = conn.execute(
+            "DELETE FROM l2_items
+             WHERE group_id = ?1 AND ttl_expires_at IS NULL
+             AND datetime(updated_at) <= datetime('now', ?2)",
+            params![group_id, cutoff],
+        )?;
+        conn.execute(
+            "DELETE FROM l2_conflicts
+             WHERE group_id = ?1 AND item_id NOT IN (SELECT id FROM l2_items)",
+            params![group_id],
+        )?;
+        Ok(changes as u32)
+    }
+
+    fn count_group_items(&self, group_id: &str) -> Result<u32> {
+        let conn = self.db()?;
+        let count: u32 = conn.query_row(
+            "SELECT COUNT(*) FROM l2_items WHERE group_id = ?1",
+            params![group_id],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    }
+
+    fn purge_group_items(&self, group_id: &str) -> Result<u32> {
+        let mut conn = self.db()?;
+        // secure_delete zeroes freed content; VACUUM then drops the freed pages.
+        // The plaintext search index and conflict siblings go with the items.
+        conn.execute_batch("PRAGMA secure_delete = ON;")?;
+        let purged = conn.transaction().and_then(|tx| {
+            let indexed = tx.execute(
+                "DELETE FROM l2_fts WHERE item_id IN
+                   (SELECT id FROM l2_items WHERE group_id = ?1
+                    UNION SELECT item_id FROM l2_conflicts WHERE group_id = ?1)",
+                params![group_id],
+            )?;
+            let items = tx.execute(
+                "DELETE FROM l2_items WHERE group_id = ?1",
+                params![group_id],
+            )?;
+            let siblings = tx.execute(
+                "DELETE FROM l2_conflicts WHERE group_id = ?1",
+                params![group_id],
+            )?;
+            tx.commit()?;
+            Ok((items, indexed + siblings))
+        });
+        conn.execute_batch("PRAGMA secure_delete = OFF;")?;
+        let (changes, others) = purged?;
+        if changes + others > 0 {
+            conn.execute_batch("VACUUM;")?;
+        }
+        Ok(changes as u32)
+    }
+
+    fn archive_group_items(&self, group_id: &str) -> Result<(u32, String)> {
+        use std::io::Write;
+
+        let rows: Vec<L2ItemRow> = {
+            let conn = self.db()?;
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {L2_ITEM_COLUMNS} FROM l2_items WHERE group_id = ?1 ORDER BY updated_at ASC"
+            ))?;
+            let rows = stmt.query_map(params![group_id], l2_item_from_row)?;
+            rows.collect::<std::result::Result<Vec<_>, _>>()?
+        };
+
+        let dir = self
+            .db_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("archive");
+        std::fs::create_dir_all(&dir)?;
+        let safe_id: String = group_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let path = dir.join(format!(
+            "{safe_id}-{}.jsonl",
+            chrono::Utc::now().format("%Y%m%dT%H%M%SZ")
+        ));
+
+        let mut file = std::fs::File::create(&path)?;
+        for row in &rows {
+            let line = serde_json::to_string(row).map_err(std::io::Error::other)?;
+            writeln!(file, "{line}")?;
+        }
+        file.sync_all()?;
+
+        Ok((rows.len() as u32, path.display().to_string()))
+    }
+
+    fn record_departure(&self, departure: &GroupDepartureRow) -> Result<()> {
+        let conn = self.db()?;
+        conn.execute(
+            "INSERT INTO group_departures (group_id, policy, reason, items_affected, archive_path)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                departure.group_id,
+                departure.policy,
+                departure.reason,
+                departure.items_affected,
+                departure.archive_path,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn append_membership_entry(&self, entry: &MembershipLogRow) -> Result<bool> {
+        let prev = (!entry.prev.is_empty())
+            .then(|| serde_json::to_string(&entry.prev))
+            .transpose()
+            .map_err(std::io::Error::other)?;
+        let conn = self.db()?;
+        let inserted = conn.execute(
+            "INSERT INTO group_membership_log
+               (entry_id, group_id, op, entity_id, role, member_pubkey, created_at,
+                signer_id, signer_pubkey, signature, prev)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+             ON CONFLICT(entry_id) DO NOTHING",
+            params![
+                entry.entry_id,
+                entry.group_id,
+                entry.op,
+                entry.entity_id,
+                entry.role,
+                entry.member_pubkey,
+                entry.created_at,
+                entry.signer_id,
+                entry.signer_pubkey,
+                entry.signature,
+                prev,
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    fn list_membership_log(&self, group_id: &str) -> Result<Vec<MembershipLogRow>> {
+        let conn = self.db()?;
+        let mut stmt = conn.prepare(
+            "SELECT entry_id, group_id, op, entity_id, role, member_pubkey, created_at,
+                    signer_id, signer_pubkey, signature, prev
+             FROM group_membership_log WHERE group_id = ?1
+             ORDER BY created_at, entry_id",
+        )?;
+        let rows = stmt
+            .query_map(params![group_id], |row| {
+                Ok(MembershipLogRow {
+                    entry_id: row.get(0)?,
+                    group_id: row.get(1)?,
+                    op: row.get(2)?,
+                    entity_id: row.get(3)?,
+                    role: row.get(4)?,
+                    member_pubkey: row.get(5)?,
+                    created_at: row.get(6)?,
+                    signer_id: row.get(7)?,
+                    signer_pubkey: row.get(8)?,
+                    signature: row.get(9)?,
+                    prev: json_list(row.get(10)?).unwrap_or_default(),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    fn list_departures(&self) -> Result<Vec<GroupDepartureRow>> {
+        let conn = self.db()?;
+        let mut stmt = conn.prepare(
+            "SELECT group_id, policy, reason, items_affected, archive_path, departed_at
+             FROM group_departures ORDER BY id DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(GroupDepartureRow {
+                group_id: row.get(0)?,
+                policy: row.get(1)?,
+                reason: row.get(2)?,
+                items_affected: row.get(3)?,
+                archive_path: row.get(4)?,
+                departed_at: row.get(5)?,
+            })
+        })?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    fn save_peer_book(&self, peers: &[PeerBookRow]) -> Result<()> {
+        let mut conn = self.db()?;
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM peer_book", [])?;
+        for peer in peers {
+            tx.execute(
+                "INSERT OR REPLACE INTO peer_book
+                   (peer_id, addrs, group_ids, is_relay, rtt_ms, disconnect_count,
+                    ban_escalation, banned_until, ban_reason, last_seen)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    peer.peer_id,
+                    serde_json::to_string(&peer.addrs).map_err(std::io::Error::other)?,
+                    serde_json::to_string(&peer.groups).map_err(std::io::Error::other)?,
+                    peer.is_relay,
+                    peer.rtt_ms,
+                    peer.disconnect_count,
+                    peer.ban_escalation,
+                    peer.banned_until,
+                    peer.ban_reason,
+                    peer.last_seen,
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn load_peer_book(&self) -> Result<Vec<PeerBookRow>> {
+        let conn = self.db()?;
+        let mut stmt = conn.prepare(
+            "SELECT peer_id, addrs, group_ids, is_relay, rtt_ms, disconnect_count,
+                    ban_escalation, banned_until, ban_reason, last_seen
+             FROM peer_book ORDER BY last_seen DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(PeerBookRow {
+                peer_id: row.get(0)?,
+                addrs: json_list(row.get(1)?).unwrap_or_default(),
+                groups: json_list(row.get(2)?).unwrap_or_default(),
+                is_relay: row.get(3)?,
+                rtt_ms: row.get(4)?,
+                disconnect_count: row.get(5)?,
+                ban_escalation: row.get(6)?,
+                banned_until: row.get(7)?,
+                ban_reason: row.get(8)?,
+                last_seen: row.get(9)?,
+            })
+        })?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
     fn log_access(&self, entry: &AccessLogEntry) -> Result<()> {
         let conn = self.db()?;
         conn.execute(
@@ -866,18 +1683,22 @@ impl Storage for SqliteStorage {
     fn register_device(&self, device: &DeviceRow) -> Result<()> {
         let conn = self.db()?;
         conn.execute(
-            "INSERT INTO devices (device_id, entity_id, device_name, device_type, auth_token_hash)
-             VALUES (?1, ?2, ?3, ?4, ?5)
+            "INSERT INTO devices (device_id, entity_id, device_name, device_type, auth_token_hash, scopes)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
              ON CONFLICT(device_id) DO UPDATE SET
+               entity_id = excluded.entity_id,
                device_name = excluded.device_name,
                device_type = excluded.device_type,
-               auth_token_hash = excluded.auth_token_hash",
+               auth_token_hash = excluded.auth_token_hash,
+               scopes = excluded.scopes,
+               revoked_at = NULL",
             params![
                 device.device_id,
                 device.entity_id,
                 device.device_name,
                 device.device_type,
                 device.auth_token_hash,
+                device.scopes,
             ],
         )?;
         Ok(())
@@ -887,7 +1708,7 @@ impl Storage for SqliteStorage {
         let conn = self.db()?;
         let mut stmt = conn.prepare(
             "SELECT device_id, entity_id, device_name, device_type, auth_token_hash,
-                    created_at, last_seen_at, revoked_at
+                    created_at, last_seen_at, revoked_at, scopes
              FROM devices WHERE entity_id = ?1 ORDER BY created_at DESC",
         )?;
         let rows = stmt
@@ -901,6 +1722,7 @@ impl Storage for SqliteStorage {
                     created_at: row.get(5)?,
                     last_seen_at: row.get(6)?,
                     revoked_at: row.get(7)?,
+                    scopes: row.get(8)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -922,7 +1744,7 @@ impl Storage for SqliteStorage {
         let result = conn
             .query_row(
                 "SELECT device_id, entity_id, device_name, device_type, auth_token_hash,
-                        created_at, last_seen_at, revoked_at
+                        created_at, last_seen_at, revoked_at, scopes
                  FROM devices WHERE auth_token_hash = ?1 AND revoked_at IS NULL",
                 params![token_hash],
                 |row| {
@@ -935,6 +1757,7 @@ impl Storage for SqliteStorage {
                         created_at: row.get(5)?,
                         last_seen_at: row.get(6)?,
                         revoked_at: row.get(7)?,
+                        scopes: row.get(8)?,
                     })
                 },
             )
@@ -942,6 +1765,17 @@ impl Storage for SqliteStorage {
         Ok(result)
     }
 
+    fn touch_device(&self, device_id: &str) -> Result<()> {
+        let conn = self.db()?;
+        conn.execute(
+            "UPDATE devices SET last_seen_at = datetime('now')
+             WHERE device_id = ?1
+               AND (last_seen_at IS NULL OR last_seen_at < datetime('now', '-60 seconds'))",
+            params![device_id],
+        )?;
+        Ok(())
+    }
+
     fn fts_search(&self, query: &str, limit: u32) -> Result<Vec<String>> {
         let trimmed = query.trim();
         if trimmed.is_empty() {
@@ -971,6 +1805,31 @@ impl Storage for SqliteStorage {
     }
 }
 
+/// Columns read into a `GroupRow` (see `group_from_row`).
+const GROUP_COLUMNS: &str = "id, name, culture, security_policy, created_at, updated_at,
+     owner_id, owner_pubkey, signature, admins, signer_pubkey, owner_transfers, hlc";
+
+fn group_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<GroupRow> {
+    let admins: Option<String> = row.get(9)?;
+    Ok(GroupRow {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        culture: row.get(2)?,
+        security_policy: row.get(3)?,
+        created_at: row.get(4)?,
+        updated_at: row.get(5)?,
+        owner_id: row.get(6)?,
+        owner_pubkey: row.get(7)?,
+        signature: row.get(8)?,
+        admins: admins
+            .and_then(|a| serde_json::from_str(&a).ok())
+            .unwrap_or_default(),
+        signer_pubkey: row.get(10)?,
+        owner_transfers: row.get(11)?,
+        hlc: row.get(12)?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1013,6 +1872,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: None,
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         storage.write_l2_item(&item).unwrap();
@@ -1081,6 +1945,11 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: None,
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         storage.write_l2_item(&item).unwrap();
@@ -1145,6 +2014,39 @@ mod tests {
         assert_eq!(groups.len(), 1);
     }
 
+    #[test]
+    fn test_write_group_authority() {
+        let (_dir, storage) = test_db();
+        storage.write_group("grp-1", "G", "{}", "{}").unwrap();
+        let fresh = storage.read_group("grp-1").unwrap().unwrap();
+        assert!(fresh.admins.is_empty() && fresh.signer_pubkey.is_none());
+
+        let authority = GroupAuthority {
+            updated_at: "2026-03-01 12:00:00".into(),
+            owner_id: "bill".into(),
+            owner_pubkey: "bb".into(),
+            signature: "sig".into(),
+            signer_pubkey: Some("aa".into()),
+            admins: vec!["cc".into(), "dd".into()],
+            owner_transfers: Some("[]".into()),
+            hlc: Some("001772366400000-0000000000-bb".into()),
+        };
+        storage.write_group_authority("grp-1", &authority).unwrap();
+
+        let group = storage.list_groups().unwrap().remove(0);
+        // Signed updated_at is stored verbatim
+        assert_eq!(group.updated_at, "2026-03-01 12:00:00");
+        assert_eq!(group.owner_id.as_deref(), Some("bill"));
+        assert_eq!(group.signer_pubkey.as_deref(), Some("aa"));
+        assert_eq!(group.admins, vec!["cc", "dd"]);
+        assert_eq!(group.owner_transfers.as_deref(), Some("[]"));
+        assert_eq!(group.hlc.as_deref(), Some("001772366400000-0000000000-bb"));
+
+        // Unsigned groups set their version alone; write_group keeps it
+        storage.set_group_hlc("grp-1", None).unwrap();
+        assert!(storage.read_group("grp-1").unwrap().unwrap().hlc.is_none());
+    }
+
     #[test]
     fn test_add_remove_member() {
         let (_dir, storage) = test_db();
@@ -1278,6 +2180,11 @@ mod tests {
                     parent_id: None,
                     is_copy: false,
                     updated_at: None,
+                    expires_at: None,
+                    author_pubkey: None,
+                    author_signature: None,
+                    hlc: None,
+                    ancestry: None,
                 })
                 .unwrap();
         }
@@ -1296,6 +2203,11 @@ mod tests {
                 parent_id: None,
                 is_copy: false,
                 updated_at: None,
+                expires_at: None,
+                author_pubkey: None,
+                author_signature: None,
+                hlc: None,
+                ancestry: None,
             })
             .unwrap();
 
@@ -1321,13 +2233,414 @@ mod tests {
             parent_id: None,
             is_copy: false,
             updated_at: None,
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            hlc: None,
+            ancestry: None,
         };
 
         storage.write_l2_item(&item).unwrap();
         let row = storage.read_l2_item("chk-1").unwrap().unwrap();
 
-        let expected = SqliteStorage::checksum(data);
+        let expected = SqliteStorage::checksum(data, None);
         assert_eq!(row.checksum.unwrap(), expected);
+
+        // Expiry is bound into the checksum
+        assert_ne!(
+            item_checksum(data, Some("2099-01-01T00:00:00Z")),
+            item_checksum(data, Some("2100-01-01T00:00:00Z"))
+        );
+        assert_eq!(item_checksum(data, None), expected);
+    }
+
+    #[test]
+    fn test_item_timestamps_canonical() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let write = |storage: &SqliteStorage, id: &str, updated_at: Option<&str>| {
+            storage
+                .write_l2_item(&L2ItemWrite {
+                    id: id.into(),
+                    item_type: "entity".into(),
+                    data: b"{}".to_vec(),
+                    owner_id: None,
+                    visibility: "private".into(),
+                    group_id: None,
+                    author_id: None,
+                    key_version: 1,
+                    parent_id: None,
+                    is_copy: false,
+                    updated_at: updated_at.map(String::from),
+                    expires_at: None,
+                    author_pubkey: None,
+                    author_signature: None,
+                    hlc: None,
+                    ancestry: None,
+                })
+                .unwrap();
+        };
+
+        // Pre-v14 rows: SQLite local format and chrono RFC 3339
+        let old = SqliteStorage::create_new(&path).unwrap();
+        write(&old, "local", Some("2026-03-01 12:00:00"));
+        write(&old, "pushed", Some("2026-03-01T14:00:00.123456789+02:00"));
+        drop(old);
+
+        let storage = SqliteStorage::open(&path).unwrap();
+        let updated_at = |id: &str| storage.read_l2_item(id).unwrap().unwrap().updated_at;
+        assert_eq!(updated_at("local"), "2026-03-01T12:00:00.000Z");
+        assert_eq!(updated_at("pushed"), "2026-03-01T12:00:00.123Z");
+
+        // New local writes use the canonical format too
+        write(&storage, "fresh", None);
+        let fresh = updated_at("fresh");
+        assert_eq!(fresh.len(), "2026-03-01T12:00:00.000Z".len(), "{fresh}");
+        assert!(fresh.ends_with('Z') && fresh.contains('T'), "{fresh}");
+    }
+
+    #[test]
+    fn test_item_expiry() {
+        let (_dir, storage) = test_db();
+
+        let write = |id: &str, expires_at: Option<&str>, updated_at: Option<&str>| {
+            storage
+                .write_l2_item(&L2ItemWrite {
+                    id: id.into(),
+                    item_type: "entity".into(),
+                    data: b"blob".to_vec(),
+                    owner_id: None,
+                    visibility: "group".into(),
+                    group_id: Some("seed-drill".into()),
+                    author_id: Some("russell".into()),
+                    key_version: 1,
+                    parent_id: None,
+                    is_copy: false,
+                    updated_at: updated_at.map(String::from),
+                    expires_at: expires_at.map(String::from),
+                    author_pubkey: None,
+                    author_signature: None,
+                    hlc: None,
+                    ancestry: None,
+                })
+                .unwrap();
+        };
+
+        write("live", Some("2099-01-01T00:00:00Z"), None);
+        write("expired", Some("2020-01-01T00:00:00Z"), None);
+        write("forever", None, None);
+        write("old", None, Some("2020-01-01T00:00:00Z"));
+        write("garbled", Some("not a time"), None);
+
+        let row = storage.read_l2_item("live").unwrap().unwrap();
+        assert_eq!(row.expires_at.as_deref(), Some("2099-01-01T00:00:00Z"));
+
+        // Expired items are not advertised to peers
+        let ids: Vec<String> = storage
+            .list_group_items("seed-drill", None, 100)
+            .unwrap()
+            .into_iter()
+            .map(|h| h.item_id)
+            .collect();
+        assert!(!ids.contains(&"expired".to_string()));
+        assert_eq!(ids.len(), 3);
+
+        // Unparseable expiries count as expired, for serving and purging
+        assert!(!ids.contains(&"garbled".to_string()));
+        assert_eq!(storage.purge_expired_items().unwrap(), 2);
+        assert!(storage.read_l2_item("expired").unwrap().is_none());
+        assert!(storage.read_l2_item("garbled").unwrap().is_none());
+
+        // ttl_default fallback only touches items without an explicit expiry
+        assert_eq!(
+            storage
+                .purge_group_items_older_than("seed-drill", 86400)
+                .unwrap(),
+            1
+        );
+        assert!(storage.read_l2_item("old").unwrap().is_none());
+        assert!(storage.read_l2_item("forever").unwrap().is_some());
+        assert!(storage.read_l2_item("live").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_l2_conflicts() {
+        let (_dir, storage) = test_db();
+        storage
+            .write_l2_item(&L2ItemWrite {
+                id: "doc".into(),
+                item_type: "learning".into(),
+                data: b"current".to_vec(),
+                owner_id: None,
+                visibility: "group".into(),
+                group_id: Some("seed-drill".into()),
+                author_id: Some("russell".into()),
+                key_version: 1,
+                parent_id: None,
+                is_copy: false,
+                updated_at: None,
+                expires_at: None,
+                author_pubkey: None,
+                author_signature: None,
+                hlc: None,
+                ancestry: Some(vec!["aaa".into(), "bbb".into()]),
+            })
+            .unwrap();
+        let row = storage.read_l2_item("doc").unwrap().unwrap();
+        assert_eq!(row.ancestry, Some(vec!["aaa".into(), "bbb".into()]));
+
+        let sibling = |checksum: &str| L2ConflictRow {
+            item_id: "doc".into(),
+            checksum: checksum.into(),
+            item_type: "learning".into(),
+            data: checksum.as_bytes().to_vec(),
+            group_id: Some("seed-drill".into()),
+            author_id: Some("martin".into()),
+            key_version: 1,
+            updated_at: "2026-03-01T12:00:00.000Z".into(),
+            hlc: None,
+            ancestry: Some(vec!["aaa".into()]),
+            expires_at: None,
+            author_pubkey: None,
+            author_signature: None,
+            detected_at: String::new(),
+        };
+        assert!(storage.write_l2_conflict(&sibling("ccc"), 8).unwrap());
+        assert!(storage.write_l2_conflict(&sibling("ddd"), 8).unwrap());
+        assert!(!storage.write_l2_conflict(&sibling("ccc"), 8).unwrap());
+
+        let listed = storage.list_l2_conflicts(Some("doc")).unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].ancestry, Some(vec!["aaa".into()]));
+        assert!(!listed[0].detected_at.is_empty());
+        assert_eq!(storage.list_l2_conflicts(None).unwrap().len(), 2);
+        assert!(storage.list_l2_conflicts(Some("other")).unwrap().is_empty());
+
+        assert_eq!(
+            storage
+                .delete_l2_conflicts("doc", &["ccc".into(), "zzz".into()])
+                .unwrap(),
+            1
+        );
+        assert_eq!(storage.list_l2_conflicts(Some("doc")).unwrap().len(), 1);
+
+        // Deleting the item takes its siblings with it
+        assert!(storage.delete_l2_item("doc").unwrap());
+        assert!(storage.list_l2_conflicts(None).unwrap().is_empty());
+
+        // Past the cap the oldest versions (by HLC) are evicted, and a
+        // version older than every kept one is not kept at all
+        let versioned = |checksum: &str, ms: u64| L2ConflictRow {
+            hlc: Some(format!("{ms:015}-0000000000-n1")),
+            ..sibling(checksum)
+        };
+        for (i, cs) in ["v1", "v2", "v3"].into_iter().enumerate() {
+            assert!(storage
+                .write_l2_conflict(&versioned(cs, 100 + i as u64), 2)
+                .unwrap());
+        }
+        assert!(!storage.write_l2_conflict(&versioned("v0", 50), 2).unwrap());
+        let mut kept: Vec<String> = storage
+            .list_l2_conflicts(Some("doc"))
+            .unwrap()
+            .into_iter()
+            .map(|c| c.checksum)
+            .collect();
+        kept.sort();
+        assert_eq!(kept, vec!["v2", "v3"]);
+    }
+
+    #[test]
+    fn test_peer_book() {
+        let (_dir, storage) = test_db();
+        assert!(storage.load_peer_book().unwrap().is_empty());
+
+        let peer = |id: &str, last_seen: &str| PeerBookRow {
+            peer_id: id.into(),
+            addrs: vec!["/ip4/10.0.0.1/udp/9474/quic-v1".into()],
+            groups: vec!["seed-drill".into()],
+            is_relay: true,
+            rtt_ms: Some(12.5),
+            disconnect_count: 2,
+            ban_escalation: 1,
+            banned_until: Some("2026-03-01T13:00:00Z".into()),
+            ban_reason: Some("spam".into()),
+            last_seen: last_seen.into(),
+        };
+        let book = vec![
+            peer("12D3KooWA", "2026-03-01T12:00:00Z"),
+            peer("12D3KooWB", "2026-03-02T12:00:00Z"),
+        ];
+        storage.save_peer_book(&book).unwrap();
+        // Most recently seen first
+        let loaded = storage.load_peer_book().unwrap();
+        assert_eq!(loaded, vec![book[1].clone(), book[0].clone()]);
+
+        // A save replaces the whole book
+        storage.save_peer_book(&book[..1]).unwrap();
+        assert_eq!(storage.load_peer_book().unwrap(), book[..1].to_vec());
+    }
+
+    #[test]
+    fn test_group_departure_storage() {
+        let (dir, storage) = test_db();
+
+        for (id, group) in [
+            ("a1", "grp-a"),
+            ("a2", "grp-a"),
+            ("p1", "grp-p"),
+            ("k1", "keep"),
+        ] {
+            storage
+                .write_l2_item(&L2ItemWrite {
+                    id: id.into(),
+                    item_type: "entity".into(),
+                    data: format!("blob-{id}").into_bytes(),
+                    owner_id: None,
+                    visibility: "group".into(),
+                    group_id: Some(group.into()),
+                    author_id: Some("russell".into()),
+                    key_version: 1,
+                    parent_id: None,
+                    is_copy: false,
+                    updated_at: None,
+                    expires_at: None,
+                    author_pubkey: None,
+                    author_signature: None,
+                    hlc: None,
+                    ancestry: None,
+                })
+                .unwrap();
+        }
+        assert_eq!(storage.count_group_items("grp-a").unwrap(), 2);
+
+        // Archive exports the group's items and keeps them
+        let (archived, path) = storage.archive_group_items("grp-a").unwrap();
+        assert_eq!(archived, 2);
+        assert!(path.starts_with(&dir.path().join("archive").display().to_string()));
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let rows: Vec<L2ItemRow> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].data, b"blob-a1");
+        assert_eq!(storage.count_group_items("grp-a").unwrap(), 2);
+
+        // Purge also drops the item's search index rows and siblings
+        {
+            let conn = storage.db().unwrap();
+            for id in ["p1", "k1"] {
+                conn.execute(
+                    "INSERT INTO l2_fts (item_id, name, content, tags) VALUES (?1, ?1, 'plaintext', '')",
+                    params![id],
+                )
+                .unwrap();
+            }
+        }
+        storage
+            .write_l2_conflict(
+                &L2ConflictRow {
+                    item_id: "p1".into(),
+                    checksum: "sibling".into(),
+                    item_type: "entity".into(),
+                    data: b"blob-sibling".to_vec(),
+                    group_id: Some("grp-p".into()),
+                    author_id: None,
+                    key_version: 1,
+                    updated_at: "2026-02-01T00:00:00Z".into(),
+                    hlc: None,
+                    ancestry: None,
+                    expires_at: None,
+                    author_pubkey: None,
+                    author_signature: None,
+                    detected_at: String::new(),
+                },
+                8,
+            )
+            .unwrap();
+        assert_eq!(storage.purge_group_items("grp-p").unwrap(), 1);
+        assert!(storage.read_l2_item("p1").unwrap().is_none());
+        assert!(storage.read_l2_item("k1").unwrap().is_some());
+        assert!(storage.list_l2_conflicts(Some("p1")).unwrap().is_empty());
+        assert_eq!(storage.fts_search("plaintext", 10).unwrap(), vec!["k1"]);
+
+        storage
+            .record_departure(&GroupDepartureRow {
+                group_id: "grp-a".into(),
+                policy: "archive".into(),
+                reason: "local_leave".into(),
+                items_affected: archived,
+                archive_path: Some(path.clone()),
+                departed_at: String::new(),
+            })
+            .unwrap();
+        storage
+            .record_departure(&GroupDepartureRow {
+                group_id: "grp-p".into(),
+                policy: "purge".into(),
+                reason: "owner_removal".into(),
+                items_affected: 1,
+                archive_path: None,
+                departed_at: String::new(),
+            })
+            .unwrap();
+
+        let departures = storage.list_departures().unwrap();
+        assert_eq!(departures.len(), 2);
+        assert_eq!(departures[0].group_id, "grp-p"); // newest first
+        assert_eq!(departures[1].archive_path.as_deref(), Some(path.as_str()));
+        assert!(!departures[1].departed_at.is_empty());
+    }
+
+    #[test]
+    fn test_membership_log() {
+        let (_dir, storage) = test_db();
+
+        let entry = |id: &str, op: &str, entity: &str, at: &str| MembershipLogRow {
+            entry_id: id.into(),
+            group_id: "grp".into(),
+            op: op.into(),
+            entity_id: entity.into(),
+            role: (op != "remove").then(|| "member".to_string()),
+            member_pubkey: None,
+            created_at: at.into(),
+            signer_id: "russell".into(),
+            signer_pubkey: "ab".into(),
+            signature: "cd".into(),
+            prev: Vec::new(),
+        };
+        let remove = entry("e2", "remove", "alice", "2026-02-02T00:00:00Z");
+        let add = entry("e1", "add", "alice", "2026-02-01T00:00:00Z");
+        let tie = entry("e0", "add", "bob", "2026-02-02T00:00:00Z");
+
+        assert!(storage.append_membership_entry(&remove).unwrap());
+        assert!(storage.append_membership_entry(&add).unwrap());
+        assert!(storage.append_membership_entry(&tie).unwrap());
+        // Duplicate entry IDs are ignored
+        assert!(!storage.append_membership_entry(&add).unwrap());
+
+        // Replay order: created_at, then entry_id
+        let log = storage.list_membership_log("grp").unwrap();
+        let ids: Vec<&str> = log.iter().map(|e| e.entry_id.as_str()).collect();
+        assert_eq!(ids, vec!["e1", "e0", "e2"]);
+        assert_eq!(log[2], remove);
+        assert!(storage.list_membership_log("other").unwrap().is_empty());
+
+        // Causal links round-trip
+        let chained = MembershipLogRow {
+            entry_id: "e4".into(),
+            prev: vec!["e0".into(), "e2".into()],
+            ..entry("e4", "add", "carol", "2026-02-03T00:00:00Z")
+        };
+        assert!(storage.append_membership_entry(&chained).unwrap());
+        let log = storage.list_membership_log("grp").unwrap();
+        assert_eq!(log.last(), Some(&chained));
+
+        // Op is constrained
+        assert!(storage
+            .append_membership_entry(&entry("e3", "promote", "alice", "2026-02-03T00:00:00Z"))
+            .is_err());
     }
 
     #[test]
@@ -1346,6 +2659,7 @@ mod tests {
             device_name: Some("Russell's MacBook".into()),
             device_type: "node".into(),
             auth_token_hash: "abc123hash".into(),
+            scopes: "write group:team-a".into(),
             created_at: String::new(),
             last_seen_at: None,
             revoked_at: None,
@@ -1364,6 +2678,12 @@ mod tests {
             .unwrap()
             .unwrap();
         assert_eq!(found.device_id, "dev-001");
+        assert_eq!(found.scopes, "write group:team-a");
+
+        // Activity stamp
+        storage.touch_device("dev-001").unwrap();
+        let devices = storage.list_devices("russell").unwrap();
+        assert!(devices[0].last_seen_at.is_some());
 
         // Revoke
         assert!(storage.revoke_device("russell", "dev-001").unwrap());
@@ -1379,5 +2699,21 @@ mod tests {
         let devices2 = storage.list_devices("russell").unwrap();
         assert_eq!(devices2.len(), 1);
         assert!(devices2[0].revoked_at.is_some());
+
+        // Re-registration rebinds the device and lifts the revocation
+        storage
+            .register_device(&DeviceRow {
+                entity_id: "martin".into(),
+                auth_token_hash: "def456hash".into(),
+                ..device
+            })
+            .unwrap();
+        let found = storage
+            .get_device_by_token_hash("def456hash")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.entity_id, "martin");
+        assert!(found.revoked_at.is_none());
+        assert!(storage.list_devices("russell").unwrap().is_empty());
     }
 }
@@ -0,0 +1,7 @@
+-- Cordelia schema v10 migration from v9.
+-- Adds per-device token scopes (API auth via device bearer tokens).
+-- Existing devices get read-only access until re-registered with scopes.
+
+ALTER TABLE devices ADD COLUMN scopes TEXT NOT NULL DEFAULT 'read';
+
+UPDATE schema_version SET version = 10, migrated_at = datetime('now') WHERE version = 9;
@@ -0,0 +1,21 @@
+-- Cordelia schema v11 migration from v10.
+-- Adds group_membership_log: owner/admin-signed membership operations,
+-- replicated via GroupExchange and replayed into group_members.
+
+CREATE TABLE IF NOT EXISTS group_membership_log (
+    entry_id TEXT PRIMARY KEY,
+    group_id TEXT NOT NULL,
+    op TEXT NOT NULL CHECK(op IN ('add', 'remove', 'role')),
+    entity_id TEXT NOT NULL,
+    role TEXT,
+    member_pubkey TEXT,
+    created_at TEXT NOT NULL,
+    signer_id TEXT NOT NULL,
+    signer_pubkey TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    received_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_membership_log_group ON group_membership_log(group_id, created_at);
+
+UPDATE schema_version SET version = 11, migrated_at = datetime('now') WHERE version = 10;
@@ -0,0 +1,8 @@
+-- Cordelia schema v12 migration from v11.
+-- Adds descriptor admin signers and the ownership handover chain to groups.
+
+ALTER TABLE groups ADD COLUMN admins TEXT;
+ALTER TABLE groups ADD COLUMN signer_pubkey TEXT;
+ALTER TABLE groups ADD COLUMN owner_transfers TEXT;
+
+UPDATE schema_version SET version = 12, migrated_at = datetime('now') WHERE version = 11;
@@ -0,0 +1,7 @@
+-- Cordelia schema v13 migration from v12.
+-- Adds author signatures to L2 items (security_policy.require_author_signature).
+
+ALTER TABLE l2_items ADD COLUMN author_pubkey TEXT;
+ALTER TABLE l2_items ADD COLUMN author_signature TEXT;
+
+UPDATE schema_version SET version = 13, migrated_at = datetime('now') WHERE version = 12;
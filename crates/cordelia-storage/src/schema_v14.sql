@@ -0,0 +1,10 @@
+-- Cordelia schema v14 migration from v13.
+-- Rewrites L2 item timestamps in the canonical format (RFC 3339 UTC,
+-- millisecond precision), so LWW string comparison is time order. Local
+-- writes used SQLite datetime('now'); replicated ones chrono RFC 3339.
+
+UPDATE l2_items
+   SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', updated_at)
+ WHERE strftime('%Y-%m-%dT%H:%M:%fZ', updated_at) IS NOT NULL;
+
+UPDATE schema_version SET version = 14, migrated_at = datetime('now') WHERE version = 13;
@@ -0,0 +1,9 @@
+-- Cordelia schema v15 migration from v14.
+-- Adds hybrid logical clock versions to L2 items and groups. Existing rows
+-- keep NULL: their version is derived from updated_at (counter 0, no node
+-- id), which orders before any HLC stamped in the same millisecond.
+
+ALTER TABLE l2_items ADD COLUMN hlc TEXT;
+ALTER TABLE groups ADD COLUMN hlc TEXT;
+
+UPDATE schema_version SET version = 15, migrated_at = datetime('now') WHERE version = 14;
@@ -0,0 +1,28 @@
+-- Cordelia schema v16 migration from v15.
+-- Adds item ancestry (checksums of replaced versions, JSON array) and
+-- l2_conflicts: concurrent versions kept alongside the current one until
+-- a merge write resolves them.
+
+ALTER TABLE l2_items ADD COLUMN ancestry TEXT;
+
+CREATE TABLE IF NOT EXISTS l2_conflicts (
+    item_id TEXT NOT NULL,
+    checksum TEXT NOT NULL,
+    type TEXT NOT NULL,
+    data BLOB NOT NULL,
+    group_id TEXT,
+    author_id TEXT,
+    key_version INTEGER DEFAULT 1,
+    updated_at TEXT NOT NULL,
+    hlc TEXT,
+    ancestry TEXT,
+    ttl_expires_at TEXT,
+    author_pubkey TEXT,
+    author_signature TEXT,
+    detected_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (item_id, checksum)
+);
+
+CREATE INDEX IF NOT EXISTS idx_l2_conflicts_group ON l2_conflicts(group_id) WHERE group_id IS NOT NULL;
+
+UPDATE schema_version SET version = 16, migrated_at = datetime('now') WHERE version = 15;
@@ -0,0 +1,19 @@
+-- Cordelia schema v17 migration from v16.
+-- Adds peer_book: the governor's known peers (addresses, relay flag, RTT,
+-- backoff and ban history), saved periodically and on shutdown so a
+-- restarted node can rejoin without its bootnodes.
+
+CREATE TABLE IF NOT EXISTS peer_book (
+    peer_id TEXT PRIMARY KEY,
+    addrs TEXT NOT NULL,
+    group_ids TEXT NOT NULL DEFAULT '[]',
+    is_relay INTEGER NOT NULL DEFAULT 0,
+    rtt_ms REAL,
+    disconnect_count INTEGER NOT NULL DEFAULT 0,
+    ban_escalation INTEGER NOT NULL DEFAULT 0,
+    banned_until TEXT,
+    ban_reason TEXT,
+    last_seen TEXT NOT NULL
+);
+
+UPDATE schema_version SET version = 17, migrated_at = datetime('now') WHERE version = 16;
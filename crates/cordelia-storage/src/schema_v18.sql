@@ -0,0 +1,8 @@
+-- Cordelia schema v18 migration from v17.
+-- Adds prev to group_membership_log: the log heads each entry was signed
+-- after (JSON array of entry IDs), so replay follows causal order rather
+-- than signer-chosen timestamps.
+
+ALTER TABLE group_membership_log ADD COLUMN prev TEXT;
+
+UPDATE schema_version SET version = 18, migrated_at = datetime('now') WHERE version = 17;
@@ -24,7 +24,31 @@ CREATE TABLE IF NOT EXISTS l2_items (
   parent_id TEXT,
   is_copy INTEGER DEFAULT 0,
   created_at TEXT NOT NULL DEFAULT (datetime('now')),
-  updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+  updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  ttl_expires_at TEXT,
+  author_pubkey TEXT,
+  author_signature TEXT,
+  hlc TEXT,
+  ancestry TEXT
+);
+
+-- Concurrent versions of L2 items kept alongside the current one until merged
+CREATE TABLE IF NOT EXISTS l2_conflicts (
+  item_id TEXT NOT NULL,
+  checksum TEXT NOT NULL,
+  type TEXT NOT NULL,
+  data BLOB NOT NULL,
+  group_id TEXT,
+  author_id TEXT,
+  key_version INTEGER DEFAULT 1,
+  updated_at TEXT NOT NULL,
+  hlc TEXT,
+  ancestry TEXT,
+  ttl_expires_at TEXT,
+  author_pubkey TEXT,
+  author_signature TEXT,
+  detected_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  PRIMARY KEY (item_id, checksum)
 );
 
 CREATE TABLE IF NOT EXISTS l2_index (
@@ -82,7 +106,11 @@ CREATE TABLE IF NOT EXISTS groups (
   updated_at TEXT NOT NULL DEFAULT (datetime('now')),
   owner_id TEXT,
   owner_pubkey TEXT,
-  signature TEXT
+  signature TEXT,
+  admins TEXT,
+  signer_pubkey TEXT,
+  owner_transfers TEXT,
+  hlc TEXT
 );
 
 CREATE TABLE IF NOT EXISTS group_members (
@@ -109,9 +137,66 @@ CREATE TABLE IF NOT EXISTS access_log (
   detail TEXT
 );
 
+CREATE TABLE IF NOT EXISTS group_membership_log (
+  entry_id TEXT PRIMARY KEY,
+  group_id TEXT NOT NULL,
+  op TEXT NOT NULL CHECK(op IN ('add', 'remove', 'role')),
+  entity_id TEXT NOT NULL,
+  role TEXT,
+  member_pubkey TEXT,
+  created_at TEXT NOT NULL,
+  signer_id TEXT NOT NULL,
+  signer_pubkey TEXT NOT NULL,
+  signature TEXT NOT NULL,
+  prev TEXT,
+  received_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS devices (
+  device_id TEXT PRIMARY KEY,
+  entity_id TEXT NOT NULL,
+  device_name TEXT,
+  device_type TEXT NOT NULL DEFAULT 'node'
+    CHECK(device_type IN ('node', 'browser', 'mobile')),
+  auth_token_hash TEXT NOT NULL,
+  scopes TEXT NOT NULL DEFAULT 'read',
+  created_at TEXT NOT NULL DEFAULT (datetime('now')),
+  last_seen_at TEXT,
+  revoked_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS group_departures (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  group_id TEXT NOT NULL,
+  policy TEXT NOT NULL CHECK(policy IN ('retain', 'purge', 'archive')),
+  reason TEXT NOT NULL,
+  items_affected INTEGER NOT NULL DEFAULT 0,
+  archive_path TEXT,
+  departed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS peer_book (
+  peer_id TEXT PRIMARY KEY,
+  addrs TEXT NOT NULL,
+  group_ids TEXT NOT NULL DEFAULT '[]',
+  is_relay INTEGER NOT NULL DEFAULT 0,
+  rtt_ms REAL,
+  disconnect_count INTEGER NOT NULL DEFAULT 0,
+  ban_escalation INTEGER NOT NULL DEFAULT 0,
+  banned_until TEXT,
+  ban_reason TEXT,
+  last_seen TEXT NOT NULL
+);
+
 -- Indexes
 CREATE INDEX IF NOT EXISTS idx_l2_items_group ON l2_items(group_id) WHERE group_id IS NOT NULL;
 CREATE INDEX IF NOT EXISTS idx_l2_items_parent ON l2_items(parent_id) WHERE parent_id IS NOT NULL;
 CREATE INDEX IF NOT EXISTS idx_l2_items_author ON l2_items(author_id) WHERE author_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_l2_items_expiry ON l2_items(ttl_expires_at) WHERE ttl_expires_at IS NOT NULL;
 CREATE INDEX IF NOT EXISTS idx_access_log_entity ON access_log(entity_id);
 CREATE INDEX IF NOT EXISTS idx_access_log_group ON access_log(group_id) WHERE group_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_group_departures_group ON group_departures(group_id);
+CREATE INDEX IF NOT EXISTS idx_l2_conflicts_group ON l2_conflicts(group_id) WHERE group_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_membership_log_group ON group_membership_log(group_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_devices_entity ON devices(entity_id);
+CREATE INDEX IF NOT EXISTS idx_devices_token ON devices(auth_token_hash);
@@ -0,0 +1,9 @@
+-- Cordelia schema v8 migration from v7.
+-- Adds item expiry (GroupCulture.ttl_default or explicit per-item expiry).
+-- NOTE: Column existence check is done in Rust before executing this file.
+
+ALTER TABLE l2_items ADD COLUMN ttl_expires_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_l2_items_expiry ON l2_items(ttl_expires_at) WHERE ttl_expires_at IS NOT NULL;
+
+UPDATE schema_version SET version = 8, migrated_at = datetime('now') WHERE version = 7;
@@ -0,0 +1,16 @@
+-- Cordelia schema v9 migration from v8.
+-- Adds group_departures: record of departure_policy enforcement per group.
+
+CREATE TABLE IF NOT EXISTS group_departures (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    group_id TEXT NOT NULL,
+    policy TEXT NOT NULL CHECK(policy IN ('retain', 'purge', 'archive')),
+    reason TEXT NOT NULL,
+    items_affected INTEGER NOT NULL DEFAULT 0,
+    archive_path TEXT,
+    departed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_group_departures_group ON group_departures(group_id);
+
+UPDATE schema_version SET version = 9, migrated_at = datetime('now') WHERE version = 8;
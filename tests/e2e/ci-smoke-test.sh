@@ -206,7 +206,7 @@ T4_OK=true
 
 # 4a: Create group on agent-alpha-1
 if ! api "agent-alpha-1" "groups/create" \
-    "{\"group_id\":\"${GRP_ID}\",\"name\":\"CI Test Group\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1; then
+    "{\"group_id\":\"${GRP_ID}\",\"name\":\"CI Test Group\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1; then
     fail "groups/create failed on agent-alpha-1" agent-alpha-1
     T4_OK=false
 else
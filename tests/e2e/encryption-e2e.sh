@@ -282,7 +282,7 @@ fi
 if $T3_OK; then
     # Create the group on the Rust node so it's recognized
     node_api "keeper-seeddrill-1" "groups/create" \
-        "{\"group_id\":\"${TEST_GROUP_ID}\",\"name\":\"E2E Encryption Test\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+        "{\"group_id\":\"${TEST_GROUP_ID}\",\"name\":\"E2E Encryption Test\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 
     # Create the group on the proxy too
     proxy_post "/api/groups" \
@@ -457,9 +457,9 @@ provision_proxy_psk "$GROUP_B" "$PSK_B" > /dev/null 2>&1
 
 # Create groups on node
 node_api "keeper-seeddrill-1" "groups/create" \
-    "{\"group_id\":\"${GROUP_A}\",\"name\":\"Isolation A\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+    "{\"group_id\":\"${GROUP_A}\",\"name\":\"Isolation A\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 node_api "keeper-seeddrill-1" "groups/create" \
-    "{\"group_id\":\"${GROUP_B}\",\"name\":\"Isolation B\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+    "{\"group_id\":\"${GROUP_B}\",\"name\":\"Isolation B\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 
 # Encrypt and write items to each group
 ITEM_A="e2e-iso-A-${TS}"
@@ -528,7 +528,7 @@ provision_proxy_psk "$ROT_GROUP" "$ROT_PSK_V1" 1 > /dev/null 2>&1
 
 # Create group on node
 node_api "keeper-seeddrill-1" "groups/create" \
-    "{\"group_id\":\"${ROT_GROUP}\",\"name\":\"Rotation Test\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+    "{\"group_id\":\"${ROT_GROUP}\",\"name\":\"Rotation Test\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 
 # Write item with v1 key
 ROT_ITEM_V1="e2e-rot-v1-${TS}"
@@ -589,7 +589,7 @@ MEM_PSK_V1=$(generate_psk)
 # Provision group + PSK
 provision_proxy_psk "$MEM_GROUP" "$MEM_PSK_V1" 1 > /dev/null 2>&1
 node_api "keeper-seeddrill-1" "groups/create" \
-    "{\"group_id\":\"${MEM_GROUP}\",\"name\":\"Member Test\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+    "{\"group_id\":\"${MEM_GROUP}\",\"name\":\"Member Test\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 
 # Add a member
 node_api "keeper-seeddrill-1" "l1/write" \
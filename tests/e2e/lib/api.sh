@@ -58,16 +58,17 @@ api_read_item() {
     api_post "$host" "/api/v1/l2/read" "{\"item_id\": \"${id}\"}"
 }
 
-# api_create_group HOST GROUP_ID NAME CULTURE
+# api_create_group HOST GROUP_ID NAME CULTURE (culture JSON document)
 api_create_group() {
-    local host="$1" group_id="$2" name="$3" culture="${4:-chatty}"
+    local host="$1" group_id="$2" name="$3" culture="${4:-}"
+    [ -n "$culture" ] || culture='{"broadcast_eagerness":"chatty"}'
     local body
     body=$(cat <<EOF
 {
     "group_id": "${group_id}",
     "name": "${name}",
-    "culture": "${culture}",
-    "security_policy": "standard"
+    "culture": "${culture//\"/\\\"}",
+    "security_policy": "{}"
 }
 EOF
 )
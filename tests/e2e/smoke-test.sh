@@ -201,7 +201,7 @@ PG_ITEM="smoke-pg-${TS}"
 # without it, relays can't compute group_intersection and won't forward items.
 for node in "agent-${ORG_A}-1" "edge-${ORG_A}-1" "edge-${ORG_A}-2" "keeper-${ORG_A}-1" "keeper-${ORG_A}-2"; do
     api "$node" "groups/create" \
-        "{\"group_id\":\"${PG_ID}\",\"name\":\"agent-${ORG_A}-1 (personal)\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+        "{\"group_id\":\"${PG_ID}\",\"name\":\"agent-${ORG_A}-1 (personal)\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 done
 
 # Wait for group exchange so group_intersection is computed on all peers.
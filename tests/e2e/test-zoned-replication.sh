@@ -81,9 +81,9 @@ api_status() {
 create_group() {
     local node="$1"
     local group_id="$2"
-    local culture="${3:-chatty}"
+    local eagerness="${3:-chatty}"
     api "$node" "/api/v1/groups/create" \
-        "{\"group_id\":\"${group_id}\",\"name\":\"${group_id}\",\"culture\":\"${culture}\",\"departure_policy\":\"standard\"}" || true
+        "{\"group_id\":\"${group_id}\",\"name\":\"${group_id}\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"${eagerness}\\\"}\",\"departure_policy\":\"standard\"}" || true
 }
 
 write_item() {
@@ -133,7 +133,7 @@ echo "[1] Creating personal group '${PG_ID}' on agent, edges, and keepers..."
 # the relay can't compute group_intersection and won't forward items.
 for node in agent-alpha-1 edge-alpha-1 edge-alpha-2 keeper-alpha-1 keeper-alpha-2; do
     api "$node" "groups/create" \
-        "{\"group_id\":\"${PG_ID}\",\"name\":\"agent-alpha-1 (personal)\",\"culture\":\"chatty\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+        "{\"group_id\":\"${PG_ID}\",\"name\":\"agent-alpha-1 (personal)\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"chatty\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 done
 
 # Verify group exists on key nodes
@@ -246,7 +246,7 @@ echo "  Creating group on agent + keepers only (edges learn via group exchange).
 
 for node in agent-alpha-1 keeper-alpha-1 keeper-alpha-2; do
     api "$node" "groups/create" \
-        "{\"group_id\":\"${PG_ID2}\",\"name\":\"taciturn-test\",\"culture\":\"taciturn\",\"security_policy\":\"standard\"}" > /dev/null 2>&1 || true
+        "{\"group_id\":\"${PG_ID2}\",\"name\":\"taciturn-test\",\"culture\":\"{\\\"broadcast_eagerness\\\":\\\"taciturn\\\"}\",\"security_policy\":\"{}\"}" > /dev/null 2>&1 || true
 done
 
 # Wait for group exchange so edges learn the group from agent
@@ -19,7 +19,7 @@ wait_all_hot "$N" 1 "$TIMEOUT"
 
 # Create a chatty group on node1
 echo "Creating chatty group on node1..."
-api_create_group "$HOST1" "e2e-repl-group" "E2E Replication Test" "chatty"
+api_create_group "$HOST1" "e2e-repl-group" "E2E Replication Test" '{"broadcast_eagerness":"chatty"}'
 
 # Wait for group to propagate (group exchange happens on governor tick)
 sleep 15